@@ -0,0 +1,106 @@
+use crate::executor::{BoxFuture, ThreadWaker};
+use crate::listener::ListenerHandle;
+use crate::EventListener;
+use std::sync::Arc;
+use std::task::{Context, Waker};
+use std::time::{Duration, Instant};
+
+/// Listeners which did or didn't acknowledge an emission in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AckReport {
+    /// Listeners which acknowledged before the deadline
+    pub acked: Vec<ListenerHandle>,
+    /// Listeners which didn't acknowledge before the deadline
+    pub missing: Vec<ListenerHandle>,
+}
+
+impl AckReport {
+    /// Check whether every listener acknowledged
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Listener call recorded during the emission of [`EventListener::emit_and_wait_acks`]
+pub(crate) struct AckCall {
+    /// The called listener
+    pub(crate) listener: ListenerHandle,
+    /// Whether the listener returned without failing
+    pub(crate) succeeded: bool,
+    /// When the listener returned
+    pub(crate) finished: Instant,
+}
+
+impl EventListener {
+    /// Emit an event and wait until every listener acknowledges it or the timeout elapses
+    ///
+    /// The event is emitted through [`EventListener::emit`], so routing, aliases, pattern
+    /// listeners and middlewares apply. Synchronous listeners acknowledge by returning
+    /// and async listeners by completing their future. Async futures are polled on the
+    /// current thread until the deadline, futures still pending then are dropped and
+    /// reported as missing. Listeners which weren't called, because of filters, budgets
+    /// or a queued dispatch mode, are in neither list.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// * `timeout` - Max time to wait for acknowledgements
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("shutdown", Box::new(|_, _| println!("cache flushed")));
+    /// emitter.on_async("shutdown", Box::new(|_, _| Box::pin(async {
+    ///  println!("connections closed");
+    /// })));
+    /// let report = emitter.emit_and_wait_acks("shutdown", "now".to_string(), Duration::from_secs(1));
+    /// assert!(report.is_complete());
+    /// assert_eq!(report.acked.len(), 2);
+    /// ```
+    /// ## Returns
+    /// [`AckReport`]
+    /// ## Panics
+    /// Like [`EventListener::emit`]
+    pub fn emit_and_wait_acks(&mut self, name: &str, data: String, timeout: Duration) -> AckReport {
+        let deadline = Instant::now() + timeout;
+        let previous_calls = self.ack_calls.replace(vec![]);
+        let previous_futures = self.async_pending.replace(vec![]);
+        let outcome =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.emit(name, data)));
+        let calls = std::mem::replace(&mut self.ack_calls, previous_calls).unwrap_or_default();
+        let mut pending: Vec<(ListenerHandle, BoxFuture)> =
+            std::mem::replace(&mut self.async_pending, previous_futures).unwrap_or_default();
+        if let Err(panic) = outcome {
+            std::panic::resume_unwind(panic);
+        }
+        let mut report = AckReport::default();
+        for call in calls {
+            if call.succeeded && call.finished <= deadline {
+                report.acked.push(call.listener);
+            } else {
+                report.missing.push(call.listener);
+            }
+        }
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            let mut index = 0;
+            while index < pending.len() {
+                if pending[index].1.as_mut().poll(&mut context).is_ready() {
+                    report.acked.push(pending.remove(index).0);
+                } else {
+                    index += 1;
+                }
+            }
+            let now = Instant::now();
+            if pending.is_empty() || now >= deadline {
+                break;
+            }
+            std::thread::park_timeout(deadline - now);
+        }
+        report.missing.extend(pending.into_iter().map(|x| x.0));
+        report
+    }
+}
@@ -0,0 +1,175 @@
+//! Actor-style mailboxes
+//!
+//! An [`Actor`] registered with [`EventListener::spawn_actor`] receives every message
+//! sent to its id with [`EventListener::send`]. Messages wait in the actor's mailbox and
+//! are handed to it one at a time by [`EventListener::run_actors`], so an actor never
+//! handles two messages at once and never runs inside another listener.
+
+use crate::listener::{Listener, ListenerTypes};
+use crate::outcome::EmitOutcome;
+use crate::EventListener;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Object consuming messages addressed to it
+pub trait Actor {
+    /// Handle a message
+    /// ## Parameters
+    /// * `message` - The message
+    fn receive(&mut self, message: String);
+}
+
+/// Actor and its pending messages
+struct Mailbox<A: Actor> {
+    actor: RefCell<A>,
+    pending: RefCell<VecDeque<String>>,
+}
+
+/// Type-erased mailbox
+trait MailboxRun {
+    fn run(&self) -> usize;
+    fn pending(&self) -> usize;
+}
+
+impl<A: Actor> MailboxRun for Mailbox<A> {
+    fn run(&self) -> usize {
+        let mut handled = 0;
+        loop {
+            let message = match self.pending.borrow_mut().pop_front() {
+                Some(message) => message,
+                None => break,
+            };
+            self.actor.borrow_mut().receive(message);
+            handled += 1;
+        }
+        handled
+    }
+
+    fn pending(&self) -> usize {
+        self.pending.borrow().len()
+    }
+}
+
+/// Registered actor
+pub(crate) struct ActorEntry {
+    id: String,
+    mailbox: Rc<dyn MailboxRun>,
+}
+
+/// Get the event messages of an actor are emitted as
+/// ## Parameters
+/// * `id` - The actor id
+/// ## Returns
+/// [`String`]
+pub fn actor_event_name(id: &str) -> String {
+    format!("actor.{}", id)
+}
+
+impl EventListener {
+    /// Register an actor receiving messages sent to `id`
+    ///
+    /// Replaces the actor previously registered with the same id, dropping its pending messages.
+    /// ## Parameters
+    /// * `id` - The actor id
+    /// * `actor` - The actor
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::actor::Actor;
+    /// use rust_event_listener::EventListener;
+    ///
+    /// struct Counter {
+    ///     total: i64,
+    /// }
+    ///
+    /// impl Actor for Counter {
+    ///     fn receive(&mut self, message: String) {
+    ///         self.total += message.parse::<i64>().unwrap_or(0);
+    ///         println!("total: {}", self.total);
+    ///     }
+    /// }
+    ///
+    /// let mut emitter = EventListener::new();
+    /// emitter.spawn_actor("counter", Counter { total: 0 });
+    /// emitter.send("counter", 2);
+    /// emitter.send("counter", 3);
+    /// assert_eq!(emitter.actor_pending("counter"), 2);
+    /// assert_eq!(emitter.run_actors(), 2); // total: 2, total: 5
+    /// ```
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn spawn_actor<A: Actor + 'static>(&mut self, id: &str, actor: A) {
+        self.stop_actor(id);
+        let mailbox = Rc::new(Mailbox {
+            actor: RefCell::new(actor),
+            pending: RefCell::new(VecDeque::new()),
+        });
+        let inbox = mailbox.clone();
+        self.add_listener(
+            &actor_event_name(id),
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |_, message| inbox.pending.borrow_mut().push_back(message)),
+            ),
+        );
+        self.actors.push(ActorEntry {
+            id: id.to_string(),
+            mailbox,
+        });
+    }
+
+    /// Send a message to an actor
+    /// ## Parameters
+    /// * `id` - The actor id
+    /// * `message` - The message, see [`crate::payload::IntoPayload`]
+    /// ## Returns
+    /// [`EmitOutcome`] - [`EmitOutcome::NoListeners`] if no actor has this id
+    #[track_caller]
+    pub fn send<P: crate::payload::IntoPayload>(&mut self, id: &str, message: P) -> EmitOutcome {
+        let name = actor_event_name(id);
+        if self.event(&name).is_none() {
+            return EmitOutcome::NoListeners;
+        }
+        self.emit(&name, message)
+    }
+
+    /// Hand pending messages to their actors, each mailbox is drained in order
+    /// ## Returns
+    /// [`usize`] - The number of handled messages
+    pub fn run_actors(&mut self) -> usize {
+        let mailboxes = self
+            .actors
+            .iter()
+            .map(|x| x.mailbox.clone())
+            .collect::<Vec<_>>();
+        mailboxes.iter().map(|x| x.run()).sum()
+    }
+
+    /// Get the number of messages waiting for an actor
+    /// ## Parameters
+    /// * `id` - The actor id
+    /// ## Returns
+    /// [`usize`]
+    pub fn actor_pending(&self, id: &str) -> usize {
+        self.actors
+            .iter()
+            .find(|x| x.id == id)
+            .map(|x| x.mailbox.pending())
+            .unwrap_or(0)
+    }
+
+    /// Unregister an actor, dropping its pending messages
+    /// ## Parameters
+    /// * `id` - The actor id
+    /// ## Returns
+    /// [`bool`] - `false` if no actor has this id
+    pub fn stop_actor(&mut self, id: &str) -> bool {
+        let before = self.actors.len();
+        self.actors.retain(|x| x.id != id);
+        if before == self.actors.len() {
+            return false;
+        }
+        self.remove_all_listeners(&actor_event_name(id));
+        true
+    }
+}
@@ -0,0 +1,66 @@
+use crate::EventListener;
+use std::borrow::Cow;
+
+impl EventListener {
+    /// Make an event name an alias of another event
+    ///
+    /// Emitting, subscribing to or removing listeners of either name uses the same
+    /// listener set, registered under the target name.
+    /// ## Parameters
+    /// * `alias` - The alternative name
+    /// * `target` - The event the alias resolves to
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.alias("quit", "exit");
+    /// emitter.on("quit", Box::new(|name, _| {
+    ///  assert_eq!(name, "exit");
+    /// }));
+    /// emitter.emit("exit", "".to_string());
+    /// emitter.emit("quit", "".to_string());
+    /// assert_eq!(emitter.get_listeners("exit").len(), 1);
+    /// ```
+    /// ## Panics
+    /// If the alias would resolve to itself
+    pub fn alias(&mut self, alias: &str, target: &str) {
+        let target = self.resolve_alias(target);
+        if target == alias {
+            panic!("Alias resolves to itself");
+        }
+        self.aliases.retain(|x| x.0 != alias);
+        self.aliases.push((alias.to_string(), target.into_owned()));
+    }
+
+    /// Remove an alias
+    /// ## Parameters
+    /// * `alias` - The alternative name
+    /// ## Returns
+    /// [`bool`] - `true` if the alias was removed
+    pub fn remove_alias(&mut self, alias: &str) -> bool {
+        let before = self.aliases.len();
+        self.aliases.retain(|x| x.0 != alias);
+        before != self.aliases.len()
+    }
+
+    /// Resolve an event name through aliases
+    /// ## Parameters
+    /// * `name` - The event name or alias
+    /// ## Returns
+    /// [`Cow<str>`] - The name listeners are registered under, borrows `name` if it isn't
+    /// an alias
+    pub fn resolve_alias<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        let mut target = None::<&String>;
+        while let Some(alias) = self
+            .aliases
+            .iter()
+            .find(|x| x.0 == target.map_or(name, |x| x.as_str()))
+        {
+            target = Some(&alias.1);
+        }
+        match target {
+            Some(target) => Cow::Owned(target.clone()),
+            None => Cow::Borrowed(name),
+        }
+    }
+}
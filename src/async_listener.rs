@@ -0,0 +1,205 @@
+use crate::executor::{BoxFuture, Executor, JoinAll};
+use crate::listener::ListenerHandle;
+use crate::outcome::EmitOutcome;
+use crate::payload::IntoPayload;
+use crate::EventListener;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Async listener callback returning a future
+pub type AsyncListenerCallback = Box<dyn Fn(String, String) -> BoxFuture>;
+
+/// Registered async listener
+pub struct AsyncListener {
+    /// Listener id
+    pub id: u64,
+    /// Event name
+    pub name: String,
+    /// Callback function
+    pub callback: AsyncListenerCallback,
+}
+
+impl core::fmt::Debug for AsyncListener {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "AsyncListener {{ id: {}, name: {:?}, callback: f' }}",
+            self.id, self.name
+        )
+    }
+}
+
+/// Future returned by [`EventListener::emit_async`]
+///
+/// Completes once every async listener of the emission completed. It is [`Send`] and
+/// doesn't borrow the emitter, so it can be spawned on a multi-threaded runtime or awaited
+/// while the emitter is used elsewhere.
+pub struct EmitAsync {
+    join: JoinAll,
+    outcome: Option<EmitOutcome>,
+}
+
+impl Future for EmitAsync {
+    type Output = EmitOutcome;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<EmitOutcome> {
+        match Pin::new(&mut self.join).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(
+                self.outcome
+                    .take()
+                    .expect("EmitAsync polled after completion"),
+            ),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl core::fmt::Debug for EmitAsync {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EmitAsync {{ outcome: {:?} }}", self.outcome)
+    }
+}
+
+impl EventListener {
+    /// Add an async listener, run by [`EventListener::emit_async`] and [`EventListener::emit_blocking`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function returning a future
+    /// ## Returns
+    /// [`ListenerHandle`]
+    pub fn on_async(&mut self, name: &str, callback: AsyncListenerCallback) -> ListenerHandle {
+        let resolved = self.resolve_alias(name);
+        let name = resolved.as_ref();
+        self.ensure_event(name);
+        self.emit_listener_change("newListener", name);
+        self.next_listener_id += 1;
+        self.async_listeners.push(AsyncListener {
+            id: self.next_listener_id,
+            name: name.to_string(),
+            callback,
+        });
+        self.notify_subscriber_watches(name);
+        ListenerHandle(self.next_listener_id)
+    }
+
+    /// Get async listeners of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Vec<&AsyncListener>`]
+    pub fn get_async_listeners(&self, name: &str) -> Vec<&AsyncListener> {
+        let name = self.resolve_alias(name);
+        self.async_listeners
+            .iter()
+            .filter(|x| x.name == name)
+            .collect()
+    }
+
+    /// Set the executor driving async listeners from synchronous code
+    /// ## Parameters
+    /// * `executor` - The executor, defaults to [`crate::executor::ThreadExecutor`]
+    pub fn set_executor(&mut self, executor: Box<dyn Executor>) {
+        self.executor = executor;
+    }
+
+    /// Emit an event and block until every async listener completed
+    ///
+    /// The emission goes through [`EventListener::emit`], synchronous listeners are called
+    /// first, then the futures of all async listeners are driven concurrently by the
+    /// configured executor.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_async("save", Box::new(|name, data| Box::pin(async move {
+    ///  println!("{}: {}", name, data);
+    /// })));
+    /// emitter.emit_blocking("save", "document".to_string());
+    /// ```
+    /// ## Returns
+    /// [`EmitOutcome`] - The delivered count includes async listeners
+    /// ## Panics
+    /// Like [`EventListener::emit`]
+    pub fn emit_blocking<P: IntoPayload>(&mut self, name: &str, data: P) -> EmitOutcome {
+        let EmitAsync { join, outcome } = self.emit_async(name, data);
+        if !join.is_empty() {
+            self.executor.block_on(Box::pin(join));
+        }
+        outcome.expect("EmitAsync polled after completion")
+    }
+
+    /// Emit an event and get a future awaiting every async listener
+    ///
+    /// The emission goes through [`EventListener::emit`] and synchronous listeners are
+    /// called before `emit_async` returns. Async listeners are only started if the emission
+    /// is delivered: cancelled, dropped, paused, buffered and queued emissions don't start
+    /// them. They are driven by whichever runtime awaits the returned future.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::executor::{Executor, ThreadExecutor};
+    /// use rust_event_listener::outcome::EmitOutcome;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("save", Box::new(|_, data| println!("saving {}", data)));
+    /// emitter.on_async("save", Box::new(|_, data| Box::pin(async move {
+    ///  println!("saved {}", data);
+    /// })));
+    /// let saved = emitter.emit_async("save", "document");
+    /// let worker = std::thread::spawn(move || {
+    ///  ThreadExecutor.block_on(Box::pin(async move {
+    ///   assert_eq!(saved.await, EmitOutcome::DeliveredTo(2));
+    ///  }));
+    /// });
+    /// worker.join().unwrap();
+    /// ```
+    /// ## Returns
+    /// [`EmitAsync`] - Resolves to the [`EmitOutcome`], the delivered count includes async listeners
+    /// ## Panics
+    /// Like [`EventListener::emit`]
+    pub fn emit_async<P: IntoPayload>(&mut self, name: &str, data: P) -> EmitAsync {
+        let previous = self.async_pending.replace(vec![]);
+        let outcome =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.emit(name, data)));
+        let futures = std::mem::replace(&mut self.async_pending, previous).unwrap_or_default();
+        let outcome = outcome.unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        EmitAsync {
+            join: JoinAll::new(futures.into_iter().map(|x| x.1).collect()),
+            outcome: Some(outcome),
+        }
+    }
+
+    /// Check whether [`EventListener::emit_async`] collects the futures of async listeners
+    pub(crate) fn collects_async(&self) -> bool {
+        self.async_pending.is_some()
+    }
+
+    /// Start the async listeners of a delivered emission, if they are collected
+    /// ## Returns
+    /// [`usize`] - The number of started listeners
+    pub(crate) fn start_async_listeners(&mut self, name: &str, data: &str) -> usize {
+        let pending = match &mut self.async_pending {
+            Some(pending) => pending,
+            None => return 0,
+        };
+        let before = pending.len();
+        pending.extend(
+            self.async_listeners
+                .iter()
+                .filter(|x| x.name == name)
+                .map(|x| {
+                    (
+                        ListenerHandle(x.id),
+                        (x.callback)(name.to_string(), data.to_string()),
+                    )
+                }),
+        );
+        pending.len() - before
+    }
+}
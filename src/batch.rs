@@ -0,0 +1,151 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::uncaught::{CallResult, ListenerError};
+use crate::EventListener;
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+/// Callback receiving batched payloads
+pub type BatchCallback = Box<dyn Fn(Vec<String>)>;
+
+/// Payloads accumulated for a batched listener
+pub(crate) struct Batcher {
+    pending: RefCell<(Vec<String>, Option<Instant>)>,
+    max_batch: usize,
+    max_delay: Duration,
+    callback: BatchCallback,
+    clock: crate::clock::Clock,
+    name: String,
+    handle: Cell<ListenerHandle>,
+}
+
+impl Batcher {
+    /// Deliver pending payloads if the batch is full, overdue or `force` is set
+    /// ## Returns
+    /// [`Option<CallResult>`] - `None` if no batch was delivered
+    fn deliver(&self, force: bool) -> Option<CallResult> {
+        let batch = {
+            let mut pending = self.pending.borrow_mut();
+            let due = match pending.1 {
+                Some(first) => {
+                    force
+                        || pending.0.len() >= self.max_batch
+                        || self.clock.now().duration_since(first) >= self.max_delay
+                }
+                None => false,
+            };
+            if !due {
+                return None;
+            }
+            pending.1 = None;
+            std::mem::take(&mut pending.0)
+        };
+        Some(
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (self.callback)(batch)))
+                .map_err(|panic| {
+                    let error = ListenerError::from_panic(&self.name, self.handle.get(), &*panic);
+                    (error, panic)
+                }),
+        )
+    }
+}
+
+impl EventListener {
+    /// Add a listener receiving payloads in batches
+    ///
+    /// A batch is delivered once it holds `max_batch` payloads, or once `max_delay` elapsed
+    /// since its first payload. Overdue batches are delivered on the next emission of the
+    /// event or by [`EventListener::flush_batches`]. Panics of the callback are reported
+    /// like panics of other listeners, see [`crate::uncaught`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `max_batch` - Max payloads per batch, `0` is treated as `1`
+    /// * `max_delay` - Max time a payload waits for its batch
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_batched("row", 2, Duration::from_secs(1), Box::new(|rows| {
+    ///  println!("inserting {} rows", rows.len());
+    /// }));
+    /// emitter.emit("row", "a".to_string());
+    /// emitter.emit("row", "b".to_string()); // inserting 2 rows
+    /// emitter.emit("row", "c".to_string());
+    /// assert_eq!(emitter.flush_all_batches(), 1); // inserting 1 rows
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_batched(
+        &mut self,
+        name: &str,
+        max_batch: usize,
+        max_delay: Duration,
+        callback: BatchCallback,
+    ) -> ListenerHandle {
+        let batcher = Rc::new(Batcher {
+            pending: RefCell::new((vec![], None)),
+            max_batch: max_batch.max(1),
+            max_delay,
+            callback,
+            clock: self.clock.clone(),
+            name: name.to_string(),
+            handle: Cell::new(ListenerHandle(0)),
+        });
+        self.batchers.push(Rc::downgrade(&batcher));
+        let registered = batcher.clone();
+        let handle = self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |_, data| {
+                    {
+                        let mut pending = batcher.pending.borrow_mut();
+                        pending.0.push(data);
+                        pending.1.get_or_insert_with(|| batcher.clock.now());
+                    }
+                    if let Some(Err((_, panic))) = batcher.deliver(false) {
+                        std::panic::resume_unwind(panic);
+                    }
+                }),
+            ),
+        );
+        registered.handle.set(handle);
+        handle
+    }
+
+    /// Deliver every overdue batch
+    ///
+    /// Panics of the callbacks are reported like during emission, see
+    /// [`crate::uncaught::UncaughtPolicy`].
+    /// ## Returns
+    /// [`usize`] - The number of delivered batches
+    pub fn flush_batches(&mut self) -> usize {
+        self.deliver_batches(false)
+    }
+
+    /// Deliver every pending batch regardless of its size and age
+    /// ## Returns
+    /// [`usize`] - The number of delivered batches
+    pub fn flush_all_batches(&mut self) -> usize {
+        self.deliver_batches(true)
+    }
+
+    fn deliver_batches(&mut self, force: bool) -> usize {
+        self.batchers.retain(|x| x.strong_count() > 0);
+        let batchers: Vec<_> = self.batchers.iter().filter_map(Weak::upgrade).collect();
+        let mut delivered = 0;
+        for batcher in batchers {
+            if let Some(result) = batcher.deliver(force) {
+                delivered += 1;
+                if let Err((error, panic)) = result {
+                    self.report_listener_error(error, panic);
+                }
+            }
+        }
+        delivered
+    }
+}
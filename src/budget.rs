@@ -0,0 +1,103 @@
+use crate::listener::{ListenerHandle, ListenerTypes};
+use crate::EventListener;
+use std::time::Duration;
+
+/// What happens to the remaining listeners once an emission exceeds its time budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    /// Defer the remaining listeners until [`EventListener::run_deferred`]
+    Defer,
+    /// Skip the remaining listeners
+    Skip,
+}
+
+/// Listener call postponed by an exceeded time budget
+#[derive(Debug, Clone)]
+pub struct DeferredCall {
+    /// The name of the event
+    pub name: String,
+    /// The emitted payload
+    pub data: String,
+    /// The listener to call
+    pub listener: ListenerHandle,
+}
+
+impl EventListener {
+    /// Limit the time a single emission spends calling listeners
+    ///
+    /// The budget is checked before each listener, the first listener is always called.
+    /// Once exceeded, the remaining listeners are deferred or skipped according to `policy`
+    /// and `budgetExceeded` is emitted with the event name as payload.
+    /// ## Parameters
+    /// * `budget` - The time budget, `None` removes the limit
+    /// * `policy` - What happens to the remaining listeners
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::budget::BudgetPolicy;
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_emit_budget(Some(Duration::from_millis(1)), BudgetPolicy::Defer);
+    /// emitter.on("tick", Box::new(|_, _| std::thread::sleep(Duration::from_millis(2))));
+    /// emitter.on("tick", Box::new(|_, frame| println!("late work for frame {}", frame)));
+    /// emitter.emit("tick", 1);
+    /// assert_eq!(emitter.get_deferred().len(), 1);
+    /// assert_eq!(emitter.run_deferred(), 1); // late work for frame 1
+    /// ```
+    pub fn set_emit_budget(&mut self, budget: Option<Duration>, policy: BudgetPolicy) {
+        self.emit_budget = budget.map(|x| (x, policy));
+    }
+
+    /// Get the per-emit time budget
+    /// ## Returns
+    /// [`Option<(Duration, BudgetPolicy)>`]
+    pub fn get_emit_budget(&self) -> Option<(Duration, BudgetPolicy)> {
+        self.emit_budget
+    }
+
+    /// Get listener calls deferred by exceeded budgets
+    /// ## Returns
+    /// [`Vec<&DeferredCall>`]
+    pub fn get_deferred(&self) -> Vec<&DeferredCall> {
+        self.deferred.iter().collect()
+    }
+
+    /// Call every listener deferred by exceeded budgets
+    ///
+    /// Calls are only deferred for listeners which accepted the emission, see
+    /// [`crate::listener::Listener::filter`]. Listeners removed since their call was
+    /// deferred, or whose group was paused since, are skipped.
+    /// ## Returns
+    /// [`usize`] - The number of called listeners
+    pub fn run_deferred(&mut self) -> usize {
+        let mut called = 0;
+        while let Some(call) = self.deferred.pop_front() {
+            let listener = self
+                .events
+                .iter()
+                .flat_map(|x| x.data.iter())
+                .find(|x| x.handle() == call.listener);
+            let (result, once) = match listener {
+                Some(listener)
+                    if !listener.group.as_ref().is_some_and(|group| {
+                        crate::quota::is_paused(&self.group_accounts, group)
+                    }) =>
+                {
+                    (
+                        crate::uncaught::call_listener(listener, &call.name, &call.data),
+                        matches!(listener.rtype, ListenerTypes::Once),
+                    )
+                }
+                _ => continue,
+            };
+            called += 1;
+            if once {
+                self.remove_listener_by_handle(call.listener);
+            }
+            if let Err((error, panic)) = result {
+                self.report_listener_error(error, panic);
+            }
+        }
+        called
+    }
+}
@@ -0,0 +1,212 @@
+use crate::listener::{
+    Listener, ListenerCallback, ListenerCallbackMut, ListenerHandle, ListenerTypes,
+};
+use crate::EventListener;
+use std::time::Duration;
+
+/// Fluent builder composing listener options before registration
+pub struct ListenBuilder<'a> {
+    emitter: &'a mut EventListener,
+    name: String,
+    rtype: ListenerTypes,
+    priority: i32,
+    filter: Option<crate::listener::ListenerFilter>,
+    throttle: Option<Duration>,
+    debounce: Option<Duration>,
+    group: Option<String>,
+    init: Option<crate::listener::ListenerInit>,
+    label: Option<String>,
+    after: Vec<String>,
+}
+
+impl<'a> ListenBuilder<'a> {
+    /// Call the listener only once
+    pub fn once(mut self) -> Self {
+        self.rtype = ListenerTypes::Once;
+        self
+    }
+
+    /// Set listener priority, higher priorities are called first
+    /// ## Parameters
+    /// * `priority` - The listener priority, defaults to `0`
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Only call the listener for payloads matching the predicate
+    /// ## Parameters
+    /// * `filter` - The payload predicate
+    pub fn filter<F: Fn(&String) -> bool + 'static>(mut self, filter: F) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Call the listener at most once per `period`, dropping emissions in between
+    ///
+    /// Behaves like [`EventListener::on_throttled`], the first emission of a period is
+    /// delivered right away. For trailing calls with the last payload use
+    /// [`ListenBuilder::debounce`].
+    /// ## Parameters
+    /// * `period` - Min time between two calls
+    pub fn throttle(mut self, period: Duration) -> Self {
+        self.throttle = Some(period);
+        self
+    }
+
+    /// Call the listener with the last payload once the event has been quiet for `period`
+    ///
+    /// Behaves like [`EventListener::on_debounced`], filters apply before emissions are
+    /// held back.
+    /// ## Parameters
+    /// * `period` - The quiet period
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_virtual_time(true);
+    /// emitter
+    ///     .listen("keystroke")
+    ///     .debounce(Duration::from_millis(300))
+    ///     .call(Box::new(|_, text| println!("searching {}", text)));
+    /// emitter.emit("keystroke", "ru");
+    /// emitter.emit("keystroke", "rust");
+    /// emitter.advance(Duration::from_millis(300)); // searching rust
+    /// ```
+    pub fn debounce(mut self, period: Duration) -> Self {
+        self.debounce = Some(period);
+        self
+    }
+
+    /// Put the listener in a group which can be removed at once with [`EventListener::remove_group`]
+    /// ## Parameters
+    /// * `group` - The group name
+    pub fn group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Call a hook on registration, before the listener receives any event
+    /// ## Parameters
+    /// * `init` - The hook, receiving the event's sticky value, see [`EventListener::make_sticky`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.make_sticky("theme");
+    /// emitter.on("theme", Box::new(|_, _| {}));
+    /// emitter.emit("theme", "dark".to_string());
+    /// emitter
+    ///     .listen("theme")
+    ///     .init(|current| assert_eq!(current, Some(&"dark".to_string())))
+    ///     .call(Box::new(|name, data| {
+    ///         println!("{}: {}", name, data);
+    ///     }));
+    /// ```
+    pub fn init<F: FnOnce(Option<&String>) + 'static>(mut self, init: F) -> Self {
+        self.init = Some(Box::new(init));
+        self
+    }
+
+    /// Label the listener so other listeners of the event can run after it
+    /// ## Parameters
+    /// * `label` - The label
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Run the listener after the listener labeled `label`, regardless of priorities
+    /// ## Parameters
+    /// * `label` - The label of the listener to run after
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.listen("save").after("validation").call(Box::new(|_, data| {
+    ///  println!("saving {}", data);
+    /// }));
+    /// emitter.listen("save").label("validation").call(Box::new(|_, data| {
+    ///  println!("validating {}", data);
+    /// }));
+    /// emitter.emit("save", "doc".to_string()); // validating doc, saving doc
+    /// ```
+    pub fn after(mut self, label: &str) -> Self {
+        self.after.push(label.to_string());
+        self
+    }
+
+    /// Register the listener with the composed options
+    /// ## Parameters
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if `after` dependencies form a cycle
+    pub fn call(self, callback: ListenerCallback) -> ListenerHandle {
+        let mut listener = Listener::new(self.rtype, callback);
+        listener.priority = self.priority;
+        listener.filter = self.filter;
+        listener.throttle = self.throttle;
+        listener.group = self.group;
+        listener.init = self.init;
+        listener.label = self.label;
+        listener.after = self.after;
+        match self.debounce {
+            Some(period) => self.emitter.add_debounced(&self.name, period, listener),
+            None => self.emitter.add_listener(&self.name, listener),
+        }
+    }
+
+    /// Register the listener with a callback that can mutate its captured state
+    /// ## Parameters
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if `after` dependencies form a cycle
+    pub fn call_mut(self, callback: ListenerCallbackMut) -> ListenerHandle {
+        self.call(crate::listener::from_mut(callback))
+    }
+}
+
+impl EventListener {
+    /// Start building a listener with fluent options
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter
+    ///     .listen("msg")
+    ///     .once()
+    ///     .priority(5)
+    ///     .filter(|data| !data.is_empty())
+    ///     .throttle(Duration::from_millis(10))
+    ///     .group("ui")
+    ///     .call(Box::new(|name, data| {
+    ///         println!("{}: {}", name, data);
+    ///     }));
+    /// emitter.emit("msg", "hello".to_string());
+    /// ```
+    /// ## Returns
+    /// [`ListenBuilder`]
+    pub fn listen(&mut self, name: &str) -> ListenBuilder<'_> {
+        ListenBuilder {
+            emitter: self,
+            name: name.to_string(),
+            rtype: ListenerTypes::On,
+            priority: 0,
+            filter: None,
+            throttle: None,
+            debounce: None,
+            group: None,
+            init: None,
+            label: None,
+            after: vec![],
+        }
+    }
+}
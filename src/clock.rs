@@ -0,0 +1,233 @@
+//! Virtual time
+//!
+//! Time-based features (timers, debounce, throttle, batch delays, rate limit windows and
+//! TTLs) read the emitter's [`Clock`]. In simulation mode the clock only moves when
+//! [`EventListener::advance`] is called, so a day of events can be replayed in seconds
+//! with deterministic results. Emission budgets and group quotas measure real CPU time
+//! and always use the wall clock.
+
+use crate::EventListener;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Shared time source of an emitter
+#[derive(Debug, Clone)]
+pub struct Clock {
+    base: Instant,
+    offset: Rc<Cell<Option<Duration>>>,
+}
+
+impl Clock {
+    /// Create a clock following the wall clock
+    pub fn new() -> Self {
+        Clock {
+            base: Instant::now(),
+            offset: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Get the current time
+    /// ## Returns
+    /// [`Instant`]
+    pub fn now(&self) -> Instant {
+        match self.offset.get() {
+            Some(offset) => self.base + offset,
+            None => Instant::now(),
+        }
+    }
+
+    /// Check whether the clock is virtual
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_virtual(&self) -> bool {
+        self.offset.get().is_some()
+    }
+
+    /// Switch between virtual and wall clock time, time never goes backwards when switching
+    pub(crate) fn set_virtual(&self, enabled: bool) {
+        match (enabled, self.offset.get()) {
+            (true, None) => self.offset.set(Some(self.base.elapsed())),
+            (false, Some(_)) => self.offset.set(None),
+            _ => {}
+        }
+    }
+
+    /// Move virtual time forward to an instant
+    pub(crate) fn set_now(&self, now: Instant) {
+        if self.is_virtual() && now > self.now() {
+            self.offset.set(Some(now - self.base));
+        }
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Identifier of a scheduled emission
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerId(u64);
+
+/// Emission scheduled for later
+#[derive(Debug)]
+pub(crate) struct Timer {
+    id: TimerId,
+    due: Instant,
+    interval: Option<Duration>,
+    name: String,
+    data: String,
+}
+
+impl EventListener {
+    /// Get the emitter's clock
+    /// ## Returns
+    /// [`Clock`]
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Switch simulation mode on or off
+    ///
+    /// While on, time only moves with [`EventListener::advance`].
+    /// ## Parameters
+    /// * `enabled` - `true` to run on virtual time
+    pub fn set_virtual_time(&mut self, enabled: bool) {
+        self.clock.set_virtual(enabled);
+    }
+
+    /// Emit an event once `delay` elapsed, see [`EventListener::run_timers`]
+    /// ## Parameters
+    /// * `delay` - The delay
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`TimerId`]
+    pub fn emit_after(&mut self, delay: Duration, name: &str, data: String) -> TimerId {
+        self.add_timer(delay, None, name, data)
+    }
+
+    /// Emit an event every `interval`, see [`EventListener::run_timers`]
+    /// ## Parameters
+    /// * `interval` - The interval, `0` is treated as one nanosecond
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`TimerId`]
+    pub fn emit_every(&mut self, interval: Duration, name: &str, data: String) -> TimerId {
+        let interval = interval.max(Duration::from_nanos(1));
+        self.add_timer(interval, Some(interval), name, data)
+    }
+
+    /// Cancel a scheduled emission
+    /// ## Parameters
+    /// * `id` - The timer id
+    /// ## Returns
+    /// [`bool`] - `false` if the timer already fired or was cancelled
+    pub fn cancel_timer(&mut self, id: TimerId) -> bool {
+        let before = self.timers.len();
+        self.timers.retain(|x| x.id != id);
+        before != self.timers.len()
+    }
+
+    /// Fire every due timer
+    ///
+    /// Also syncs the attached journal writer when its [`crate::persist::Durability::Interval`]
+    /// elapsed.
+    /// ## Returns
+    /// [`usize`] - The number of emitted events
+    /// ## Panics
+    /// If a scheduled event doesn't exist
+    pub fn run_timers(&mut self) -> usize {
+        let now = self.clock.now();
+        let fired = self.fire_timers_until(now);
+        #[cfg(feature = "persistence")]
+        self.commit_due_journal();
+        fired
+    }
+
+    /// Move virtual time forward, firing timers and delivering overdue batches and
+    /// debounced listeners on the way
+    /// ## Parameters
+    /// * `duration` - The time to advance by
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_virtual_time(true);
+    /// emitter.on("tick", Box::new(|_, data| println!("tick {}", data)));
+    /// emitter.emit_every(Duration::from_secs(60), "tick", "minute".to_string());
+    /// assert_eq!(emitter.advance(Duration::from_secs(24 * 60 * 60)), 24 * 60);
+    /// ```
+    /// ## Returns
+    /// [`usize`] - The number of emitted events
+    /// ## Panics
+    /// If virtual time is disabled, or if a scheduled event doesn't exist
+    pub fn advance(&mut self, duration: Duration) -> usize {
+        if !self.clock.is_virtual() {
+            panic!("Virtual time is disabled");
+        }
+        let target = self.clock.now() + duration;
+        let fired = self.fire_timers_until(target);
+        self.clock.set_now(target);
+        self.flush_batches();
+        self.flush_debounced();
+        fired
+    }
+
+    fn add_timer(
+        &mut self,
+        delay: Duration,
+        interval: Option<Duration>,
+        name: &str,
+        data: String,
+    ) -> TimerId {
+        self.next_timer_id += 1;
+        let id = TimerId(self.next_timer_id);
+        self.timers.push(Timer {
+            id,
+            due: self.clock.now() + delay,
+            interval,
+            name: name.to_string(),
+            data,
+        });
+        id
+    }
+
+    /// Fire timers due until `until` in deadline order, moving virtual time to each deadline
+    fn fire_timers_until(&mut self, until: Instant) -> usize {
+        let mut fired = 0;
+        loop {
+            let next = self
+                .timers
+                .iter()
+                .enumerate()
+                .filter(|x| x.1.due <= until)
+                .min_by_key(|x| (x.1.due, x.1.id.0))
+                .map(|x| x.0);
+            let index = match next {
+                Some(index) => index,
+                None => break,
+            };
+            let due = self.timers[index].due;
+            self.clock.set_now(due);
+            let (name, data) = match self.timers[index].interval {
+                Some(interval) => {
+                    let timer = &mut self.timers[index];
+                    timer.due += interval;
+                    (timer.name.clone(), timer.data.clone())
+                }
+                None => {
+                    let timer = self.timers.remove(index);
+                    (timer.name, timer.data)
+                }
+            };
+            self.emit(&name, data);
+            fired += 1;
+        }
+        fired
+    }
+}
@@ -0,0 +1,253 @@
+//! Event-driven configuration reload
+//!
+//! A [`ConfigWatcher`] reads `key = value` pairs from a file or from environment
+//! variables, and on every [`ConfigWatcher::poll`] emits `config.changed` with a
+//! [`ConfigDiff`] payload when the configuration changed. `config.changed` is made
+//! sticky, so listeners registered later can read the last diff in their init hook.
+
+use crate::json::{self, Value};
+use crate::EventListener;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+/// Event emitted when the configuration changed
+pub const CONFIG_CHANGED: &str = "config.changed";
+
+/// Where configuration values are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// File of `key = value` lines, `#` starts a comment line
+    File(PathBuf),
+    /// Environment variables starting with the prefix, keys are lowercased without the prefix
+    Env(String),
+}
+
+/// Difference between two configurations
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// Added keys and their values
+    pub added: BTreeMap<String, String>,
+    /// Removed keys
+    pub removed: Vec<String>,
+    /// Changed keys with their old and new values
+    pub changed: BTreeMap<String, (String, String)>,
+}
+
+impl ConfigDiff {
+    /// Compute the difference between two configurations
+    /// ## Parameters
+    /// * `old` - The previous configuration
+    /// * `new` - The current configuration
+    /// ## Returns
+    /// [`ConfigDiff`]
+    pub fn between(old: &BTreeMap<String, String>, new: &BTreeMap<String, String>) -> Self {
+        let mut diff = ConfigDiff::default();
+        for (key, value) in new {
+            match old.get(key) {
+                None => {
+                    diff.added.insert(key.clone(), value.clone());
+                }
+                Some(previous) if previous != value => {
+                    diff.changed
+                        .insert(key.clone(), (previous.clone(), value.clone()));
+                }
+                _ => {}
+            }
+        }
+        diff.removed = old
+            .keys()
+            .filter(|x| !new.contains_key(*x))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Check whether nothing changed
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Serialize the diff as the JSON payload of `config.changed`
+    ///
+    /// `{"added":{"key":"value"},"removed":["key"],"changed":{"key":{"old":"a","new":"b"}}}`
+    /// ## Returns
+    /// [`String`]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"added\":{");
+        for (index, (key, value)) in self.added.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            json::write_string(&mut out, key);
+            out.push(':');
+            json::write_string(&mut out, value);
+        }
+        out.push_str("},\"removed\":[");
+        for (index, key) in self.removed.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            json::write_string(&mut out, key);
+        }
+        out.push_str("],\"changed\":{");
+        for (index, (key, (old, new))) in self.changed.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            json::write_string(&mut out, key);
+            out.push_str(":{\"old\":");
+            json::write_string(&mut out, old);
+            out.push_str(",\"new\":");
+            json::write_string(&mut out, new);
+            out.push('}');
+        }
+        out.push_str("}}");
+        out
+    }
+
+    /// Parse the JSON payload of `config.changed`
+    /// ## Parameters
+    /// * `payload` - The payload
+    /// ## Returns
+    /// [`Option<ConfigDiff>`] - `None` if the payload is malformed
+    pub fn from_json(payload: &str) -> Option<Self> {
+        let value = json::parse(payload).ok()?;
+        let mut diff = ConfigDiff::default();
+        for (key, value) in value.get("added")?.as_object()? {
+            diff.added.insert(key.clone(), value.as_str()?.to_string());
+        }
+        for key in value.get("removed")?.as_array()? {
+            diff.removed.push(key.as_str()?.to_string());
+        }
+        for (key, value) in value.get("changed")?.as_object()? {
+            let old = value.get("old").and_then(Value::as_str)?.to_string();
+            let new = value.get("new").and_then(Value::as_str)?.to_string();
+            diff.changed.insert(key.clone(), (old, new));
+        }
+        Some(diff)
+    }
+}
+
+/// Configuration watcher emitting `config.changed`
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    source: ConfigSource,
+    values: BTreeMap<String, String>,
+    modified: Option<SystemTime>,
+}
+
+impl ConfigWatcher {
+    /// Create a watcher, nothing is read until the first poll
+    /// ## Parameters
+    /// * `source` - The configuration source
+    pub fn new(source: ConfigSource) -> Self {
+        ConfigWatcher {
+            source,
+            values: BTreeMap::new(),
+            modified: None,
+        }
+    }
+
+    /// Get the configuration source
+    /// ## Returns
+    /// [`&ConfigSource`]
+    pub fn source(&self) -> &ConfigSource {
+        &self.source
+    }
+
+    /// Get every configuration value
+    /// ## Returns
+    /// [`&BTreeMap<String, String>`]
+    pub fn values(&self) -> &BTreeMap<String, String> {
+        &self.values
+    }
+
+    /// Get a raw configuration value
+    /// ## Parameters
+    /// * `key` - The configuration key
+    /// ## Returns
+    /// [`Option<&str>`]
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|x| x.as_str())
+    }
+
+    /// Get a configuration value parsed as `T`
+    /// ## Parameters
+    /// * `key` - The configuration key
+    /// ## Returns
+    /// [`Option<T>`] - `None` if the key is missing or doesn't parse
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.get_str(key).and_then(|x| x.parse().ok())
+    }
+
+    /// Reload the configuration and emit `config.changed` if it changed
+    /// ## Parameters
+    /// * `emitter` - The emitter receiving `config.changed`
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::config::{ConfigDiff, ConfigSource, ConfigWatcher};
+    /// use rust_event_listener::EventListener;
+    /// let path = std::env::temp_dir().join("rust_event_listener_doc_config.conf");
+    /// std::fs::write(&path, "port = 8080\n").unwrap();
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("config.changed", Box::new(|_, payload| {
+    ///  let diff = ConfigDiff::from_json(&payload).unwrap();
+    ///  println!("{:?}", diff.added);
+    /// }));
+    /// let mut watcher = ConfigWatcher::new(ConfigSource::File(path.clone()));
+    /// assert!(watcher.poll(&mut emitter).unwrap());
+    /// assert_eq!(watcher.get::<u16>("port"), Some(8080));
+    /// assert!(!watcher.poll(&mut emitter).unwrap());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    /// ## Returns
+    /// [`std::io::Result<bool>`] - `true` if `config.changed` was emitted
+    pub fn poll(&mut self, emitter: &mut EventListener) -> std::io::Result<bool> {
+        let values = match &self.source {
+            ConfigSource::File(path) => {
+                let modified = std::fs::metadata(path)?.modified().ok();
+                if modified.is_some() && modified == self.modified {
+                    return Ok(false);
+                }
+                self.modified = modified;
+                parse_config(&std::fs::read_to_string(path)?)
+            }
+            ConfigSource::Env(prefix) => std::env::vars()
+                .filter_map(|(key, value)| {
+                    key.strip_prefix(prefix.as_str())
+                        .map(|x| (x.to_lowercase(), value))
+                })
+                .collect(),
+        };
+        let diff = ConfigDiff::between(&self.values, &values);
+        self.values = values;
+        if diff.is_empty() {
+            return Ok(false);
+        }
+        emitter.make_sticky(CONFIG_CHANGED);
+        if !emitter
+            .get_event_names()
+            .iter()
+            .any(|x| x == CONFIG_CHANGED)
+        {
+            emitter.buffer_until_listener(CONFIG_CHANGED, 1);
+        }
+        emitter.emit(CONFIG_CHANGED, diff.to_json());
+        Ok(true)
+    }
+}
+
+/// Parse `key = value` lines
+fn parse_config(content: &str) -> BTreeMap<String, String> {
+    content
+        .lines()
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty() && !x.starts_with('#'))
+        .filter_map(|x| x.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
@@ -0,0 +1,79 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::EventListener;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Control over the emission in progress, passed to listeners added with
+/// [`EventListener::on_controlled`]
+#[derive(Debug, Clone)]
+pub struct EventControl {
+    stopped: Rc<Cell<bool>>,
+}
+
+impl EventControl {
+    /// Prevent the remaining listeners from being called for this emission
+    pub fn stop_propagation(&self) {
+        self.stopped.set(true);
+    }
+
+    /// Check whether a listener stopped the emission
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.stopped.get()
+    }
+}
+
+impl EventListener {
+    /// Add a listener that can stop the emission from reaching the next listeners
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function, receiving the [`EventControl`] of the emission
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_controlled("submit", |_, form, control| {
+    ///  if form.is_empty() {
+    ///   control.stop_propagation();
+    ///  }
+    /// });
+    /// emitter.on("submit", Box::new(|_, form| println!("saving {}", form)));
+    /// emitter.emit("submit", ""); // nothing saved
+    /// emitter.emit("submit", "name=Ahmet"); // saving name=Ahmet
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_controlled<F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        F: Fn(String, String, &EventControl) + 'static,
+    {
+        let control = EventControl {
+            stopped: self.propagation.clone(),
+        };
+        self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |name, data| callback(name, data, &control)),
+            ),
+        )
+    }
+
+    /// Reset the propagation flag for a new emission, returning the flag of the outer one
+    pub(crate) fn start_propagation(&self) -> bool {
+        self.propagation.replace(false)
+    }
+
+    /// Check whether a listener stopped the emission in progress
+    pub(crate) fn propagation_stopped(&self) -> bool {
+        self.propagation.get()
+    }
+
+    /// Restore the propagation flag of the outer emission
+    pub(crate) fn end_propagation(&self, outer: bool) {
+        self.propagation.set(outer);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::listener::{Listener, ListenerCallback, ListenerHandle, ListenerTypes};
+use crate::uncaught::CallResult;
+use crate::EventListener;
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+/// Last emission held back from a debounced listener
+pub(crate) struct Debouncer {
+    pending: RefCell<Option<(String, String, Instant)>>,
+    period: Duration,
+    callback: ListenerCallback,
+    clock: crate::clock::Clock,
+    handle: Cell<ListenerHandle>,
+}
+
+impl Debouncer {
+    /// Call the listener with the last emission if it has been quiet for the period
+    /// ## Returns
+    /// [`Option<CallResult>`] - `None` if the listener wasn't called
+    fn deliver(&self) -> Option<CallResult> {
+        let (name, data) = {
+            let mut pending = self.pending.borrow_mut();
+            match &*pending {
+                Some((_, _, last)) if self.clock.now().duration_since(*last) >= self.period => {}
+                _ => return None,
+            }
+            let (name, data, _) = pending.take().unwrap();
+            (name, data)
+        };
+        Some(crate::uncaught::call_callback(
+            &self.callback,
+            self.handle.get(),
+            &name,
+            &data,
+        ))
+    }
+}
+
+impl EventListener {
+    /// Add a listener called with the last payload once the event has been quiet for `period`
+    ///
+    /// Each emission restarts the quiet period. Due calls are made by
+    /// [`EventListener::flush_debounced`] and when virtual time advances, see
+    /// [`EventListener::advance`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `period` - The quiet period
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_virtual_time(true);
+    /// emitter.on_debounced("keystroke", Duration::from_millis(300), Box::new(|_, text| {
+    ///  println!("searching {}", text);
+    /// }));
+    /// emitter.emit("keystroke", "r");
+    /// emitter.emit("keystroke", "ru");
+    /// emitter.emit("keystroke", "rust");
+    /// emitter.advance(Duration::from_millis(300)); // searching rust
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_debounced(
+        &mut self,
+        name: &str,
+        period: Duration,
+        callback: ListenerCallback,
+    ) -> ListenerHandle {
+        self.add_debounced(name, period, Listener::new(ListenerTypes::On, callback))
+    }
+
+    /// Register a listener whose calls are debounced, keeping its other options
+    ///
+    /// The listener's callback is moved into a [`Debouncer`] and replaced by one
+    /// recording the last accepted emission.
+    pub(crate) fn add_debounced(
+        &mut self,
+        name: &str,
+        period: Duration,
+        mut listener: Listener,
+    ) -> ListenerHandle {
+        let debouncer = Rc::new(Debouncer {
+            pending: RefCell::new(None),
+            period,
+            callback: std::mem::replace(&mut listener.callback, Box::new(|_, _| {})),
+            clock: self.clock.clone(),
+            handle: Cell::new(ListenerHandle(0)),
+        });
+        self.debouncers.push(Rc::downgrade(&debouncer));
+        let pending = debouncer.clone();
+        listener.callback = Box::new(move |name, data| {
+            let now = pending.clock.now();
+            *pending.pending.borrow_mut() = Some((name, data, now));
+        });
+        let handle = self.add_listener(name, listener);
+        debouncer.handle.set(handle);
+        handle
+    }
+
+    /// Add a listener called at most once per `period`, dropping emissions in between
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `period` - Min time between two calls
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_virtual_time(true);
+    /// emitter.on_throttled("resize", Duration::from_millis(100), Box::new(|_, size| {
+    ///  println!("layout for {}", size);
+    /// }));
+    /// emitter.emit("resize", "800x600"); // layout for 800x600
+    /// emitter.emit("resize", "801x600");
+    /// emitter.advance(Duration::from_millis(100));
+    /// emitter.emit("resize", "900x600"); // layout for 900x600
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_throttled(
+        &mut self,
+        name: &str,
+        period: Duration,
+        callback: ListenerCallback,
+    ) -> ListenerHandle {
+        let mut listener = Listener::new(ListenerTypes::On, callback);
+        listener.throttle = Some(period);
+        self.add_listener(name, listener)
+    }
+
+    /// Call every debounced listener whose event has been quiet for its period
+    ///
+    /// Panics of the listeners are reported like during emission, see
+    /// [`crate::uncaught::UncaughtPolicy`].
+    /// ## Returns
+    /// [`usize`] - The number of called listeners
+    pub fn flush_debounced(&mut self) -> usize {
+        self.debouncers.retain(|x| x.strong_count() > 0);
+        let debouncers: Vec<_> = self.debouncers.iter().filter_map(Weak::upgrade).collect();
+        let mut called = 0;
+        for debouncer in debouncers {
+            if let Some(result) = debouncer.deliver() {
+                called += 1;
+                if let Err((error, panic)) = result {
+                    self.report_listener_error(error, panic);
+                }
+            }
+        }
+        called
+    }
+}
@@ -0,0 +1,57 @@
+use crate::EventListener;
+
+/// How [`EventListener::emit`] delivers an event to its listeners
+///
+/// Listener callbacks aren't `Send`, so offloading to a thread pool isn't a dispatch
+/// mode; forward events to a worker thread with [`EventListener::ingest`] instead.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchMode {
+    /// Call listeners before `emit` returns
+    #[default]
+    Inline,
+    /// Enqueue the event, listeners are called by [`EventListener::process_pending`]
+    Queued,
+    /// Call listeners and drive async listeners to completion before `emit` returns,
+    /// like [`EventListener::emit_blocking`]
+    #[cfg(feature = "async")]
+    Async,
+}
+
+impl EventListener {
+    /// Set the dispatch mode of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `mode` - The dispatch mode
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::dispatch::DispatchMode;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("render", Box::new(|_, frame| println!("render {}", frame)));
+    /// emitter.on("analytics", Box::new(|_, hit| println!("track {}", hit)));
+    /// emitter.set_dispatch_mode("analytics", DispatchMode::Queued);
+    /// emitter.emit("render", "1".to_string()); // render 1
+    /// emitter.emit("analytics", "click".to_string());
+    /// assert_eq!(emitter.pending_count(), 1);
+    /// emitter.process_pending(0); // track click
+    /// ```
+    pub fn set_dispatch_mode(&mut self, name: &str, mode: DispatchMode) {
+        self.dispatch_modes.retain(|x| x.0 != name);
+        if mode != DispatchMode::Inline {
+            self.dispatch_modes.push((name.to_string(), mode));
+        }
+    }
+
+    /// Get the dispatch mode of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`DispatchMode`] - [`DispatchMode::Inline`] unless configured otherwise
+    pub fn get_dispatch_mode(&self, name: &str) -> DispatchMode {
+        self.dispatch_modes
+            .iter()
+            .find(|x| x.0 == name)
+            .map(|x| x.1)
+            .unwrap_or_default()
+    }
+}
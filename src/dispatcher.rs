@@ -0,0 +1,166 @@
+//! Dedicated dispatcher thread
+//!
+//! [`EventListener::spawn_dispatcher`] moves an emitter to a background thread. Emissions
+//! from any thread are sent over a channel and the dispatcher thread calls the listeners
+//! one emission at a time, so slow listeners never block emitters and callbacks don't
+//! need to be `Send`.
+use crate::payload::IntoPayload;
+use crate::EventListener;
+use std::sync::mpsc::{channel, Sender};
+use std::thread::JoinHandle;
+
+/// Work sent to the dispatcher thread
+enum Command {
+    Emit(String, String),
+    Call(Box<dyn FnOnce(&mut EventListener) + Send>),
+    Stop,
+}
+
+/// Cloneable, thread-safe handle emitting on a [`Dispatcher`]
+#[derive(Clone)]
+pub struct DispatchHandle {
+    sender: Sender<Command>,
+}
+
+impl DispatchHandle {
+    /// Send an emission to the dispatcher thread
+    ///
+    /// Emissions of events without listeners are ignored by the dispatcher thread.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Returns
+    /// [`bool`] - `false` if the dispatcher stopped
+    pub fn emit<P: IntoPayload>(&self, name: &str, data: P) -> bool {
+        self.sender
+            .send(Command::Emit(name.to_string(), data.into_payload()))
+            .is_ok()
+    }
+
+    /// Run a function with the emitter on the dispatcher thread, e.g. to add listeners
+    /// ## Parameters
+    /// * `call` - The function
+    /// ## Returns
+    /// [`bool`] - `false` if the dispatcher stopped
+    pub fn with<F: FnOnce(&mut EventListener) + Send + 'static>(&self, call: F) -> bool {
+        self.sender.send(Command::Call(Box::new(call))).is_ok()
+    }
+}
+
+impl std::fmt::Debug for DispatchHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DispatchHandle").finish_non_exhaustive()
+    }
+}
+
+/// Background thread owning an emitter, see [`EventListener::spawn_dispatcher`]
+///
+/// Dropping the dispatcher dispatches the emissions already sent, then stops the thread.
+#[derive(Debug)]
+pub struct Dispatcher {
+    handle: DispatchHandle,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Dispatcher {
+    /// Get a handle emitting on this dispatcher from other threads
+    /// ## Returns
+    /// [`DispatchHandle`]
+    pub fn handle(&self) -> DispatchHandle {
+        self.handle.clone()
+    }
+
+    /// Send an emission to the dispatcher thread, see [`DispatchHandle::emit`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Returns
+    /// [`bool`] - `false` if the dispatcher stopped
+    pub fn emit<P: IntoPayload>(&self, name: &str, data: P) -> bool {
+        self.handle.emit(name, data)
+    }
+
+    /// Run a function with the emitter on the dispatcher thread, see [`DispatchHandle::with`]
+    /// ## Parameters
+    /// * `call` - The function
+    /// ## Returns
+    /// [`bool`] - `false` if the dispatcher stopped
+    pub fn with<F: FnOnce(&mut EventListener) + Send + 'static>(&self, call: F) -> bool {
+        self.handle.with(call)
+    }
+
+    /// Dispatch the emissions already sent, then stop the thread
+    ///
+    /// Emissions sent by remaining handles afterwards are rejected.
+    /// ## Returns
+    /// [`std::thread::Result<()>`] - `Err` if a listener panicked on the dispatcher thread
+    pub fn shutdown(mut self) -> std::thread::Result<()> {
+        self.stop()
+    }
+
+    fn stop(&mut self) -> std::thread::Result<()> {
+        let _ = self.handle.sender.send(Command::Stop);
+        match self.thread.take() {
+            Some(thread) => thread.join(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Dispatcher {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+impl EventListener {
+    /// Create an emitter on a dedicated dispatcher thread
+    ///
+    /// The emitter is created on the dispatcher thread and passed to `setup`, which
+    /// registers its listeners. Emissions sent with [`Dispatcher::emit`] or a
+    /// [`DispatchHandle`] are dispatched in the order they were received.
+    /// ## Parameters
+    /// * `setup` - Configures the emitter on the dispatcher thread
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let dispatcher = EventListener::spawn_dispatcher(|emitter| {
+    ///  emitter.on("job", Box::new(|_, id| println!("job {} done", id)));
+    /// });
+    /// let workers = (0..4)
+    ///     .map(|id| {
+    ///         let handle = dispatcher.handle();
+    ///         std::thread::spawn(move || assert!(handle.emit("job", id)))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// for worker in workers {
+    ///     worker.join().unwrap();
+    /// }
+    /// dispatcher.shutdown().unwrap();
+    /// ```
+    /// ## Returns
+    /// [`Dispatcher`]
+    pub fn spawn_dispatcher<F>(setup: F) -> Dispatcher
+    where
+        F: FnOnce(&mut EventListener) + Send + 'static,
+    {
+        let (sender, receiver) = channel();
+        let thread = std::thread::spawn(move || {
+            let mut emitter = EventListener::new();
+            setup(&mut emitter);
+            for command in receiver {
+                match command {
+                    Command::Emit(name, data) => {
+                        let _ = emitter.try_emit(&name, data);
+                    }
+                    Command::Call(call) => call(&mut emitter),
+                    Command::Stop => break,
+                }
+            }
+        });
+        Dispatcher {
+            handle: DispatchHandle { sender },
+            thread: Some(thread),
+        }
+    }
+}
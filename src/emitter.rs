@@ -0,0 +1,295 @@
+use crate::listener::{ListenerCallback, ListenerHandle};
+use crate::outcome::{EmitError, EmitOutcome};
+use crate::sync::{SyncEventListener, SyncListenerCallback};
+use crate::EventListener;
+
+/// Common interface of event emitters
+///
+/// Application code can depend on this trait instead of [`EventListener`] so tests
+/// can substitute a fake emitter. It is implemented for [`SyncEventListener`] too, with
+/// thread safe callbacks, so code can be written once for both emitters.
+/// ## Example
+/// ```
+/// use rust_event_listener::emitter::Emitter;
+/// use rust_event_listener::listener::{ListenerCallback, ListenerHandle};
+/// use rust_event_listener::outcome::EmitOutcome;
+/// use rust_event_listener::EventListener;
+///
+/// fn greet<E: Emitter>(emitter: &mut E) {
+///     emitter.emit("greet", "hello".to_string());
+/// }
+///
+/// #[derive(Default)]
+/// struct Recorder {
+///     inner: EventListener,
+///     emitted: Vec<(String, String)>,
+/// }
+///
+/// impl Emitter for Recorder {
+///     type Callback = ListenerCallback;
+///     fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+///         self.inner.on(name, callback)
+///     }
+///     fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+///         self.inner.once(name, callback)
+///     }
+///     fn emit(&mut self, name: &str, data: String) -> EmitOutcome {
+///         self.emitted.push((name.to_string(), data.clone()));
+///         self.inner.emit(name, data)
+///     }
+///     fn remove_all_listeners(&mut self, name: &str) -> bool {
+///         self.inner.remove_all_listeners(name)
+///     }
+///     fn off(&mut self, handle: ListenerHandle) -> bool {
+///         self.inner.off(handle)
+///     }
+/// }
+///
+/// let mut recorder = Recorder::default();
+/// let handle = recorder.on("greet", Box::new(|_, data| println!("{}", data)));
+/// greet(&mut recorder);
+/// assert_eq!(recorder.emitted, vec![("greet".to_string(), "hello".to_string())]);
+/// assert!(recorder.off(handle));
+/// ```
+pub trait Emitter {
+    /// Callback type accepted by the emitter, [`ListenerCallback`] or
+    /// [`SyncListenerCallback`]
+    type Callback;
+
+    /// Add a new listener to the event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    fn on(&mut self, name: &str, callback: Self::Callback) -> ListenerHandle;
+
+    /// Add a listener that will be called only once
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    fn once(&mut self, name: &str, callback: Self::Callback) -> ListenerHandle;
+
+    /// Emit an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`EmitOutcome`]
+    fn emit(&mut self, name: &str, data: String) -> EmitOutcome;
+
+    /// Emit an event, returning an error instead of panicking if it doesn't exist
+    ///
+    /// The default implementation forwards to [`Emitter::emit`], emitters panicking on
+    /// unknown events override it.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`Result<EmitOutcome, EmitError>`]
+    fn try_emit(&mut self, name: &str, data: String) -> Result<EmitOutcome, EmitError> {
+        Ok(self.emit(name, data))
+    }
+
+    /// Remove all listeners of event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if the event was removed, `false` if it wasn't
+    fn remove_all_listeners(&mut self, name: &str) -> bool;
+
+    /// Remove a single listener
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered
+    fn off(&mut self, handle: ListenerHandle) -> bool;
+
+    /// Create a sink forwarding `(name, payload)` pairs into this emitter
+    /// ## Returns
+    /// [`crate::sink::EmitSink`]
+    fn sink(&mut self) -> crate::sink::EmitSink<'_, Self>
+    where
+        Self: Sized,
+    {
+        crate::sink::EmitSink::new(self)
+    }
+}
+
+impl Emitter for EventListener {
+    type Callback = ListenerCallback;
+
+    fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        EventListener::on(self, name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        EventListener::once(self, name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> EmitOutcome {
+        EventListener::emit(self, name, data)
+    }
+
+    fn try_emit(&mut self, name: &str, data: String) -> Result<EmitOutcome, EmitError> {
+        self.check_emit(name, &data)?;
+        Ok(EventListener::emit(self, name, data))
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        EventListener::remove_all_listeners(self, name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        EventListener::off(self, handle)
+    }
+}
+
+/// Shared emitters are used through `&SyncEventListener`, so an `Arc` can be handed to
+/// code written against [`Emitter`] without locking it.
+/// ## Example
+/// ```
+/// use rust_event_listener::emitter::Emitter;
+/// use rust_event_listener::outcome::EmitOutcome;
+/// use rust_event_listener::sync::SyncEventListener;
+/// use std::sync::Arc;
+/// fn ping<E: Emitter>(emitter: &mut E) -> EmitOutcome {
+///     emitter.emit("ping", "1".to_string())
+/// }
+///
+/// let shared = Arc::new(SyncEventListener::new());
+/// let handle = shared.on("ping", Box::new(|_, data| println!("ping {}", data)));
+/// assert_eq!(ping(&mut &*shared), EmitOutcome::DeliveredTo(1));
+/// assert!(shared.off(handle));
+/// ```
+impl Emitter for &SyncEventListener {
+    type Callback = SyncListenerCallback;
+
+    fn on(&mut self, name: &str, callback: SyncListenerCallback) -> ListenerHandle {
+        SyncEventListener::on(self, name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: SyncListenerCallback) -> ListenerHandle {
+        SyncEventListener::once(self, name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> EmitOutcome {
+        match SyncEventListener::emit(self, name, data) {
+            0 => EmitOutcome::NoListeners,
+            called => EmitOutcome::DeliveredTo(called),
+        }
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        SyncEventListener::remove_all_listeners(self, name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        SyncEventListener::off(self, handle)
+    }
+}
+
+impl Emitter for SyncEventListener {
+    type Callback = SyncListenerCallback;
+
+    fn on(&mut self, name: &str, callback: SyncListenerCallback) -> ListenerHandle {
+        SyncEventListener::on(self, name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: SyncListenerCallback) -> ListenerHandle {
+        SyncEventListener::once(self, name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> EmitOutcome {
+        Emitter::emit(&mut &*self, name, data)
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        SyncEventListener::remove_all_listeners(self, name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        SyncEventListener::off(self, handle)
+    }
+}
+
+/// Owned, type-erased emitter
+///
+/// Frameworks can store emitters of different types in one registry and inject them
+/// into components without making every component generic.
+/// ## Example
+/// ```
+/// use rust_event_listener::emitter::{BoxEmitter, Emitter};
+/// use rust_event_listener::EventListener;
+/// let mut registry: Vec<(&str, BoxEmitter)> = vec![];
+/// let mut bus = EventListener::new();
+/// bus.on("ready", Box::new(|_, data| println!("ready: {}", data)));
+/// registry.push(("bus", BoxEmitter::new(bus)));
+/// for (_, emitter) in &mut registry {
+///     emitter.emit("ready", "1".to_string());
+/// }
+/// ```
+pub struct BoxEmitter {
+    inner: Box<dyn Emitter<Callback = ListenerCallback>>,
+}
+
+impl BoxEmitter {
+    /// Box an emitter
+    /// ## Parameters
+    /// * `emitter` - The emitter
+    pub fn new<E: Emitter<Callback = ListenerCallback> + 'static>(emitter: E) -> Self {
+        BoxEmitter {
+            inner: Box::new(emitter),
+        }
+    }
+
+    /// Get the boxed emitter
+    /// ## Returns
+    /// [`&mut dyn Emitter`]
+    pub fn as_dyn(&mut self) -> &mut dyn Emitter<Callback = ListenerCallback> {
+        &mut *self.inner
+    }
+
+    /// Unwrap the boxed emitter
+    /// ## Returns
+    /// [`Box<dyn Emitter>`]
+    pub fn into_inner(self) -> Box<dyn Emitter<Callback = ListenerCallback>> {
+        self.inner
+    }
+}
+
+impl std::fmt::Debug for BoxEmitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoxEmitter {{ inner: dyn Emitter }}")
+    }
+}
+
+impl Emitter for BoxEmitter {
+    type Callback = ListenerCallback;
+
+    fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        self.inner.on(name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        self.inner.once(name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> EmitOutcome {
+        self.inner.emit(name, data)
+    }
+
+    fn try_emit(&mut self, name: &str, data: String) -> Result<EmitOutcome, EmitError> {
+        self.inner.try_emit(name, data)
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        self.inner.remove_all_listeners(name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        self.inner.off(handle)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::payload::IntoPayload;
+use crate::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::SystemTime;
+
+/// Payload of an emission with its ordering and provenance, see [`EventListener::on_with_meta`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventEnvelope {
+    /// Event name, after routing and aliases
+    pub name: String,
+    /// Sequence number of the emission, increasing across all events of the emitter
+    pub seq: u64,
+    /// Time of the emission
+    pub timestamp: SystemTime,
+    /// Source given to [`EventListener::emit_from`], or the source location of the emit
+    /// call as `file:line:column` with the `debug-location` feature
+    pub source: Option<String>,
+    /// Event payload
+    pub payload: String,
+}
+
+/// Envelope of the emission in progress, shared with envelope listeners
+pub(crate) type EnvelopeSlot = Rc<RefCell<Option<EventEnvelope>>>;
+
+impl EventListener {
+    /// Add a listener receiving payloads wrapped in an [`EventEnvelope`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_with_meta("order.paid", |envelope| {
+    ///  println!(
+    ///   "#{} {} from {:?}: {}",
+    ///   envelope.seq, envelope.name, envelope.source, envelope.payload
+    ///  );
+    /// });
+    /// emitter.emit_from("order.paid", "billing-service", 42); // #0 order.paid from Some("billing-service"): 42
+    /// emitter.emit("order.paid", 43); // #1 order.paid from None: 43
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_with_meta<F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        F: Fn(&EventEnvelope) + 'static,
+    {
+        let slot = self.envelope_slot.clone();
+        self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |_, _| {
+                    if let Some(envelope) = &*slot.borrow() {
+                        callback(envelope);
+                    }
+                }),
+            ),
+        )
+    }
+
+    /// Emit an event recording where it comes from
+    ///
+    /// The source is only attached to emissions dispatched inline.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `source` - The source of the emission, see [`EventEnvelope::source`]
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`]
+    /// ## Panics
+    /// If neither the event nor a matching pattern exists
+    #[track_caller]
+    pub fn emit_from<P: IntoPayload>(
+        &mut self,
+        name: &str,
+        source: &str,
+        data: P,
+    ) -> crate::outcome::EmitOutcome {
+        let previous = self.emit_source.replace(source.to_string());
+        let outcome = self.emit(name, data);
+        self.emit_source = previous;
+        outcome
+    }
+
+    /// Build the envelope of an emission, returning the envelope of the outer one
+    pub(crate) fn open_envelope(&mut self, name: &str, data: &str) -> Option<EventEnvelope> {
+        let seq = self.emission_seq;
+        self.emission_seq += 1;
+        // Envelope listeners hold clones of the slot, skip building unseen envelopes
+        if Rc::strong_count(&self.envelope_slot) == 1 {
+            return self.envelope_slot.borrow_mut().take();
+        }
+        let source = self
+            .emit_source
+            .take()
+            .or_else(|| self.emit_location.map(|x| x.to_string()));
+        self.envelope_slot.replace(Some(EventEnvelope {
+            name: name.to_string(),
+            seq,
+            timestamp: SystemTime::now(),
+            source,
+            payload: data.to_string(),
+        }))
+    }
+
+    /// Restore the envelope of the outer emission
+    pub(crate) fn close_envelope(&mut self, outer: Option<EventEnvelope>) {
+        *self.envelope_slot.borrow_mut() = outer;
+    }
+}
@@ -0,0 +1,84 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::Thread;
+
+/// Boxed future returned by async listeners, [`Send`] so it can run on any thread
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Boxed future driven by an [`Executor`], it may hold thread-local state
+pub type LocalBoxFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Drives async listener futures to completion from synchronous code
+pub trait Executor {
+    /// Run a future to completion, blocking the current thread
+    /// ## Parameters
+    /// * `future` - The future to run
+    fn block_on(&self, future: LocalBoxFuture);
+}
+
+/// Executor parking the current thread until the future is woken
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadExecutor;
+
+pub(crate) struct ThreadWaker(pub(crate) Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+impl Executor for ThreadExecutor {
+    fn block_on(&self, mut future: LocalBoxFuture) {
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut context = Context::from_waker(&waker);
+        while future.as_mut().poll(&mut context).is_pending() {
+            std::thread::park();
+        }
+    }
+}
+
+/// Future completing once every inner future completed
+pub(crate) struct JoinAll {
+    futures: Vec<Option<BoxFuture>>,
+}
+
+impl JoinAll {
+    pub(crate) fn new(futures: Vec<BoxFuture>) -> Self {
+        JoinAll {
+            futures: futures.into_iter().map(Some).collect(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.futures.is_empty()
+    }
+}
+
+impl Future for JoinAll {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut pending = false;
+        for slot in self.futures.iter_mut() {
+            if let Some(future) = slot {
+                if future.as_mut().poll(cx).is_ready() {
+                    *slot = None;
+                } else {
+                    pending = true;
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::payload::IntoPayload;
+use crate::EventListener;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// What [`EventListener::emit_fallible`] does when a listener fails
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FailureStrategy {
+    /// Call every listener and return all failures
+    #[default]
+    CollectAll,
+    /// Skip the remaining listeners after the first failure
+    StopOnFirst,
+}
+
+/// Failures collected by the fallible emission in progress
+#[derive(Default)]
+pub(crate) struct FailureState {
+    collecting: bool,
+    strategy: FailureStrategy,
+    failures: Vec<(ListenerHandle, Box<dyn Any>)>,
+}
+
+/// Failure state shared with fallible listeners
+pub(crate) type FailureSlot = Rc<RefCell<FailureState>>;
+
+impl EventListener {
+    /// Add a listener whose callback can fail
+    ///
+    /// Failures are returned by [`EventListener::emit_fallible`]. Emitted any other way,
+    /// a failure is handled like a panicking listener, see [`crate::uncaught`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::fallible::FailureStrategy;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let parser = emitter.on_fallible("line", |_, line| {
+    ///  line.parse::<i32>().map(|_| ()).map_err(|x| x.to_string())
+    /// });
+    /// emitter.on_fallible("line", |_, line| {
+    ///  if line.is_empty() { Err("empty line".to_string()) } else { Ok(()) }
+    /// });
+    /// assert_eq!(emitter.emit_fallible::<_, String>("line", "42", FailureStrategy::CollectAll), Ok(2));
+    /// let failures = emitter
+    ///     .emit_fallible::<_, String>("line", "x", FailureStrategy::CollectAll)
+    ///     .unwrap_err();
+    /// assert_eq!(failures, vec![(parser, "invalid digit found in string".to_string())]);
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_fallible<E, F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        E: Debug + 'static,
+        F: Fn(String, String) -> Result<(), E> + 'static,
+    {
+        let slot = self.failure_slot.clone();
+        let propagation = self.propagation.clone();
+        let own_handle = Rc::new(Cell::new(ListenerHandle(0)));
+        let handle_cell = own_handle.clone();
+        let handle = self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |name, data| {
+                    let error = match callback(name, data) {
+                        Ok(()) => return,
+                        Err(error) => error,
+                    };
+                    let mut state = slot.borrow_mut();
+                    if !state.collecting {
+                        drop(state);
+                        panic!("{:?}", error);
+                    }
+                    if state.strategy == FailureStrategy::StopOnFirst {
+                        propagation.set(true);
+                    }
+                    state.failures.push((handle_cell.get(), Box::new(error)));
+                }),
+            ),
+        );
+        own_handle.set(handle);
+        handle
+    }
+
+    /// Emit an event, collecting the failures of listeners added with
+    /// [`EventListener::on_fallible`]
+    ///
+    /// Listeners are called inline whatever the dispatch mode of the event, and only
+    /// failures of type `E` are returned.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// * `strategy` - Whether to stop at the first failure
+    /// ## Returns
+    /// [`Result<usize, Vec<(ListenerHandle, E)>>`] - The number of called listeners, or
+    /// the failures in call order
+    /// ## Panics
+    /// If neither the event nor a matching pattern exists
+    #[track_caller]
+    pub fn emit_fallible<P: IntoPayload, E: 'static>(
+        &mut self,
+        name: &str,
+        data: P,
+        strategy: FailureStrategy,
+    ) -> Result<usize, Vec<(ListenerHandle, E)>> {
+        let outer = self.failure_slot.replace(FailureState {
+            collecting: true,
+            strategy,
+            failures: vec![],
+        });
+        let outcome = self.emit_inline(name, data.into_payload());
+        let state = self.failure_slot.replace(outer);
+        let failures = state
+            .failures
+            .into_iter()
+            .filter_map(|(handle, error)| error.downcast::<E>().ok().map(|x| (handle, *x)))
+            .collect::<Vec<_>>();
+        if !failures.is_empty() {
+            return Err(failures);
+        }
+        Ok(match outcome {
+            crate::outcome::EmitOutcome::DeliveredTo(called) => called,
+            _ => 0,
+        })
+    }
+}
@@ -0,0 +1,171 @@
+use crate::listener::{ListenerCallback, ListenerHandle};
+use crate::EventListener;
+
+/// Match an event name against a glob pattern
+///
+/// `*` matches any sequence of characters (including none) and `?` matches exactly one character.
+/// ## Parameters
+/// * `pattern` - The glob pattern
+/// * `name` - The event name
+/// ## Example
+/// ```
+/// use rust_event_listener::glob::matches;
+/// assert!(matches("db.*", "db.query"));
+/// assert!(matches("user.?", "user.1"));
+/// assert!(!matches("db.*", "cache.query"));
+/// ```
+/// ## Returns
+/// [`bool`] - `true` if the name matches the pattern
+pub fn matches(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|x| *x == '*')
+}
+
+/// Check whether an event name contains glob wildcards
+///
+/// Names are only matched as patterns when registered with [`EventListener::on_pattern`]
+/// or [`EventListener::once_pattern`], [`EventListener::on`] treats `*` and `?` literally.
+/// ## Parameters
+/// * `name` - The event name
+/// ## Example
+/// ```
+/// use rust_event_listener::glob::is_pattern;
+/// assert!(is_pattern("user.*"));
+/// assert!(!is_pattern("user.login"));
+/// ```
+/// ## Returns
+/// [`bool`] - `true` if the name contains `*` or `?`
+pub fn is_pattern(name: &str) -> bool {
+    name.contains(['*', '?'])
+}
+
+impl EventListener {
+    /// Add a new listener receiving emissions of every event matching a glob pattern
+    ///
+    /// Once registered as a pattern, the name stays a pattern: listeners added later with
+    /// [`EventListener::on`] under the same name also receive matching emissions.
+    /// ## Parameters
+    /// * `pattern` - The glob pattern, see [`matches`]
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_pattern("user.*", Box::new(|name, data| println!("{}: {}", name, data)));
+    /// emitter.on_pattern("*", Box::new(|name, _| println!("saw {}", name)));
+    /// emitter.emit("user.login", "alice"); // user.login: alice, saw user.login
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_pattern(&mut self, pattern: &str, callback: ListenerCallback) -> ListenerHandle {
+        self.mark_pattern(pattern);
+        self.on(pattern, callback)
+    }
+
+    /// Add a listener that will be called only once, by the first event matching a glob pattern
+    /// ## Parameters
+    /// * `pattern` - The glob pattern, see [`matches`]
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once_pattern(&mut self, pattern: &str, callback: ListenerCallback) -> ListenerHandle {
+        self.mark_pattern(pattern);
+        self.once(pattern, callback)
+    }
+
+    /// Create the event of a pattern if needed and match emissions against its name
+    pub(crate) fn mark_pattern(&mut self, pattern: &str) {
+        let resolved = self.resolve_alias(pattern).into_owned();
+        self.ensure_event(&resolved);
+        let index = self.event_index[resolved.as_str()];
+        if !self.pattern_events.contains(&index) {
+            self.pattern_events.push(index);
+        }
+    }
+
+    /// Check whether an emission of `name` reaches an event, directly or through a pattern
+    pub(crate) fn has_subscription(&self, name: &str) -> bool {
+        !self.subscribed_events(name).is_empty()
+    }
+
+    /// Remove all listeners of every event matching a glob pattern
+    /// ## Parameters
+    /// * `pattern` - The glob pattern, see [`matches`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("db.query", Box::new(|_, _| {}));
+    /// emitter.on("db.commit", Box::new(|_, _| {}));
+    /// assert_eq!(emitter.remove_listeners_matching("db.*"), 2);
+    /// ```
+    /// ## Returns
+    /// [`usize`] - The number of removed listeners, async listeners included
+    pub fn remove_listeners_matching(&mut self, pattern: &str) -> usize {
+        let mut removed = vec![];
+        for event in self.events.iter_mut().filter(|x| matches(pattern, &x.name)) {
+            removed.extend(std::iter::repeat_n(event.name.clone(), event.data.len()));
+            event.data.clear();
+        }
+        #[cfg(feature = "async")]
+        self.async_listeners.retain(|x| {
+            let matched = matches(pattern, &x.name);
+            if matched {
+                removed.push(x.name.clone().into());
+            }
+            !matched
+        });
+        for name in &removed {
+            self.emit_listener_change("removeListener", name);
+        }
+        removed.len()
+    }
+
+    /// Count listeners of every event matching a glob pattern
+    /// ## Parameters
+    /// * `pattern` - The glob pattern, see [`matches`]
+    /// ## Returns
+    /// [`usize`]
+    pub fn listener_count_matching(&self, pattern: &str) -> usize {
+        self.events
+            .iter()
+            .filter(|x| matches(pattern, &x.name))
+            .map(|x| x.data.len())
+            .sum()
+    }
+
+    /// Get names of existing events matching a glob pattern
+    /// ## Parameters
+    /// * `pattern` - The glob pattern, see [`matches`]
+    /// ## Returns
+    /// [`Vec<String>`]
+    pub fn event_names_matching(&self, pattern: &str) -> Vec<String> {
+        self.events
+            .iter()
+            .filter(|x| matches(pattern, &x.name))
+            .map(|x| x.name.to_string())
+            .collect()
+    }
+}
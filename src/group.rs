@@ -0,0 +1,65 @@
+use crate::listener::{Listener, ListenerCallback, ListenerTypes};
+use crate::EventListener;
+
+/// Handle of listeners registered together, see [`EventListener::on_many`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenerGroup {
+    name: String,
+}
+
+impl ListenerGroup {
+    /// Get the group name, usable with [`EventListener::remove_group`]
+    /// ## Returns
+    /// [`&str`]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl EventListener {
+    /// Register a set of listeners at once under a single group
+    ///
+    /// Either every listener is registered or none is.
+    /// ## Parameters
+    /// * `listeners` - Event names and their callbacks
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let group = emitter.on_many(vec![
+    ///     ("connect", Box::new(|_, data| println!("connected {}", data))),
+    ///     ("disconnect", Box::new(|_, data| println!("disconnected {}", data))),
+    /// ]);
+    /// emitter.emit("connect", "1".to_string());
+    /// assert_eq!(emitter.remove_group(group.name()), 2);
+    /// ```
+    /// ## Returns
+    /// [`ListenerGroup`]
+    /// ## Panics
+    /// If the max listener count of any event would be exceeded and the overflow policy
+    /// refuses it, nothing is registered then
+    pub fn on_many(&mut self, listeners: Vec<(&str, ListenerCallback)>) -> ListenerGroup {
+        let mut added: Vec<(&str, usize)> = vec![];
+        for (name, _) in &listeners {
+            match added.iter_mut().find(|x| x.0 == *name) {
+                Some(entry) => entry.1 += 1,
+                None => added.push((name, 1)),
+            }
+        }
+        for (name, count) in &added {
+            if let Err(error) = self.check_overflow(name, *count) {
+                panic!("{}", error);
+            }
+        }
+        self.next_group_id += 1;
+        let group = ListenerGroup {
+            name: format!("group#{}", self.next_group_id),
+        };
+        for (name, callback) in listeners {
+            let mut listener = Listener::new(ListenerTypes::On, callback);
+            listener.group = Some(group.name.clone());
+            self.add_listener(name, listener);
+        }
+        group
+    }
+}
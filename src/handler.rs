@@ -0,0 +1,63 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::EventListener;
+use std::cell::RefCell;
+
+/// Stateful listener, an alternative to closure callbacks
+///
+/// The handler is owned by the emitter and dropped when its listener is removed, so
+/// cleanup can live in its [`Drop`] implementation.
+pub trait Handler {
+    /// Handle an emission
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The emitted data
+    fn handle(&mut self, name: &str, data: &str);
+}
+
+impl EventListener {
+    /// Add a handler as listener
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `handler` - The handler
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::handler::Handler;
+    /// use rust_event_listener::EventListener;
+    ///
+    /// struct Logger {
+    ///     lines: Vec<String>,
+    /// }
+    ///
+    /// impl Handler for Logger {
+    ///     fn handle(&mut self, name: &str, data: &str) {
+    ///         self.lines.push(format!("{}: {}", name, data));
+    ///     }
+    /// }
+    ///
+    /// impl Drop for Logger {
+    ///     fn drop(&mut self) {
+    ///         println!("flushing {} lines", self.lines.len());
+    ///     }
+    /// }
+    ///
+    /// let mut emitter = EventListener::new();
+    /// let handle = emitter.on_handler("log", Box::new(Logger { lines: Vec::new() }));
+    /// emitter.emit("log", "started");
+    /// emitter.emit("log", "stopped");
+    /// emitter.off(handle); // flushing 2 lines
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if the handler emits an event it handles
+    pub fn on_handler(&mut self, name: &str, handler: Box<dyn Handler>) -> ListenerHandle {
+        let handler = RefCell::new(handler);
+        self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |name, data| handler.borrow_mut().handle(&name, &data)),
+            ),
+        )
+    }
+}
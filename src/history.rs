@@ -0,0 +1,113 @@
+use crate::listener::{Listener, ListenerCallback, ListenerHandle, ListenerTypes};
+use crate::EventListener;
+use std::collections::VecDeque;
+
+/// Recent payloads of an event
+#[derive(Debug)]
+pub(crate) struct EventHistory {
+    name: String,
+    capacity: usize,
+    payloads: VecDeque<String>,
+}
+
+impl EventListener {
+    /// Keep the most recent payloads of an event for [`EventListener::on_with_replay`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `capacity` - Max number of kept payloads, `0` disables the history
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_history("price", 2);
+    /// emitter.emit("price", 10);
+    /// emitter.emit("price", 11);
+    /// emitter.emit("price", 12);
+    /// assert_eq!(emitter.get_history("price"), vec!["11", "12"]);
+    /// ```
+    pub fn set_history(&mut self, name: &str, capacity: usize) {
+        if capacity == 0 {
+            self.histories.retain(|x| x.name != name);
+            return;
+        }
+        self.ensure_event(name);
+        match self.histories.iter_mut().find(|x| x.name == name) {
+            Some(history) => {
+                history.capacity = capacity;
+                while history.payloads.len() > capacity {
+                    history.payloads.pop_front();
+                }
+            }
+            None => self.histories.push(EventHistory {
+                name: name.to_string(),
+                capacity,
+                payloads: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Get the kept payloads of an event, oldest first
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Vec<String>`] - Empty if the event has no history
+    pub fn get_history(&self, name: &str) -> Vec<String> {
+        self.histories
+            .iter()
+            .find(|x| x.name == name)
+            .map(|x| x.payloads.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Add a new listener, first calling it with the last `count` kept payloads
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `count` - Max number of replayed payloads, see [`EventListener::set_history`]
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_history("status", 10);
+    /// emitter.emit("status", "connecting");
+    /// emitter.emit("status", "online");
+    /// emitter.on_with_replay("status", 1, Box::new(|_, status| {
+    ///  println!("status: {}", status); // status: online
+    /// }));
+    /// emitter.emit("status", "offline"); // status: offline
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_with_replay(
+        &mut self,
+        name: &str,
+        count: usize,
+        callback: ListenerCallback,
+    ) -> ListenerHandle {
+        let listener = Listener::new(ListenerTypes::On, callback);
+        let history = self.get_history(name);
+        for data in &history[history.len().saturating_sub(count)..] {
+            if let Err((error, panic)) = crate::uncaught::call_listener(&listener, name, data) {
+                self.report_listener_error(error, panic);
+            }
+        }
+        self.add_listener(name, listener)
+    }
+
+    /// Check whether an event keeps a history
+    pub(crate) fn has_history(&self, name: &str) -> bool {
+        self.histories.iter().any(|x| x.name == name)
+    }
+
+    /// Keep the payload of an event with a history
+    pub(crate) fn record_history(&mut self, name: &str, data: &str) {
+        if let Some(history) = self.histories.iter_mut().find(|x| x.name == name) {
+            if history.payloads.len() >= history.capacity {
+                history.payloads.pop_front();
+            }
+            history.payloads.push_back(data.to_string());
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::EventListener;
+use std::thread::JoinHandle;
+
+impl EventListener {
+    /// Spawn a pump turning messages of an existing channel into emissions
+    ///
+    /// Works with any blocking receiver that can be iterated, such as
+    /// [`std::sync::mpsc::Receiver`] or crossbeam receivers. Messages are queued on
+    /// the emitter and dispatched by [`EventListener::process_ingested`], so listeners
+    /// keep running on the thread owning the emitter.
+    /// ## Parameters
+    /// * `receiver` - The channel receiver
+    /// * `name` - The event name messages are emitted as
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::sync::mpsc::channel;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("log", Box::new(|name, data| {
+    ///  println!("{}: {}", name, data);
+    /// }));
+    /// let (tx, rx) = channel();
+    /// let pump = emitter.ingest(rx, "log");
+    /// tx.send("started").unwrap();
+    /// drop(tx);
+    /// pump.join().unwrap();
+    /// assert_eq!(emitter.process_ingested(), 1);
+    /// ```
+    /// ## Returns
+    /// [`JoinHandle`] - The pump thread, finishing when the channel disconnects
+    pub fn ingest<I, T>(&mut self, receiver: I, name: &str) -> JoinHandle<()>
+    where
+        I: IntoIterator<Item = T> + Send + 'static,
+        T: ToString,
+    {
+        let inbox = self.ingest_sender.clone();
+        let name = name.to_string();
+        std::thread::spawn(move || {
+            for message in receiver {
+                if inbox.send((name.clone(), message.to_string())).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Emit every message received by ingest pumps so far
+    /// ## Returns
+    /// [`usize`] - The number of emitted messages
+    /// ## Panics
+    /// If an ingested event doesn't exist
+    pub fn process_ingested(&mut self) -> usize {
+        let mut processed = 0;
+        while let Ok((name, data)) = self.ingest_receiver.try_recv() {
+            self.emit(&name, data);
+            processed += 1;
+        }
+        processed
+    }
+}
@@ -0,0 +1,238 @@
+use crate::emitter::Emitter;
+use crate::EventListener;
+use std::time::{Duration, Instant};
+
+/// Recorded emission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Sequence number in the journal
+    pub seq: u64,
+    /// Event name
+    pub name: String,
+    /// Event payload
+    pub payload: String,
+    /// Time elapsed since the journal started
+    pub offset: Duration,
+    /// Source location of the emit call as `file:line:column`, recorded with the
+    /// `debug-location` feature
+    pub location: Option<String>,
+}
+
+/// Replay speed for [`Journal::replay_into`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// Keep the original inter-event timing
+    RealTime,
+    /// Scale the original timing, `Accelerated(10.0)` replays ten times faster, the
+    /// factor must be positive and finite
+    Accelerated(f64),
+    /// Replay without waiting between events
+    AsFastAsPossible,
+}
+
+/// Error returned by [`Journal::replay_into`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayError {
+    /// The factor of [`ReplaySpeed::Accelerated`] isn't positive and finite, or scales
+    /// an offset past [`Duration::MAX`]
+    InvalidSpeed(f64),
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::InvalidSpeed(factor) => write!(f, "Invalid replay speed: {}", factor),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Result of a replay
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    /// Number of replayed entries
+    pub replayed: usize,
+    /// Sequence numbers of entries the target emitter rejected, e.g. because their
+    /// event doesn't exist
+    pub skipped: Vec<u64>,
+}
+
+/// Emission journal
+#[derive(Debug, Clone)]
+pub struct Journal {
+    started: Instant,
+    pub(crate) entries: Vec<JournalEntry>,
+    pub(crate) metadata: Vec<(String, String)>,
+}
+
+impl Journal {
+    /// Create an empty journal starting now
+    pub fn new() -> Self {
+        Journal {
+            started: Instant::now(),
+            entries: vec![],
+            metadata: vec![],
+        }
+    }
+
+    /// Set a metadata value, exported with the journal's trace
+    /// ## Parameters
+    /// * `key` - The metadata key
+    /// * `value` - The metadata value
+    pub fn set_metadata(&mut self, key: &str, value: &str) {
+        match self.metadata.iter_mut().find(|x| x.0 == key) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => self.metadata.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Get metadata values
+    /// ## Returns
+    /// [`&[(String, String)]`]
+    pub fn metadata(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    /// Record an emission
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `payload` - The emitted payload
+    pub fn record(&mut self, name: &str, payload: &str) {
+        self.entries.push(JournalEntry {
+            seq: self.entries.len() as u64,
+            name: name.to_string(),
+            payload: payload.to_string(),
+            offset: self.started.elapsed(),
+            location: None,
+        });
+    }
+
+    /// Get recorded entries
+    /// ## Returns
+    /// [`&[JournalEntry]`]
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Get the number of recorded entries
+    /// ## Returns
+    /// [`usize`]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the journal is empty
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Replay recorded emissions into an emitter
+    ///
+    /// Entries the emitter rejects are skipped instead of panicking, see [`Emitter::try_emit`].
+    /// ## Parameters
+    /// * `emitter` - The target emitter
+    /// * `speed` - The replay speed
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::journal::{Journal, ReplaySpeed};
+    /// use rust_event_listener::EventListener;
+    /// let mut journal = Journal::new();
+    /// journal.record("test", "1");
+    /// journal.record("test", "2");
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("test", Box::new(|name, data| {
+    ///  println!("{}: {}", name, data);
+    /// }));
+    /// journal.record("unknown", "3");
+    /// let report = journal
+    ///     .replay_into(&mut emitter, ReplaySpeed::AsFastAsPossible)
+    ///     .unwrap();
+    /// assert_eq!(report.replayed, 2);
+    /// assert_eq!(report.skipped, vec![2]);
+    /// ```
+    /// ## Returns
+    /// [`Result<ReplayReport, ReplayError>`] - An error if the speed is invalid, before
+    /// any entry is replayed
+    pub fn replay_into<E: Emitter + ?Sized>(
+        &self,
+        emitter: &mut E,
+        speed: ReplaySpeed,
+    ) -> Result<ReplayReport, ReplayError> {
+        replay_entries(&self.entries, emitter, speed)
+    }
+}
+
+/// Replay entries into an emitter, waiting for their offsets according to `speed`
+pub(crate) fn replay_entries<E: Emitter + ?Sized>(
+    entries: &[JournalEntry],
+    emitter: &mut E,
+    speed: ReplaySpeed,
+) -> Result<ReplayReport, ReplayError> {
+    if let ReplaySpeed::Accelerated(factor) = speed {
+        let last = entries.iter().map(|x| x.offset).max().unwrap_or_default();
+        if !(factor.is_finite() && factor > 0.0)
+            || Duration::try_from_secs_f64(last.as_secs_f64() / factor).is_err()
+        {
+            return Err(ReplayError::InvalidSpeed(factor));
+        }
+    }
+    let started = Instant::now();
+    let mut report = ReplayReport::default();
+    for entry in entries {
+        let due = match speed {
+            ReplaySpeed::RealTime => Some(entry.offset),
+            ReplaySpeed::Accelerated(factor) => Some(entry.offset.div_f64(factor)),
+            ReplaySpeed::AsFastAsPossible => None,
+        };
+        if let Some(due) = due {
+            let elapsed = started.elapsed();
+            if due > elapsed {
+                std::thread::sleep(due - elapsed);
+            }
+        }
+        match emitter.try_emit(&entry.name, entry.payload.clone()) {
+            Ok(_) => report.replayed += 1,
+            Err(_) => report.skipped.push(entry.seq),
+        }
+    }
+    Ok(report)
+}
+
+impl Default for Journal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventListener {
+    /// Start recording emissions into a new journal, replacing the current one
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("test", Box::new(|_, _| {}));
+    /// emitter.start_journal();
+    /// emitter.emit("test", "1".to_string());
+    /// assert_eq!(emitter.take_journal().unwrap().len(), 1);
+    /// ```
+    pub fn start_journal(&mut self) {
+        self.journal = Some(Journal::new());
+    }
+
+    /// Get the current journal
+    /// ## Returns
+    /// [`Option<&Journal>`]
+    pub fn journal(&self) -> Option<&Journal> {
+        self.journal.as_ref()
+    }
+
+    /// Stop recording and take the current journal
+    /// ## Returns
+    /// [`Option<Journal>`]
+    pub fn take_journal(&mut self) -> Option<Journal> {
+        self.journal.take()
+    }
+}
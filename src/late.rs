@@ -0,0 +1,130 @@
+use crate::listener::ListenerTypes;
+use crate::EventListener;
+
+/// Emissions held until the first listener of an event registers
+#[derive(Debug)]
+pub(crate) struct LateBuffer {
+    name: String,
+    capacity: usize,
+    pending: Vec<(String, Option<std::time::Instant>)>,
+}
+
+impl EventListener {
+    /// Buffer emissions of an event while it has no listeners and flush them to the first subscriber
+    ///
+    /// When the buffer is full the oldest emission is dropped and `droppedEvent` is emitted.
+    /// ## Parameters
+    /// * `name` - The name of the event, created if it doesn't exist
+    /// * `capacity` - Max number of buffered emissions, `0` means unlimited
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.buffer_until_listener("ready", 10);
+    /// emitter.emit("ready", "early".to_string());
+    /// emitter.on("ready", Box::new(|name, data| {
+    ///  println!("{}: {}", name, data); // ready: early
+    /// }));
+    /// ```
+    pub fn buffer_until_listener(&mut self, name: &str, capacity: usize) {
+        self.ensure_event(name);
+        match self.late_buffers.iter_mut().find(|x| x.name == name) {
+            Some(buffer) => buffer.capacity = capacity,
+            None => self.late_buffers.push(LateBuffer {
+                name: name.to_string(),
+                capacity,
+                pending: vec![],
+            }),
+        }
+    }
+
+    /// Stop buffering emissions of an event, discarding pending ones
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Vec<String>`] - The discarded emissions
+    pub fn stop_buffering(&mut self, name: &str) -> Vec<String> {
+        match self.late_buffers.iter().position(|x| x.name == name) {
+            Some(index) => self
+                .late_buffers
+                .remove(index)
+                .pending
+                .into_iter()
+                .map(|x| x.0)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Check whether emissions of an event are buffered until a listener registers
+    pub(crate) fn is_buffered(&self, name: &str) -> bool {
+        self.late_buffers.iter().any(|x| x.name == name)
+    }
+
+    /// Buffer an emission if the event is buffered and no listener would receive it
+    ///
+    /// Listeners of matching patterns and of the parent events it bubbles up to count.
+    /// ## Returns
+    /// [`bool`] - `true` if the emission was buffered
+    pub(crate) fn buffer_late_emission(&mut self, name: &str, data: &str) -> bool {
+        let has_listeners = self
+            .subscribed_events(name)
+            .into_iter()
+            .any(|x| !self.events[x].data.is_empty());
+        if has_listeners {
+            return false;
+        }
+        let expires_at = self.expiry_for(name);
+        let buffer = match self.late_buffers.iter_mut().find(|x| x.name == name) {
+            Some(buffer) => buffer,
+            None => return false,
+        };
+        buffer.pending.push((data.to_string(), expires_at));
+        if buffer.capacity != 0 && buffer.pending.len() > buffer.capacity {
+            buffer.pending.remove(0);
+            self.emit_meta_event("droppedEvent", name);
+        }
+        true
+    }
+
+    /// Deliver buffered emissions to a newly registered listener
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `position` - The position of the new listener
+    pub(crate) fn flush_late_buffer(&mut self, name: &str, position: usize) {
+        let pending = match self.late_buffers.iter_mut().find(|x| x.name == name) {
+            Some(buffer) if !buffer.pending.is_empty() => std::mem::take(&mut buffer.pending),
+            _ => return,
+        };
+        let pending = pending
+            .into_iter()
+            .filter(|x| !self.check_expired(x.1))
+            .collect::<Vec<_>>();
+        let now = self.clock.now();
+        let listener = &mut self.event_mut(name).unwrap().data[position];
+        let mut pending = pending.into_iter();
+        let mut fired = false;
+        let mut failures = vec![];
+        for (data, _) in pending.by_ref() {
+            if listener.accepts(&data, now) {
+                if let Err(failure) = crate::uncaught::call_listener(listener, name, &data) {
+                    failures.push(failure);
+                }
+                if matches!(listener.rtype, ListenerTypes::Once) {
+                    fired = true;
+                    break;
+                }
+            }
+        }
+        let handle = listener.handle();
+        for (error, panic) in failures {
+            self.report_listener_error(error, panic);
+        }
+        if fired {
+            self.remove_listener_by_handle(handle);
+            if let Some(buffer) = self.late_buffers.iter_mut().find(|x| x.name == name) {
+                buffer.pending = pending.collect();
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(test, deny(warnings))]
 #![doc(html_root_url = "https://docs.rs/rust_event_listener/0.1.0")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //!# rust_event_listener
 //!NodeJS like Event Listener library for rust!
@@ -11,39 +12,452 @@
 //! ```
 //! use rust_event_listener::EventListener;
 //!
-//! fn main() {
-//!    let mut emitter = EventListener::new();
-//!    //Set max listeners
-//!    emitter.set_max_listeners(10);
+//! let mut emitter = EventListener::new();
+//! //Set max listeners
+//! emitter.set_max_listeners(10);
 //!
-//!    //Add listener
-//!    emitter.on("test",  Box::new(|name, d| {
-//!        println!("Emited: {} {:#?}", name, d);
-//!    }));
-//!    emitter.emit("test", "1".to_string());
-//! }
+//! //Add listener
+//! emitter.on("test",  Box::new(|name, d| {
+//!     println!("Emited: {} {:#?}", name, d);
+//! }));
+//! emitter.emit("test", "1".to_string());
 //! ```
+//!
+//!## Features
+//!
+//! The core emitter has no dependencies and no optional subsystems. Heavier subsystems
+//! are enabled by default and can be dropped with `default-features = false`, keeping
+//! `features = ["std"]` for the core emitter:
+//!
+//! * `std` - The full emitter; without it the crate is `no_std` and only provides the
+//!   allocation-only [`minimal`] emitter
+//! * `async` - Async listeners, executors, acknowledged emissions, subscriber readiness and
+//!   event streams
+//! * `persistence` - JSON trace files, persistent journals and ring journals
+//! * `process` - Child-process event source
+//! * `config` - Event-driven configuration reload
+//! * `debug-location` - Record the source location of emit calls
+//! * `global` - Process-wide shared emitter, see [`sync::global`]
+//!
 //! You can find more examples [here](https://github.com/behemehal/Menemen/tree/main/examples)
 
+extern crate alloc;
+
 /// Listener utilities
+#[cfg(feature = "std")]
 pub mod listener;
 
+/// Fluent subscription builder
+#[cfg(feature = "std")]
+pub mod builder;
+
+/// Emitter trait abstraction
+#[cfg(feature = "std")]
+pub mod emitter;
+
+/// Sink adapter for piping pipelines into emitters
+#[cfg(feature = "std")]
+pub mod sink;
+
+/// Channel ingestion pumps
+#[cfg(feature = "std")]
+pub mod ingest;
+
+/// Event name glob matching and bulk queries
+#[cfg(feature = "std")]
+pub mod glob;
+
+/// Namespaced views and per-namespace configuration
+#[cfg(feature = "std")]
+pub mod namespace;
+
+/// Filtered sub-view handles
+#[cfg(feature = "std")]
+pub mod view;
+
+/// Emission journal and replay
+#[cfg(feature = "std")]
+pub mod journal;
+
+/// JSON trace import and export
+#[cfg(feature = "persistence")]
+pub mod trace;
+
+/// Late registration buffers
+#[cfg(feature = "std")]
+pub mod late;
+
+/// Sticky last-value events
+#[cfg(feature = "std")]
+pub mod sticky;
+
+/// Batch listener registration
+#[cfg(feature = "std")]
+pub mod group;
+
+/// Event name routing rules
+#[cfg(feature = "std")]
+pub mod route;
+
+/// Queued dispatch with fairness policies
+#[cfg(feature = "std")]
+pub mod queue;
+
+/// Persistent journal files with group commit
+#[cfg(feature = "persistence")]
+pub mod persist;
+
+/// Fixed-size ring journal files
+#[cfg(feature = "persistence")]
+pub mod ring;
+
+/// Read-only observer handles
+#[cfg(feature = "std")]
+pub mod observer;
+
+/// Executors driving async listeners
+#[cfg(feature = "async")]
+pub mod executor;
+
+/// Async listeners
+#[cfg(feature = "async")]
+pub mod async_listener;
+
+/// Acknowledged emissions
+#[cfg(feature = "async")]
+pub mod ack;
+
+/// Event-driven configuration reload
+#[cfg(feature = "config")]
+pub mod config;
+
+/// Typed listeners with a per-emission parse cache
+#[cfg(feature = "std")]
+pub mod parsed;
+
+/// Batched listeners
+#[cfg(feature = "std")]
+pub mod batch;
+
+/// Pluggable listener selection
+#[cfg(feature = "std")]
+pub mod router;
+
+/// Event name aliases
+#[cfg(feature = "std")]
+pub mod alias;
+
+#[cfg(feature = "std")]
+mod order;
+
+/// Per-event dispatch modes
+#[cfg(feature = "std")]
+pub mod dispatch;
+
+/// Payload conversion at the emit call site
+#[cfg(feature = "std")]
+pub mod payload;
+
+/// Non-owning emitter references
+#[cfg(feature = "std")]
+pub mod weak;
+
+/// Listener failure policy
+#[cfg(feature = "std")]
+pub mod uncaught;
+
+/// Per-emit time budgets
+#[cfg(feature = "std")]
+pub mod budget;
+
+/// Payload redaction before recording
+#[cfg(feature = "std")]
+pub mod redact;
+
+/// Multi-tenant partitions
+#[cfg(feature = "std")]
+pub mod tenant;
+
+/// Scoped child handles removing their listeners on drop
+#[cfg(feature = "std")]
+pub mod scope;
+
+/// Journal replay filters and projections
+#[cfg(feature = "std")]
+pub mod replay;
+
+/// Emission time to live
+#[cfg(feature = "std")]
+pub mod ttl;
+
+/// Listener group resource accounting
+#[cfg(feature = "std")]
+pub mod quota;
+
+/// Waiting for subscribers
+#[cfg(feature = "async")]
+pub mod readiness;
+
+/// Emission delivery status
+#[cfg(feature = "std")]
+pub mod outcome;
+
+/// Values emitted without serialization
+#[cfg(feature = "std")]
+pub mod value;
+
+/// Virtual time and timers
+#[cfg(feature = "std")]
+pub mod clock;
+
+/// Child-process event source
+#[cfg(feature = "process")]
+pub mod process;
+
+/// Actor-style mailboxes
+#[cfg(feature = "std")]
+pub mod actor;
+
+/// Thread-safe shared emitter
+#[cfg(feature = "std")]
+pub mod sync;
+
+/// Max listener overflow policies
+#[cfg(feature = "std")]
+pub mod overflow;
+
+/// Auto-unsubscribing listener guards
+#[cfg(feature = "std")]
+pub mod subscription;
+
+/// Events keyed by Rust type
+#[cfg(feature = "std")]
+pub mod typed;
+
+/// Recent payload history and replay
+#[cfg(feature = "std")]
+pub mod history;
+
+/// Stopping propagation from within listeners
+#[cfg(feature = "std")]
+pub mod control;
+
+/// Emission middlewares
+#[cfg(feature = "std")]
+pub mod middleware;
+
+/// Dedicated dispatcher threads
+#[cfg(feature = "std")]
+pub mod dispatcher;
+
+/// Duplicate listener detection and removal by callback
+#[cfg(feature = "std")]
+pub mod unique;
+
+/// Emission metadata envelopes
+#[cfg(feature = "std")]
+pub mod envelope;
+
+/// Pausing and resuming emitters
+#[cfg(feature = "std")]
+pub mod pause;
+
+/// Allocation-only emitter for `no_std` targets
+pub mod minimal;
+
+/// Listeners returning errors
+#[cfg(feature = "std")]
+pub mod fallible;
+
+/// Debounced and throttled listeners
+#[cfg(feature = "std")]
+pub mod debounce;
+
+/// Interned event names
+#[cfg(feature = "std")]
+pub mod name;
+
+/// Listener call counters and timings
+#[cfg(feature = "std")]
+pub mod stats;
+
+/// Forwarding events between emitters
+#[cfg(feature = "std")]
+pub mod pipe;
+
+/// Stateful listeners implementing a trait
+#[cfg(feature = "std")]
+pub mod handler;
+
+/// Event payload streams
+#[cfg(feature = "async")]
+pub mod stream;
+
+#[cfg(feature = "std")]
+mod json;
+
+/// Meta-events created with every emitter
+#[cfg(feature = "std")]
+pub(crate) const META_EVENTS: [&str; 7] = [
+    "newListener",
+    "removeListener",
+    "unhandledEvent",
+    "droppedEvent",
+    "pressureChanged",
+    "budgetExceeded",
+    "quotaExceeded",
+];
+
 /// Event interface
+#[cfg(feature = "std")]
 pub struct Event {
-    /// Event name
-    pub name: String,
+    /// Event name, shared with [`EventListener::intern`]
+    pub name: crate::name::EventName,
     /// Event listeners
     pub data: Vec<crate::listener::Listener>,
+    /// Dispatch counters, see [`EventListener::stats`]
+    pub stats: crate::stats::CallStats,
 }
 
 /// EventListener
+#[cfg(feature = "std")]
 pub struct EventListener {
-    /// All events
-    pub events: Vec<Event>,
+    /// All events, in creation order
+    events: Vec<Event>,
+    /// Position of each event in `events`
+    event_index: std::collections::HashMap<crate::name::EventName, usize>,
+    /// Positions of events named by a glob pattern
+    pattern_events: Vec<usize>,
     /// Max listeners
     max_listeners: usize,
+    /// Max listeners overrides of single events
+    event_max_listeners: Vec<(String, usize)>,
+    /// Sender handed to ingest pumps
+    ingest_sender: std::sync::mpsc::Sender<(String, String)>,
+    /// Messages received by ingest pumps
+    ingest_receiver: std::sync::mpsc::Receiver<(String, String)>,
+    /// Sender handed to subscription guards
+    unsubscribe_sender: std::sync::mpsc::Sender<crate::listener::ListenerHandle>,
+    /// Listeners whose subscription guards were dropped
+    unsubscribe_receiver: std::sync::mpsc::Receiver<crate::listener::ListenerHandle>,
+    /// Namespace configurations
+    namespaces: Vec<crate::namespace::NamespaceState>,
+    /// Journal recording emissions
+    journal: Option<crate::journal::Journal>,
+    /// Whether a meta-event is being dispatched, meta-events are not nested
+    in_meta_event: bool,
+    /// Emissions held until the first listener registers
+    late_buffers: Vec<crate::late::LateBuffer>,
+    /// Last payloads of sticky events
+    sticky: Vec<(String, Option<String>)>,
+    /// Last generated listener group id
+    next_group_id: usize,
+    /// Last assigned listener id
+    next_listener_id: u64,
+    /// Routing rules applied to emitted event names
+    routes: Vec<crate::route::RouteRule>,
+    /// Queued emissions
+    queue: crate::queue::PendingQueue,
+    /// Fairness policy of queued dispatch
+    fairness: crate::queue::FairnessPolicy,
+    /// Number of queued emissions dispatched so far
+    queue_tick: u64,
+    /// Max queued emissions, `0` means unbounded
+    queue_capacity: usize,
+    /// Last reported queue fill level
+    last_pressure: crate::queue::PressureLevel,
+    #[cfg(feature = "persistence")]
+    /// Writer persisting emissions
+    journal_writer: Option<crate::persist::JournalWriter>,
+    #[cfg(feature = "persistence")]
+    /// Ring journal recording emissions
+    ring_journal: Option<crate::ring::RingJournal>,
+    #[cfg(feature = "async")]
+    /// Async listeners
+    async_listeners: Vec<crate::async_listener::AsyncListener>,
+    #[cfg(feature = "async")]
+    /// Executor driving async listeners
+    executor: Box<dyn crate::executor::Executor>,
+    #[cfg(feature = "async")]
+    /// Futures of async listeners started by the emission of `emit_async`
+    async_pending: Option<Vec<(crate::listener::ListenerHandle, crate::executor::BoxFuture)>>,
+    #[cfg(feature = "async")]
+    /// Listener calls of the emission of `emit_and_wait_acks`
+    ack_calls: Option<Vec<crate::ack::AckCall>>,
+    /// Payloads parsed for typed listeners during the current emission
+    parse_cache: std::rc::Rc<std::cell::RefCell<crate::parsed::ParseCache>>,
+    /// Payload accumulators of batched listeners
+    batchers: Vec<std::rc::Weak<crate::batch::Batcher>>,
+    /// Routers installed per event
+    routers: Vec<(String, Box<dyn crate::router::Router>)>,
+    /// Aliases and the event names they resolve to
+    aliases: Vec<(String, String)>,
+    /// Dispatch modes of events not dispatched inline
+    dispatch_modes: Vec<(String, crate::dispatch::DispatchMode)>,
+    /// Policy applied to panicking listeners when nothing listens to `listenerError`
+    uncaught_policy: crate::uncaught::UncaughtPolicy,
+    /// Time budget of a single emission
+    emit_budget: Option<(std::time::Duration, crate::budget::BudgetPolicy)>,
+    /// Listener calls deferred by exceeded budgets
+    deferred: std::collections::VecDeque<crate::budget::DeferredCall>,
+    /// Redactions applied to recorded payloads, by event pattern
+    redactions: Vec<(String, crate::redact::Redaction)>,
+    /// Time to live of waiting emissions, by event name
+    event_ttls: Vec<(String, std::time::Duration)>,
+    /// Number of emissions dropped because they expired
+    expired: u64,
+    /// Resource accounting of listener groups
+    group_accounts: Vec<crate::quota::GroupAccount>,
+    #[cfg(feature = "async")]
+    /// Pending waits for subscribers
+    subscriber_watches: Vec<std::rc::Weak<std::cell::RefCell<crate::readiness::SubscriberWatch>>>,
+    /// Source location of the last emit call, recorded with the `debug-location` feature
+    emit_location: Option<&'static std::panic::Location<'static>>,
+    /// Value of the emission in progress, see [`EventListener::emit_value`]
+    value_slot: crate::value::ValueSlot,
+    /// Time source of time-based features
+    clock: crate::clock::Clock,
+    /// Scheduled emissions
+    timers: Vec<crate::clock::Timer>,
+    /// Id of the last scheduled emission
+    next_timer_id: u64,
+    /// Registered actors
+    actors: Vec<crate::actor::ActorEntry>,
+    /// What happens when an event reaches its max listener count
+    overflow_policy: crate::overflow::OverflowPolicy,
+    /// Events already warned about by [`crate::overflow::OverflowPolicy::Warn`]
+    overflow_warned: Vec<String>,
+    /// Recent payloads of events with a history
+    histories: Vec<crate::history::EventHistory>,
+    /// Set when a listener stops the emission in progress
+    propagation: std::rc::Rc<std::cell::Cell<bool>>,
+    /// Whether emissions bubble up to parent events
+    bubbling: bool,
+    /// Hooks running before the listeners of every emission
+    middlewares: Vec<crate::middleware::Middleware>,
+    /// Context of the emission in progress, see [`EventListener::on_context`]
+    context_slot: crate::middleware::ContextSlot,
+    /// Envelope of the emission in progress, see [`EventListener::on_with_meta`]
+    envelope_slot: crate::envelope::EnvelopeSlot,
+    /// Sequence number of the next emission
+    emission_seq: u64,
+    /// Source of the next emission, see [`EventListener::emit_from`]
+    emit_source: Option<String>,
+    /// Whether emissions are buffered until [`EventListener::resume`]
+    paused: bool,
+    /// Emissions made while paused
+    pause_buffer: crate::pause::PauseBuffer,
+    /// Max emissions buffered while paused, `0` means unbounded
+    pause_capacity: usize,
+    /// Emission dropped when the pause buffer is full
+    pause_overflow: crate::pause::PauseOverflow,
+    /// Failures collected by [`EventListener::emit_fallible`]
+    failure_slot: crate::fallible::FailureSlot,
+    /// Debounced listeners waiting for their quiet period
+    debouncers: Vec<std::rc::Weak<crate::debounce::Debouncer>>,
 }
 
+#[cfg(feature = "std")]
 impl EventListener {
     /// Create a new EventListener
     /// ## Example
@@ -52,19 +466,84 @@ impl EventListener {
     /// let mut emitter = EventListener::new();
     /// ```
     pub fn new() -> Self {
-        EventListener {
-            events: vec![
-                Event {
-                    name: "newListener".to_string(),
-                    data: vec![],
-                },
-                Event {
-                    name: "removeListener".to_string(),
-                    data: vec![],
-                },
-            ],
+        let (ingest_sender, ingest_receiver) = std::sync::mpsc::channel();
+        let (unsubscribe_sender, unsubscribe_receiver) = std::sync::mpsc::channel();
+        let mut emitter = EventListener {
+            events: vec![],
+            event_index: std::collections::HashMap::new(),
+            pattern_events: vec![],
             max_listeners: 10,
+            event_max_listeners: vec![],
+            ingest_sender,
+            ingest_receiver,
+            unsubscribe_sender,
+            unsubscribe_receiver,
+            namespaces: vec![],
+            journal: None,
+            in_meta_event: false,
+            late_buffers: vec![],
+            sticky: vec![],
+            next_group_id: 0,
+            next_listener_id: 0,
+            routes: vec![],
+            queue: crate::queue::PendingQueue::default(),
+            fairness: crate::queue::FairnessPolicy::default(),
+            queue_tick: 0,
+            queue_capacity: 0,
+            last_pressure: crate::queue::PressureLevel::Low,
+            #[cfg(feature = "persistence")]
+            journal_writer: None,
+            #[cfg(feature = "persistence")]
+            ring_journal: None,
+            #[cfg(feature = "async")]
+            async_listeners: vec![],
+            #[cfg(feature = "async")]
+            executor: Box::new(crate::executor::ThreadExecutor),
+            #[cfg(feature = "async")]
+            async_pending: None,
+            #[cfg(feature = "async")]
+            ack_calls: None,
+            parse_cache: Default::default(),
+            batchers: vec![],
+            routers: vec![],
+            aliases: vec![],
+            dispatch_modes: vec![],
+            uncaught_policy: Default::default(),
+            emit_budget: None,
+            deferred: std::collections::VecDeque::new(),
+            redactions: vec![],
+            event_ttls: vec![],
+            expired: 0,
+            group_accounts: vec![],
+            #[cfg(feature = "async")]
+            subscriber_watches: vec![],
+            emit_location: None,
+            value_slot: Default::default(),
+            clock: crate::clock::Clock::new(),
+            timers: vec![],
+            next_timer_id: 0,
+            actors: vec![],
+            overflow_policy: Default::default(),
+            overflow_warned: vec![],
+            histories: vec![],
+            propagation: Default::default(),
+            bubbling: false,
+            middlewares: vec![],
+            context_slot: Default::default(),
+            envelope_slot: Default::default(),
+            emission_seq: 0,
+            emit_source: None,
+            paused: false,
+            pause_buffer: Default::default(),
+            pause_capacity: 0,
+            pause_overflow: Default::default(),
+            failure_slot: Default::default(),
+            debouncers: vec![],
+        };
+        for name in META_EVENTS {
+            emitter.ensure_event(name);
         }
+        emitter
     }
 
     /// Sets the maximum number of listeners that can be registered.
@@ -81,6 +560,52 @@ impl EventListener {
         self.max_listeners
     }
 
+    /// Sets the maximum number of listeners of a single event, overriding the emitter
+    /// and namespace limits
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `max_listeners` - The maximum number of listeners, `0` means unlimited
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_max_listeners(1);
+    /// emitter.set_max_listeners_for("tick", 100);
+    /// for _ in 0..50 {
+    ///  emitter.on("tick", Box::new(|_, _| {}));
+    /// }
+    /// assert_eq!(emitter.get_max_listeners_for("tick"), 100);
+    /// assert_eq!(emitter.get_max_listeners_for("shutdown"), 1);
+    /// ```
+    pub fn set_max_listeners_for(&mut self, name: &str, max_listeners: usize) {
+        match self.event_max_listeners.iter_mut().find(|x| x.0 == name) {
+            Some(entry) => entry.1 = max_listeners,
+            None => self
+                .event_max_listeners
+                .push((name.to_string(), max_listeners)),
+        }
+    }
+
+    /// Remove the max listener override of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if the event had an override
+    pub fn reset_max_listeners_for(&mut self, name: &str) -> bool {
+        let before = self.event_max_listeners.len();
+        self.event_max_listeners.retain(|x| x.0 != name);
+        before != self.event_max_listeners.len()
+    }
+
+    /// Get the maximum number of listeners of an event, taking overrides into account
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`usize`] - `0` means unlimited
+    pub fn get_max_listeners_for(&self, name: &str) -> usize {
+        self.max_listeners_for(name)
+    }
+
     /// Add a new listener to the event
     /// ## Parameters
     /// * `name` - The name of the event
@@ -89,44 +614,32 @@ impl EventListener {
     /// ```
     /// use rust_event_listener::EventListener;
     /// let mut emitter = EventListener::new();
-    /// emitter.on("test", Box::new(|name, data| {
+    /// let handle = emitter.on("test", Box::new(|name, data| {
     ///    println!("{}", data);
     /// }));
+    /// assert!(emitter.off(handle));
     /// ```
-    pub fn on(&mut self, name: &str, callback: crate::listener::ListenerCallback) {
-        let event = Event {
-            name: name.to_string(),
-            data: vec![],
-        };
-
-        if self.events.iter().find(|x| x.name == name).is_none() {
-            self.events.push(event);
-        }
-        if self.max_listeners == 0
-            || self
-                .events
-                .iter()
-                .find(|x| x.name == name)
-                .unwrap()
-                .data
-                .len()
-                < self.max_listeners
-        {
-            self.events
-                .iter_mut()
-                .find(|x| x.name == name)
-                .unwrap()
-                .data
-                .push(crate::listener::Listener {
-                    rtype: crate::listener::ListenerTypes::On,
-                    callback,
-                });
-        } else {
-            panic!("Max listeners reached");
-        }
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on<N: Into<crate::name::EventName>>(
+        &mut self,
+        name: N,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        let name = name.into();
+        self.adopt_event_name(&name);
+        self.add_listener(
+            &name,
+            crate::listener::Listener::new(crate::listener::ListenerTypes::On, callback),
+        )
     }
 
     /// Add a listener that will be called only once
+    ///
+    /// The listener is removed after the first emission it accepts, other listeners of
+    /// that emission are still called.
     /// ## Parameters
     /// * `name` - The name of the event
     /// * `callback` - The callback function
@@ -137,57 +650,597 @@ impl EventListener {
     /// event_listener.once("test", Box::new(|name, data| {
     ///    println!("{}", data);
     /// }));
+    /// event_listener.emit("test", "1".to_string()); // 1
+    /// event_listener.emit("test", "2".to_string());
+    /// assert!(event_listener.get_listeners("test").is_empty());
+    /// ```
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once<N: Into<crate::name::EventName>>(
+        &mut self,
+        name: N,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        let name = name.into();
+        self.adopt_event_name(&name);
+        self.add_listener(
+            &name,
+            crate::listener::Listener::new(crate::listener::ListenerTypes::Once, callback),
+        )
+    }
+
+    /// Add a listener that will be called only once, by the first payload matching the predicate
+    ///
+    /// Emissions whose payload doesn't match are skipped and leave the listener registered.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `predicate` - The payload predicate
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.once_when("job.status", |status| status == "done", Box::new(|_, _| {
+    ///    println!("job finished");
+    /// }));
+    /// emitter.emit("job.status", "queued");
+    /// emitter.emit("job.status", "running");
+    /// assert_eq!(emitter.listener_count("job.status"), 1);
+    /// emitter.emit("job.status", "done"); // job finished
+    /// assert_eq!(emitter.listener_count("job.status"), 0);
     /// ```
-    pub fn once(&mut self, name: &str, callback: crate::listener::ListenerCallback) {
-        if self.events.iter().find(|x| x.name == name).is_none() {
-            self.events.push(Event {
-                name: name.to_string(),
-                data: vec![],
-            });
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once_when<F: Fn(&str) -> bool + 'static>(
+        &mut self,
+        name: &str,
+        predicate: F,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        let mut listener =
+            crate::listener::Listener::new(crate::listener::ListenerTypes::Once, callback);
+        listener.filter = Some(Box::new(move |data| predicate(data)));
+        self.add_listener(name, listener)
+    }
+
+    /// Add a listener that will be called at most `times` times
+    ///
+    /// The listener is removed after its last call, `on_times(name, 1, callback)` behaves
+    /// like [`EventListener::once`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `times` - The number of calls
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_times("ack", 3, Box::new(|_, peer| {
+    ///    println!("ack from {}", peer);
+    /// }));
+    /// for peer in ["a", "b", "c", "d"] {
+    ///     emitter.emit("ack", peer); // ack from a, ack from b, ack from c
+    /// }
+    /// assert_eq!(emitter.listener_count("ack"), 0);
+    /// ```
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If `times` is `0` or the max listener count is reached
+    pub fn on_times(
+        &mut self,
+        name: &str,
+        times: usize,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        if times == 0 {
+            panic!("Listener must be called at least once");
         }
-        if self.max_listeners == 0
-            || self
-                .events
-                .iter()
-                .find(|x| x.name == name)
-                .unwrap()
-                .data
-                .len()
-                < self.max_listeners
+        let remaining = std::cell::Cell::new(times);
+        self.on_while(name, move |name, data| {
+            if remaining.get() == 0 {
+                return false;
+            }
+            remaining.set(remaining.get() - 1);
+            callback(name, data);
+            remaining.get() > 0
+        })
+    }
+
+    /// Add a new listener with a priority
+    ///
+    /// Listeners are called by descending priority, listeners with the same priority in
+    /// registration order. Listeners added with [`EventListener::on`] have priority `0`.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `priority` - The priority, higher priorities are called first
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("order", Box::new(|_, _| println!("handle")));
+    /// emitter.on_with_priority("order", 10, Box::new(|_, _| println!("log")));
+    /// emitter.on_with_priority("order", 10, Box::new(|_, _| println!("metrics")));
+    /// emitter.emit("order", 1); // log, metrics, handle
+    /// ```
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_with_priority(
+        &mut self,
+        name: &str,
+        priority: i32,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        let mut listener =
+            crate::listener::Listener::new(crate::listener::ListenerTypes::On, callback);
+        listener.priority = priority;
+        self.add_listener(name, listener)
+    }
+
+    /// Add a new listener before the already registered listeners of the event
+    ///
+    /// Like NodeJS `prependListener`. Priorities still apply, the listener is placed before
+    /// the listeners of the same priority.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("request", Box::new(|_, _| println!("handle")));
+    /// emitter.prepend_listener("request", Box::new(|_, _| println!("validate")));
+    /// emitter.emit("request", 1); // validate, handle
+    /// ```
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn prepend_listener(
+        &mut self,
+        name: &str,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        let listener = crate::listener::Listener::new(crate::listener::ListenerTypes::On, callback);
+        self.try_add_listener(name, listener, true)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Add a listener that will be called only once, before the already registered
+    /// listeners of the event
+    ///
+    /// Like NodeJS `prependOnceListener`.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn prepend_once_listener(
+        &mut self,
+        name: &str,
+        callback: crate::listener::ListenerCallback,
+    ) -> crate::listener::ListenerHandle {
+        let listener =
+            crate::listener::Listener::new(crate::listener::ListenerTypes::Once, callback);
+        self.try_add_listener(name, listener, true)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Add a new listener whose callback can mutate its captured state
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let mut count = 0;
+    /// emitter.on_mut("tick", Box::new(move |_, _| {
+    ///  count += 1;
+    ///  println!("tick #{}", count);
+    /// }));
+    /// emitter.emit("tick", 1); // tick #1
+    /// emitter.emit("tick", 2); // tick #2
+    /// ```
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_mut(
+        &mut self,
+        name: &str,
+        callback: crate::listener::ListenerCallbackMut,
+    ) -> crate::listener::ListenerHandle {
+        self.on(name, crate::listener::from_mut(callback))
+    }
+
+    /// Add a listener whose callback can mutate its captured state, called only once
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once_mut(
+        &mut self,
+        name: &str,
+        callback: crate::listener::ListenerCallbackMut,
+    ) -> crate::listener::ListenerHandle {
+        self.once(name, crate::listener::from_mut(callback))
+    }
+
+    /// Remove a single listener
+    ///
+    /// Like in NodeJS, `newListener` is emitted with the event name before a listener is
+    /// added and `removeListener` after one is removed, including once-listeners consumed
+    /// by an emission.
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("removeListener", Box::new(|_, event| println!("removed from {}", event)));
+    /// let first = emitter.on("test", Box::new(|_, _| println!("first")));
+    /// emitter.on("test", Box::new(|_, _| println!("second")));
+    /// assert!(emitter.off(first)); // removed from test
+    /// assert!(!emitter.off(first));
+    /// emitter.emit("test", 1); // second
+    /// ```
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered
+    pub fn off(&mut self, handle: crate::listener::ListenerHandle) -> bool {
+        self.remove_listener_by_handle(handle)
+    }
+
+    /// Register a listener, keeping the event's listeners ordered by priority and dependencies
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `listener` - The listener to register
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached and the overflow policy isn't
+    /// [`crate::overflow::OverflowPolicy::Warn`] or [`crate::overflow::OverflowPolicy::Ignore`],
+    /// or if the listener's dependencies form a cycle
+    pub(crate) fn add_listener(
+        &mut self,
+        name: &str,
+        listener: crate::listener::Listener,
+    ) -> crate::listener::ListenerHandle {
+        self.try_add_listener(name, listener, false)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Register a listener, applying the overflow policy
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `listener` - The listener to register
+    /// * `prepend` - Place the listener before listeners of the same priority instead of after
+    /// ## Returns
+    /// [`Result<crate::listener::ListenerHandle, crate::overflow::MaxListenersExceeded>`]
+    /// ## Panics
+    /// If the max listener count is reached with [`crate::overflow::OverflowPolicy::Panic`],
+    /// or if the listener's dependencies form a cycle
+    pub(crate) fn try_add_listener(
+        &mut self,
+        name: &str,
+        mut listener: crate::listener::Listener,
+        prepend: bool,
+    ) -> Result<crate::listener::ListenerHandle, crate::overflow::MaxListenersExceeded> {
+        self.drain_unsubscribed();
+        let resolved = self.resolve_alias(name);
+        let name = resolved.as_ref();
+        self.check_overflow(name, 1)?;
+        self.ensure_event(name);
+        self.emit_listener_change("newListener", name);
+        if let Some(init) = listener.init.take() {
+            init(self.get_sticky(name));
+        }
+        self.next_listener_id += 1;
+        listener.id = self.next_listener_id;
+        let handle = listener.handle();
+        let event = self.event_mut(name).unwrap();
+        let position = event
+            .data
+            .iter()
+            .position(|x| {
+                x.priority < listener.priority || (prepend && x.priority == listener.priority)
+            })
+            .unwrap_or(event.data.len());
+        event.data.insert(position, listener);
+        if let Err(cycle) = crate::order::order_listeners(&mut event.data) {
+            event.data.retain(|x| x.handle() != handle);
+            panic!("Listener dependency cycle between {:?}", cycle);
+        }
+        let position = event
+            .data
+            .iter()
+            .position(|x| x.handle() == handle)
+            .unwrap();
+        self.flush_late_buffer(name, position);
+        #[cfg(feature = "async")]
+        self.notify_subscriber_watches(name);
+        Ok(handle)
+    }
+
+    /// Get an event by exact name
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<&Event>`]
+    pub(crate) fn event(&self, name: &str) -> Option<&Event> {
+        self.event_index.get(name).map(|x| &self.events[*x])
+    }
+
+    /// Get an event by exact name
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<&mut Event>`]
+    pub(crate) fn event_mut(&mut self, name: &str) -> Option<&mut Event> {
+        self.event_index.get(name).map(|x| &mut self.events[*x])
+    }
+
+    /// Get an event by exact name, creating it if it doesn't exist
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`&mut Event`]
+    pub(crate) fn ensure_event(&mut self, name: &str) -> &mut Event {
+        let index = match self.event_index.get(name) {
+            Some(index) => *index,
+            None => self.insert_event(crate::name::EventName::from(name)),
+        };
+        &mut self.events[index]
+    }
+
+    /// Create an event under a shared name, unless it exists or the name is an alias
+    pub(crate) fn adopt_event_name(&mut self, name: &crate::name::EventName) {
+        if self.event(name).is_none()
+            && matches!(self.resolve_alias(name), std::borrow::Cow::Borrowed(_))
         {
-            self.events
-                .iter_mut()
-                .find(|x| x.name == name)
-                .unwrap()
-                .data
-                .push(crate::listener::Listener {
-                    rtype: crate::listener::ListenerTypes::Once,
-                    callback,
-                });
-        } else {
-            panic!("Max listeners reached");
+            self.insert_event(name.clone());
+        }
+    }
+
+    /// Create an event
+    /// ## Returns
+    /// [`usize`] - The position of the event
+    fn insert_event(&mut self, name: crate::name::EventName) -> usize {
+        let index = self.events.len();
+        self.events.push(Event {
+            name: name.clone(),
+            data: vec![],
+            stats: crate::stats::CallStats::default(),
+        });
+        self.event_index.insert(name, index);
+        index
+    }
+
+    /// Get positions of the events receiving emissions of `name`
+    ///
+    /// The exact event and events named by a matching pattern come first, in creation
+    /// order, followed by the parent events the emission bubbles up to.
+    pub(crate) fn subscribed_events(&self, name: &str) -> Vec<usize> {
+        let mut indexes = self
+            .pattern_events
+            .iter()
+            .copied()
+            .filter(|x| crate::glob::matches(&self.events[*x].name, name))
+            .collect::<Vec<_>>();
+        if let Some(index) = self.event_index.get(name) {
+            if !indexes.contains(index) {
+                indexes.push(*index);
+                indexes.sort_unstable();
+            }
+        }
+        for index in self.bubble_events(name) {
+            if !indexes.contains(&index) {
+                indexes.push(index);
+            }
+        }
+        indexes
+    }
+
+    /// Find a registered listener by handle
+    /// ## Parameters
+    /// * `handle` - The listener handle
+    /// ## Returns
+    /// [`Option<&mut crate::listener::Listener>`]
+    pub(crate) fn find_listener_mut(
+        &mut self,
+        handle: crate::listener::ListenerHandle,
+    ) -> Option<&mut crate::listener::Listener> {
+        self.events
+            .iter_mut()
+            .flat_map(|x| x.data.iter_mut())
+            .find(|x| x.handle() == handle)
+    }
+
+    /// Get the name of the event a listener is registered on, async listeners included
+    /// ## Parameters
+    /// * `handle` - The listener handle
+    /// ## Returns
+    /// [`Option<&str>`] - `None` if the listener isn't registered
+    pub(crate) fn listener_event(&self, handle: crate::listener::ListenerHandle) -> Option<&str> {
+        let event = self
+            .events
+            .iter()
+            .find(|x| x.data.iter().any(|x| x.handle() == handle))
+            .map(|x| x.name.as_ref());
+        #[cfg(feature = "async")]
+        let event = event.or_else(|| {
+            self.async_listeners
+                .iter()
+                .find(|x| x.id == handle.0)
+                .map(|x| x.name.as_str())
+        });
+        event
+    }
+
+    /// Remove a registered listener by handle
+    /// ## Parameters
+    /// * `handle` - The listener handle
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered
+    pub(crate) fn remove_listener_by_handle(
+        &mut self,
+        handle: crate::listener::ListenerHandle,
+    ) -> bool {
+        for event in &mut self.events {
+            if let Some(position) = event.data.iter().position(|x| x.handle() == handle) {
+                event.data.remove(position);
+                let name = event.name.clone();
+                self.emit_listener_change("removeListener", &name);
+                return true;
+            }
+        }
+        #[cfg(feature = "async")]
+        if let Some(position) = self.async_listeners.iter().position(|x| x.id == handle.0) {
+            let name = self.async_listeners.remove(position).name;
+            self.emit_listener_change("removeListener", &name);
+            return true;
+        }
+        false
+    }
+
+    /// Register a listener only while a closure runs
+    ///
+    /// The listener is removed when the closure returns, even if it panics.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// * `scope` - The closure, receiving the emitter
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let emitted = emitter.with_listener("request", Box::new(|_, data| {
+    ///  println!("traced: {}", data);
+    /// }), |emitter| {
+    ///  emitter.emit("request", "GET /".to_string());
+    ///  emitter.get_listeners("request").len()
+    /// });
+    /// assert_eq!(emitted, 1);
+    /// assert_eq!(emitter.get_listeners("request").len(), 0);
+    /// ```
+    /// ## Returns
+    /// The value returned by `scope`
+    /// ## Panics
+    /// If the max listener count is reached, or resumes the panic of `scope` after cleanup
+    pub fn with_listener<R, F: FnOnce(&mut Self) -> R>(
+        &mut self,
+        name: &str,
+        callback: crate::listener::ListenerCallback,
+        scope: F,
+    ) -> R {
+        let handle = self.add_listener(
+            name,
+            crate::listener::Listener::new(crate::listener::ListenerTypes::On, callback),
+        );
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| scope(&mut *self)));
+        self.remove_listener_by_handle(handle);
+        match result {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
         }
     }
 
+    /// Swap the callback of a registered listener in place
+    ///
+    /// The listener keeps its position, priority and other options, so no emission is
+    /// missed while hot-swapping a handler.
+    /// ## Parameters
+    /// * `handle` - The listener handle
+    /// * `callback` - The new callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let handle = emitter.listen("config").priority(1).call(Box::new(|_, data| {
+    ///  println!("old: {}", data);
+    /// }));
+    /// assert!(emitter.replace_listener(handle, Box::new(|_, data| {
+    ///  println!("new: {}", data);
+    /// })));
+    /// emitter.emit("config", "reloaded".to_string()); // new: reloaded
+    /// ```
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered
+    pub fn replace_listener(
+        &mut self,
+        handle: crate::listener::ListenerHandle,
+        callback: crate::listener::ListenerCallback,
+    ) -> bool {
+        match self.find_listener_mut(handle) {
+            Some(listener) => {
+                listener.callback = callback;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove every listener registered with the given group
+    /// ## Parameters
+    /// * `group` - The group name
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.listen("resize").group("ui").call(Box::new(|name, data| {
+    ///  println!("{}: {}", name, data);
+    /// }));
+    /// assert_eq!(emitter.remove_group("ui"), 1);
+    /// ```
+    /// ## Returns
+    /// [`usize`] - The number of removed listeners
+    pub fn remove_group(&mut self, group: &str) -> usize {
+        let mut removed = vec![];
+        for event in &mut self.events {
+            let before = event.data.len();
+            event.data.retain(|x| x.group.as_deref() != Some(group));
+            removed.extend(std::iter::repeat_n(
+                event.name.clone(),
+                before - event.data.len(),
+            ));
+        }
+        for name in &removed {
+            self.emit_listener_change("removeListener", name);
+        }
+        removed.len()
+    }
+
     /// Get existing events
     /// ## Returns
     /// [`Vec<&Event>`]
     pub fn get_events(&self) -> Vec<&Event> {
-        self.events.iter().map(|x| x).collect::<Vec<_>>()
+        self.events.iter().collect::<Vec<_>>()
     }
 
     /// Get existing event names
     /// ## Returns
     /// [`Vec<String>`]
     pub fn get_event_names(&self) -> Vec<String> {
-        self.events.iter().map(|x| x.name.clone()).collect()
+        self.events.iter().map(|x| x.name.to_string()).collect()
     }
 
     /// Get all existent listeners of event
     /// ## Parameters
     /// * `name` - The name of the event
     /// ## Returns
-    /// [`Vec<&Listener>`]
+    /// [`Vec<&Listener>`] - Empty if the event doesn't exist
     /// ## Example
     /// ```
     /// use rust_event_listener::EventListener;
@@ -200,14 +1253,58 @@ impl EventListener {
     /// });
     /// ```
     pub fn get_listeners(&self, name: &str) -> Vec<&crate::listener::Listener> {
-        self.events
-            .iter()
-            .find(|x| x.name == name)
-            .unwrap()
-            .data
-            .iter()
-            .map(|x| x)
-            .collect::<Vec<_>>()
+        let name = self.resolve_alias(name);
+        self.event(&name)
+            .map(|x| x.data.iter().collect::<Vec<_>>())
+            .unwrap_or_default()
+    }
+
+    /// Get an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<&Event>`] - `None` if the event doesn't exist
+    pub fn get_event(&self, name: &str) -> Option<&Event> {
+        self.event(&self.resolve_alias(name))
+    }
+
+    /// Get the listeners of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// assert!(emitter.listeners("test").is_none());
+    /// emitter.on("test", Box::new(|_, _| {}));
+    /// assert_eq!(emitter.listeners("test").map(|x| x.len()), Some(1));
+    /// ```
+    /// ## Returns
+    /// [`Option<&[Listener]>`] - `None` if the event doesn't exist
+    pub fn listeners(&self, name: &str) -> Option<&[crate::listener::Listener]> {
+        self.get_event(name).map(|x| x.data.as_slice())
+    }
+
+    /// Get the listeners of an event for modification
+    ///
+    /// Listeners keep their position, changing [`crate::listener::Listener::priority`]
+    /// here doesn't reorder them.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("test", Box::new(|_, _| {}));
+    /// for listener in emitter.listeners_mut("test").unwrap() {
+    ///  listener.filter = Some(Box::new(|data| !data.is_empty()));
+    /// }
+    /// ```
+    /// ## Returns
+    /// [`Option<&mut [Listener]>`] - `None` if the event doesn't exist
+    pub fn listeners_mut(&mut self, name: &str) -> Option<&mut [crate::listener::Listener]> {
+        let name = self.resolve_alias(name);
+        self.event_mut(&name).map(|x| x.data.as_mut_slice())
     }
 
     /// Remove all listeners of event
@@ -225,43 +1322,433 @@ impl EventListener {
     /// ## Returns
     /// [`bool`] - `true` if the event was removed, `false` if it wasn't
     pub fn remove_all_listeners(&mut self, name: &str) -> bool {
-        if self.events.iter().find(|x| x.name == name).is_none() {
-            return false;
+        let resolved = self.resolve_alias(name);
+        let name = resolved.as_ref();
+        let removed = match self.event_mut(name) {
+            Some(event) => std::mem::take(&mut event.data).len(),
+            None => return false,
+        };
+        #[cfg(feature = "async")]
+        let removed = {
+            let before = self.async_listeners.len();
+            self.async_listeners.retain(|x| x.name != name);
+            removed + before - self.async_listeners.len()
+        };
+        for _ in 0..removed {
+            self.emit_listener_change("removeListener", name);
         }
-        self.events
-            .iter_mut()
-            .find(|x| x.name == name)
-            .unwrap()
-            .data
-            .clear();
         true
     }
 
+    /// Remove the listeners of every event
+    ///
+    /// Like NodeJS `removeAllListeners()` without arguments, `removeListener` is emitted
+    /// for each removed listener before the `removeListener` listeners themselves are removed.
+    /// ## Parameters
+    /// * `keep_meta` - Keep the listeners of `newListener` and `removeListener`
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("removeListener", Box::new(|_, event| println!("removed from {}", event)));
+    /// emitter.on("connect", Box::new(|_, _| {}));
+    /// emitter.on("disconnect", Box::new(|_, _| {}));
+    /// assert_eq!(emitter.remove_all(true), 2); // removed from connect, removed from disconnect
+    /// assert_eq!(emitter.get_listeners("removeListener").len(), 1);
+    /// ```
+    /// ## Returns
+    /// [`usize`] - The number of removed listeners
+    pub fn remove_all(&mut self, keep_meta: bool) -> usize {
+        let names = self
+            .events
+            .iter()
+            .filter(|x| x.name != "newListener" && x.name != "removeListener")
+            .map(|x| x.name.clone())
+            .collect::<Vec<_>>();
+        let mut removed = 0;
+        for name in names {
+            removed += self.listener_total(&name);
+            self.remove_all_listeners(&name);
+        }
+        if !keep_meta {
+            for name in ["newListener", "removeListener"] {
+                removed += self.listener_total(name);
+                self.remove_all_listeners(name);
+            }
+        }
+        removed
+    }
+
+    /// Count the listeners an emission of an event would reach
+    ///
+    /// Routing rules and aliases are applied, and listeners of matching patterns and async
+    /// listeners are included. Unknown events have no listeners.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_pattern("user.*", Box::new(|_, _| {}));
+    /// emitter.on("user.login", Box::new(|_, _| {}));
+    /// assert_eq!(emitter.listener_count("user.login"), 2);
+    /// assert_eq!(emitter.listener_count("unknown"), 0);
+    /// ```
+    /// ## Returns
+    /// [`usize`]
+    pub fn listener_count(&self, name: &str) -> usize {
+        let name = self.resolve_event_name(name);
+        let count = self
+            .subscribed_events(&name)
+            .into_iter()
+            .map(|x| self.events[x].data.len())
+            .sum::<usize>();
+        #[cfg(feature = "async")]
+        let count = count
+            + self
+                .async_listeners
+                .iter()
+                .filter(|x| x.name == name)
+                .count();
+        count
+    }
+
+    /// Check whether an emission of an event would reach any listener
+    ///
+    /// Use it to skip building expensive payloads nobody listens to.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// if emitter.has_listeners("report") {
+    ///  emitter.emit("report", "expensive".repeat(1000));
+    /// }
+    /// ```
+    /// ## Returns
+    /// [`bool`]
+    pub fn has_listeners(&self, name: &str) -> bool {
+        self.listener_count(name) != 0
+    }
+
+    /// Count the listeners of an event, including async listeners
+    fn listener_total(&self, name: &str) -> usize {
+        let count = self.event(name).map(|x| x.data.len()).unwrap_or(0);
+        #[cfg(feature = "async")]
+        let count = count
+            + self
+                .async_listeners
+                .iter()
+                .filter(|x| x.name == name)
+                .count();
+        count
+    }
+
     /// Emit an event
+    ///
+    /// The event name is first rewritten by routing rules, see [`EventListener::add_route`],
+    /// then resolved through aliases, see [`EventListener::alias`]. Events are delivered
+    /// according to their dispatch mode, see [`EventListener::set_dispatch_mode`].
+    /// If the event has no listeners `unhandledEvent` is emitted with the event name as
+    /// payload, and if the emission is dropped by a rate limit `droppedEvent` is emitted.
+    /// Panicking listeners are reported with `listenerError`, see [`crate::uncaught`].
+    /// Listeners registered on a glob pattern such as `user.*` or `*` receive emissions of
+    /// every matching event, see [`EventListener::on_pattern`].
+    /// Like NodeJS, emitting [`crate::uncaught::ERROR_EVENT`] without listeners panics
+    /// with the payload, [`EventListener::try_emit`] returns it as an error instead.
     /// ## Parameters
     /// * `name` - The name of the event
-    /// * `data` - The data to pass to the listeners
+    /// * `data` - The data to pass to the listeners, see [`crate::payload::IntoPayload`]
     /// ## Example
     /// ```
+    /// use rust_event_listener::outcome::EmitOutcome;
     /// use rust_event_listener::EventListener;
     /// let mut event_listener = EventListener::new();
     /// event_listener.on("test", Box::new(|name, data| {
     ///  println!("test: {:?}", data); // test: test
     /// }));
+    /// event_listener.on("unhandledEvent", Box::new(|_, event| {
+    ///  println!("nobody listens to {}", event);
+    /// }));
     /// event_listener.emit("test", "test".to_string());
+    /// event_listener.remove_all_listeners("test");
+    /// let outcome = event_listener.emit("test", "test".to_string()); // nobody listens to test
+    /// assert_eq!(outcome, EmitOutcome::NoListeners);
     /// ```
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`]
+    /// ## Panics
+    /// If neither the event nor a matching pattern exists, or if `error` has no listeners
+    #[track_caller]
+    pub fn emit<N: AsRef<str>, P: crate::payload::IntoPayload>(
+        &mut self,
+        name: N,
+        data: P,
+    ) -> crate::outcome::EmitOutcome {
+        let name = name.as_ref();
+        let data = data.into_payload();
+        #[cfg(feature = "debug-location")]
+        {
+            self.emit_location = Some(std::panic::Location::caller());
+        }
+        if self.paused && !self.in_meta_event {
+            return self.buffer_paused(name, data);
+        }
+        let resolved = self.resolve_event_name(name);
+        if resolved == crate::uncaught::ERROR_EVENT && !self.has_listeners(name) {
+            panic!("Unhandled error: {}", data);
+        }
+        match self.get_dispatch_mode(&resolved) {
+            crate::dispatch::DispatchMode::Inline => self.emit_inline(name, data),
+            crate::dispatch::DispatchMode::Queued => self.emit_queued(name, data),
+            #[cfg(feature = "async")]
+            crate::dispatch::DispatchMode::Async if self.collects_async() => {
+                self.emit_inline(name, data)
+            }
+            #[cfg(feature = "async")]
+            crate::dispatch::DispatchMode::Async => self.emit_blocking(name, data),
+        }
+    }
+
+    /// Emit an event, calling its listeners regardless of its dispatch mode
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`]
     /// ## Panics
     /// If the event doesn't exist
-    pub fn emit(&mut self, name: &str, data: String) {
-        if self.events.iter().find(|x| x.name == name).is_none() {
+    pub(crate) fn emit_inline(&mut self, name: &str, data: String) -> crate::outcome::EmitOutcome {
+        self.drain_unsubscribed();
+        let routed = self.resolve_event_name(name);
+        let name = routed.as_ref();
+        if !self.has_subscription(name) {
             panic!("Event doesn't exist");
         }
-        for i in &self.events {
-            if i.name == name {
-                for j in &i.data {
-                    (j.callback)(name.to_string(), data.clone());
+        if self.middlewares.is_empty() {
+            return self.deliver_inline(name, data);
+        }
+        let context = match self.run_middlewares(name, data) {
+            Some(context) => context,
+            None => {
+                self.emit_meta_event("droppedEvent", name);
+                return crate::outcome::EmitOutcome::DroppedByPolicy(
+                    crate::outcome::DropReason::Cancelled,
+                );
+            }
+        };
+        let data = context.data.clone();
+        let previous = self.context_slot.replace(Some(context));
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.deliver_inline(name, data)
+        }));
+        *self.context_slot.borrow_mut() = previous;
+        outcome.unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+
+    /// Record an emission that passed the middlewares and call the listeners
+    fn deliver_inline(&mut self, name: &str, data: String) -> crate::outcome::EmitOutcome {
+        if !self.acquire_rate_limit(name) {
+            self.emit_meta_event("droppedEvent", name);
+            return crate::outcome::EmitOutcome::DroppedByPolicy(
+                crate::outcome::DropReason::RateLimited,
+            );
+        }
+        let recorded = self.redact(name, &data);
+        if let Some(journal) = &mut self.journal {
+            journal.record(name, &recorded);
+            if let (Some(entry), Some(location)) = (journal.entries.last_mut(), self.emit_location)
+            {
+                entry.location = Some(location.to_string());
+            }
+        }
+        #[cfg(feature = "persistence")]
+        self.persist_emission(name, &recorded);
+        #[cfg(feature = "persistence")]
+        self.record_ring(name, &recorded);
+        self.record_sticky(name, &data);
+        self.record_history(name, &data);
+        if self.buffer_late_emission(name, &data) {
+            return crate::outcome::EmitOutcome::Buffered;
+        }
+        self.parse_cache.borrow_mut().invalidate();
+        let error_handled = self.reports_listener_errors(name);
+        let started = std::time::Instant::now();
+        let mut called = 0;
+        let mut over_budget = false;
+        let mut failures = vec![];
+        let mut exceeded = vec![];
+        let mut handled = false;
+        let mut fired_once = vec![];
+        let mut consumed = vec![];
+        let mut uncaught = None;
+        #[cfg(feature = "async")]
+        let mut acks = self.ack_calls.take();
+        let outer_envelope = self.open_envelope(name, &data);
+        let outer_propagation = self.start_propagation();
+        for index in self.subscribed_events(name) {
+            if self.propagation_stopped() || uncaught.is_some() {
+                break;
+            }
+            let dispatch_started = std::time::Instant::now();
+            let i = &mut self.events[index];
+            handled |= !i.data.is_empty();
+            let selected = match self.routers.iter_mut().find(|x| i.name == x.0) {
+                Some(router) => router.1.route(name, &data, &i.data),
+                None => (0..i.data.len()).collect(),
+            };
+            for index in selected {
+                if self.propagation.get() || uncaught.is_some() {
+                    break;
+                }
+                if let Some(j) = i.data.get_mut(index) {
+                    if let Some(group) = &j.group {
+                        if crate::quota::is_paused(&self.group_accounts, group) {
+                            continue;
+                        }
+                    }
+                    if !j.accepts(&data, self.clock.now()) {
+                        continue;
+                    }
+                    if let Some((budget, policy)) = self.emit_budget {
+                        if called > 0 && started.elapsed() >= budget {
+                            over_budget = true;
+                            if policy == crate::budget::BudgetPolicy::Defer {
+                                self.deferred.push_back(crate::budget::DeferredCall {
+                                    name: name.to_string(),
+                                    data: data.clone(),
+                                    listener: j.handle(),
+                                });
+                            }
+                            continue;
+                        }
+                    }
+                    called += 1;
+                    if matches!(j.rtype, crate::listener::ListenerTypes::Once) {
+                        fired_once.push(j.handle());
+                    }
+                    let call_started = std::time::Instant::now();
+                    let result = crate::uncaught::call_listener(j, name, &data);
+                    j.stats.record(call_started);
+                    #[cfg(feature = "async")]
+                    if let Some(acks) = &mut acks {
+                        acks.push(crate::ack::AckCall {
+                            listener: j.handle(),
+                            succeeded: result.is_ok(),
+                            finished: std::time::Instant::now(),
+                        });
+                    }
+                    if let Some(group) = &j.group {
+                        if crate::quota::account(&mut self.group_accounts, group, call_started) {
+                            exceeded.push(group.clone());
+                        }
+                    }
+                    if let Err((error, panic)) = result {
+                        if error_handled {
+                            failures.push(error);
+                        } else {
+                            uncaught = self.uncaught_policy.apply(&error, panic);
+                        }
+                    }
                 }
             }
+            let before = i.data.len();
+            i.data.retain(|x| !fired_once.contains(&x.handle()));
+            consumed.extend(std::iter::repeat_n(i.name.clone(), before - i.data.len()));
+            i.stats.record(dispatch_started);
+        }
+        #[cfg(feature = "async")]
+        if !over_budget && uncaught.is_none() && !self.propagation_stopped() {
+            called += self.start_async_listeners(name, &data);
+        }
+        self.end_propagation(outer_propagation);
+        self.close_envelope(outer_envelope);
+        for event in consumed {
+            self.emit_listener_change("removeListener", &event);
+        }
+        self.drain_unsubscribed();
+        if let Some(panic) = uncaught {
+            std::panic::resume_unwind(panic);
+        }
+        for error in failures {
+            self.emit(crate::uncaught::LISTENER_ERROR, error.to_json());
         }
+        if over_budget {
+            self.emit_meta_event("budgetExceeded", name);
+        }
+        for group in exceeded {
+            self.emit_meta_event("quotaExceeded", &group);
+        }
+        #[cfg(feature = "async")]
+        let handled = handled || self.async_listeners.iter().any(|x| x.name == name);
+        if !handled {
+            self.emit_meta_event("unhandledEvent", name);
+        }
+        #[cfg(feature = "async")]
+        {
+            self.ack_calls = acks;
+        }
+        if !handled {
+            return crate::outcome::EmitOutcome::NoListeners;
+        }
+        crate::outcome::EmitOutcome::DeliveredTo(called)
+    }
+
+    /// Get the source location of the last emit call
+    ///
+    /// Locations are only recorded with the `debug-location` feature, they're also
+    /// attached to journal entries, see [`crate::journal::JournalEntry::location`].
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("test", Box::new(|_, _| {}));
+    /// emitter.emit("test", 1);
+    /// if let Some(location) = emitter.last_emit_location() {
+    ///  println!("emitted from {}", location);
+    /// }
+    /// ```
+    /// ## Returns
+    /// [`Option<&std::panic::Location>`]
+    pub fn last_emit_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        self.emit_location
+    }
+
+    /// Emit `newListener` or `removeListener` for an event, if the meta-event has listeners
+    /// ## Parameters
+    /// * `meta` - The meta-event name
+    /// * `name` - The name of the event whose listeners changed
+    pub(crate) fn emit_listener_change(&mut self, meta: &str, name: &str) {
+        if self.event(meta).is_some_and(|x| !x.data.is_empty()) {
+            self.emit_meta_event(meta, name);
+        }
+    }
+
+    /// Emit a meta-event describing another event, guarded against recursion
+    /// ## Parameters
+    /// * `meta` - The meta-event name
+    /// * `name` - The name of the described event, passed as payload
+    pub(crate) fn emit_meta_event(&mut self, meta: &str, name: &str) {
+        if self.in_meta_event {
+            return;
+        }
+        self.in_meta_event = true;
+        let location = self.emit_location;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.emit(meta, name.to_string())
+        }));
+        self.emit_location = location;
+        self.in_meta_event = false;
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for EventListener {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -1,8 +1,34 @@
 use core::fmt::Debug;
+use std::time::{Duration, Instant};
+
 /// EventListener callback closure
 pub type ListenerCallback = Box<dyn Fn(String, String)>;
 
-#[derive(Debug)]
+/// EventListener callback closure that can mutate its captured state
+pub type ListenerCallbackMut = Box<dyn FnMut(String, String)>;
+
+/// Wrap a mutable callback so it can be stored as a [`ListenerCallback`]
+///
+/// The callback is borrowed mutably for the duration of each call.
+pub(crate) fn from_mut(callback: ListenerCallbackMut) -> ListenerCallback {
+    let callback = std::cell::RefCell::new(callback);
+    Box::new(move |name, data| (callback.borrow_mut())(name, data))
+}
+
+/// Shared callback that can be removed with [`crate::EventListener::off_callback`]
+pub type SharedCallback = std::sync::Arc<dyn Fn(String, String)>;
+
+/// Hook called once when a listener registers, with the event's sticky value
+pub type ListenerInit = Box<dyn FnOnce(Option<&String>)>;
+
+/// Predicate deciding whether a listener receives a payload
+pub type ListenerFilter = Box<dyn Fn(&String) -> bool>;
+
+/// Opaque identifier of a registered listener
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerHandle(pub(crate) u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Listener types
 pub enum ListenerTypes {
     /// Listener that will be called on emit
@@ -13,14 +39,98 @@ pub enum ListenerTypes {
 
 /// Listener struct
 pub struct Listener {
+    /// Listener id, assigned on registration
+    pub id: u64,
     /// Listener type
     pub rtype: ListenerTypes,
     /// Callback function
     pub callback: ListenerCallback,
+    /// Listener priority, higher priorities are called first
+    pub priority: i32,
+    /// Payload filter, the listener is skipped when it returns `false`
+    pub filter: Option<ListenerFilter>,
+    /// Min time between two calls, emissions in between are dropped
+    pub throttle: Option<Duration>,
+    /// Group this listener belongs to
+    pub group: Option<String>,
+    /// Last time the throttled listener accepted an emission
+    pub last_call: Option<Instant>,
+    /// Hook called on registration, before any event is received
+    pub init: Option<ListenerInit>,
+    /// Label other listeners of the event can depend on
+    pub label: Option<String>,
+    /// Labels of listeners this listener must run after
+    pub after: Vec<String>,
+    /// Type of the callback, set by [`crate::EventListener::on_unique`]
+    pub identity: Option<std::any::TypeId>,
+    /// Address of the callback, set by [`crate::EventListener::on_shared`]
+    pub shared: Option<usize>,
+    /// Call counters, see [`crate::EventListener::stats`]
+    pub stats: crate::stats::CallStats,
+}
+
+impl Listener {
+    /// Create a new listener with default options
+    /// ## Parameters
+    /// * `rtype` - The listener type
+    /// * `callback` - The callback function
+    pub fn new(rtype: ListenerTypes, callback: ListenerCallback) -> Self {
+        Listener {
+            id: 0,
+            rtype,
+            callback,
+            priority: 0,
+            filter: None,
+            throttle: None,
+            group: None,
+            last_call: None,
+            init: None,
+            label: None,
+            after: vec![],
+            identity: None,
+            shared: None,
+            stats: crate::stats::CallStats::default(),
+        }
+    }
+
+    /// Get the handle of this listener
+    /// ## Returns
+    /// [`ListenerHandle`]
+    pub fn handle(&self) -> ListenerHandle {
+        ListenerHandle(self.id)
+    }
+
+    /// Check filter and throttle options for a payload, updating the throttle state
+    /// ## Parameters
+    /// * `data` - The payload being emitted
+    /// * `now` - The current time, see [`crate::clock::Clock`]
+    /// ## Returns
+    /// [`bool`] - `true` if the callback should be invoked
+    pub(crate) fn accepts(&mut self, data: &String, now: Instant) -> bool {
+        if let Some(filter) = &self.filter {
+            if !filter(data) {
+                return false;
+            }
+        }
+        if let Some(throttle) = self.throttle {
+            if self
+                .last_call
+                .is_some_and(|x| now.duration_since(x) < throttle)
+            {
+                return false;
+            }
+            self.last_call = Some(now);
+        }
+        true
+    }
 }
 
 impl Debug for Listener {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Listener {{ rtype: {:?}, callback: f' }}", self.rtype)
+        write!(
+            f,
+            "Listener {{ id: {}, rtype: {:?}, priority: {}, group: {:?}, label: {:?}, callback: f' }}",
+            self.id, self.rtype, self.priority, self.group, self.label
+        )
     }
 }
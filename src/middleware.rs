@@ -0,0 +1,165 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Emission seen by middlewares, see [`EventListener::use_middleware`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventContext {
+    name: String,
+    /// The payload passed to the listeners
+    pub data: String,
+    metadata: Vec<(String, String)>,
+}
+
+impl EventContext {
+    /// Get the name of the event, after routing and aliases
+    /// ## Returns
+    /// [`&str`]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Attach metadata to the emission, replacing the previous value of the key
+    /// ## Parameters
+    /// * `key` - The metadata key
+    /// * `value` - The metadata value
+    pub fn set_meta(&mut self, key: &str, value: &str) {
+        match self.metadata.iter_mut().find(|x| x.0 == key) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => self.metadata.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Get metadata attached to the emission
+    /// ## Parameters
+    /// * `key` - The metadata key
+    /// ## Returns
+    /// [`Option<&str>`]
+    pub fn meta(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|x| x.0 == key)
+            .map(|x| x.1.as_str())
+    }
+
+    /// Get all metadata attached to the emission, in insertion order
+    /// ## Returns
+    /// [`&[(String, String)]`]
+    pub fn metadata(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+}
+
+/// Decision of a middleware
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiddlewareResult {
+    /// Pass the emission to the next middleware, then to the listeners
+    Continue,
+    /// Drop the emission, it returns [`crate::outcome::DropReason::Cancelled`]
+    Cancel,
+}
+
+/// Hook running before the listeners of every emission
+pub type Middleware = Box<dyn Fn(&mut EventContext) -> MiddlewareResult>;
+
+/// Context of the emission in progress, shared with context listeners
+pub(crate) type ContextSlot = Rc<RefCell<Option<EventContext>>>;
+
+impl EventListener {
+    /// Add a middleware running before the listeners of every emission
+    ///
+    /// Middlewares run in registration order and may rewrite the payload, attach metadata
+    /// or cancel the emission. They run before the emission is recorded, so the journal
+    /// and histories see the rewritten payload.
+    /// ## Parameters
+    /// * `middleware` - The middleware
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::middleware::MiddlewareResult;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.use_middleware(Box::new(|context| {
+    ///  if context.name().starts_with("admin.") && !context.data.starts_with("root:") {
+    ///   return MiddlewareResult::Cancel;
+    ///  }
+    ///  context.set_meta("received", "now");
+    ///  MiddlewareResult::Continue
+    /// }));
+    /// emitter.on("admin.shutdown", Box::new(|_, data| println!("shutdown by {}", data)));
+    /// assert!(emitter.emit("admin.shutdown", "guest:").is_dropped());
+    /// assert!(emitter.emit("admin.shutdown", "root:").is_delivered());
+    /// ```
+    pub fn use_middleware(&mut self, middleware: Middleware) {
+        self.middlewares.push(middleware);
+    }
+
+    /// Remove all middlewares
+    /// ## Returns
+    /// [`usize`] - The number of removed middlewares
+    pub fn clear_middlewares(&mut self) -> usize {
+        let count = self.middlewares.len();
+        self.middlewares.clear();
+        count
+    }
+
+    /// Add a listener receiving the [`EventContext`] built by the middlewares
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::middleware::MiddlewareResult;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.use_middleware(Box::new(|context| {
+    ///  context.set_meta("user", "ada");
+    ///  MiddlewareResult::Continue
+    /// }));
+    /// emitter.on_context("save", |context| {
+    ///  assert_eq!(context.meta("user"), Some("ada"));
+    /// });
+    /// emitter.emit("save", "draft");
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_context<F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        F: Fn(&EventContext) + 'static,
+    {
+        let slot = self.context_slot.clone();
+        self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |name, data| match &*slot.borrow() {
+                    Some(context) if context.name == name => callback(context),
+                    _ => callback(&EventContext {
+                        name,
+                        data,
+                        metadata: vec![],
+                    }),
+                }),
+            ),
+        )
+    }
+
+    /// Run the middlewares over an emission
+    /// ## Returns
+    /// [`Option<EventContext>`] - `None` if a middleware cancelled the emission
+    pub(crate) fn run_middlewares(&self, name: &str, data: String) -> Option<EventContext> {
+        let mut context = EventContext {
+            name: name.to_string(),
+            data,
+            metadata: vec![],
+        };
+        for middleware in &self.middlewares {
+            if middleware(&mut context) == MiddlewareResult::Cancel {
+                return None;
+            }
+        }
+        Some(context)
+    }
+}
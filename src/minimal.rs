@@ -0,0 +1,194 @@
+//! Allocation-only emitter
+//!
+//! [`MinimalEventListener`] only needs `alloc`, so it's available without the `std`
+//! feature, e.g. on embedded firmware. It keeps the core NodeJS-like contract: named
+//! events, `on` / `once` listeners, removal and a max listener count.
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Callback of a [`MinimalEventListener`] listener
+pub type MinimalCallback = Box<dyn Fn(String, String)>;
+
+/// Opaque identifier of a listener registered on a [`MinimalEventListener`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MinimalHandle(u64);
+
+/// Listener registered on a [`MinimalEventListener`]
+struct MinimalListener {
+    id: u64,
+    once: bool,
+    callback: MinimalCallback,
+}
+
+/// Event emitter needing only `alloc`
+/// ## Example
+/// ```
+/// use rust_event_listener::minimal::MinimalEventListener;
+/// let mut emitter = MinimalEventListener::new();
+/// emitter.on("button", Box::new(|_, pin| println!("pressed {}", pin)));
+/// assert_eq!(emitter.emit("button", "7"), 1); // pressed 7
+/// ```
+pub struct MinimalEventListener {
+    events: Vec<(String, Vec<MinimalListener>)>,
+    next_id: u64,
+    max_listeners: usize,
+}
+
+impl MinimalEventListener {
+    /// Create a new MinimalEventListener
+    pub fn new() -> Self {
+        MinimalEventListener {
+            events: Vec::new(),
+            next_id: 0,
+            max_listeners: 10,
+        }
+    }
+
+    /// Sets the maximum number of listeners of an event
+    /// ## Parameters
+    /// * `max_listeners` - The maximum number of listeners
+    pub fn set_max_listeners(&mut self, max_listeners: usize) {
+        self.max_listeners = max_listeners;
+    }
+
+    /// Get max listeners for this MinimalEventListener
+    /// ## Returns
+    /// [`usize`]
+    pub fn get_max_listeners(&self) -> usize {
+        self.max_listeners
+    }
+
+    /// Add a new listener to the event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`MinimalHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on(&mut self, name: &str, callback: MinimalCallback) -> MinimalHandle {
+        self.add_listener(name, false, callback)
+    }
+
+    /// Add a listener that will be called only once
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`MinimalHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once(&mut self, name: &str, callback: MinimalCallback) -> MinimalHandle {
+        self.add_listener(name, true, callback)
+    }
+
+    fn add_listener(&mut self, name: &str, once: bool, callback: MinimalCallback) -> MinimalHandle {
+        let index = match self.events.iter().position(|x| x.0 == name) {
+            Some(index) => index,
+            None => {
+                self.events.push((name.to_string(), Vec::new()));
+                self.events.len() - 1
+            }
+        };
+        let listeners = &mut self.events[index].1;
+        if listeners.len() >= self.max_listeners {
+            panic!("Max listeners reached");
+        }
+        self.next_id += 1;
+        listeners.push(MinimalListener {
+            id: self.next_id,
+            once,
+            callback,
+        });
+        MinimalHandle(self.next_id)
+    }
+
+    /// Remove a single listener
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener was already removed
+    pub fn off(&mut self, handle: MinimalHandle) -> bool {
+        for (_, listeners) in &mut self.events {
+            if let Some(position) = listeners.iter().position(|x| x.id == handle.0) {
+                listeners.remove(position);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Remove all listeners of event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if the event exists
+    pub fn remove_all_listeners(&mut self, name: &str) -> bool {
+        match self.events.iter_mut().find(|x| x.0 == name) {
+            Some((_, listeners)) => {
+                listeners.clear();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the number of listeners of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`usize`]
+    pub fn listener_count(&self, name: &str) -> usize {
+        self.events
+            .iter()
+            .find(|x| x.0 == name)
+            .map(|x| x.1.len())
+            .unwrap_or(0)
+    }
+
+    /// Emit an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`usize`] - The number of called listeners
+    /// ## Panics
+    /// If the event doesn't exist
+    pub fn emit(&mut self, name: &str, data: &str) -> usize {
+        let listeners = &mut self
+            .events
+            .iter_mut()
+            .find(|x| x.0 == name)
+            .expect("Event doesn't exist")
+            .1;
+        for listener in listeners.iter() {
+            (listener.callback)(name.to_string(), data.to_string());
+        }
+        let called = listeners.len();
+        listeners.retain(|x| !x.once);
+        called
+    }
+}
+
+impl Default for MinimalEventListener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for MinimalEventListener {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MinimalEventListener")
+            .field(
+                "events",
+                &self
+                    .events
+                    .iter()
+                    .map(|x| (&x.0, x.1.len()))
+                    .collect::<Vec<_>>(),
+            )
+            .field("max_listeners", &self.max_listeners)
+            .finish()
+    }
+}
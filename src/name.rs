@@ -0,0 +1,137 @@
+use std::borrow::Borrow;
+use std::fmt::Display;
+use std::sync::Arc;
+
+/// Interned event name
+///
+/// Cloning shares the same allocation, so names kept around by callers or by the emitter
+/// are never copied. Get the name an emitter stores with [`crate::EventListener::intern`],
+/// or pass any name to [`crate::EventListener::on`] and [`crate::EventListener::once`],
+/// which store it as is for new events. It derefs to [`str`] for every other method.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventName(Arc<str>);
+
+impl EventName {
+    /// Get the name
+    /// ## Returns
+    /// [`&str`]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check whether both names share the same allocation
+    /// ## Parameters
+    /// * `other` - The other name
+    /// ## Returns
+    /// [`bool`]
+    pub fn ptr_eq(&self, other: &EventName) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::ops::Deref for EventName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for EventName {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for EventName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for EventName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for EventName {
+    fn from(name: &str) -> Self {
+        EventName(Arc::from(name))
+    }
+}
+
+impl From<&String> for EventName {
+    fn from(name: &String) -> Self {
+        EventName(Arc::from(name.as_str()))
+    }
+}
+
+impl From<&EventName> for EventName {
+    fn from(name: &EventName) -> Self {
+        name.clone()
+    }
+}
+
+impl From<String> for EventName {
+    fn from(name: String) -> Self {
+        EventName(Arc::from(name))
+    }
+}
+
+impl From<Arc<str>> for EventName {
+    fn from(name: Arc<str>) -> Self {
+        EventName(name)
+    }
+}
+
+impl From<EventName> for String {
+    fn from(name: EventName) -> Self {
+        name.0.to_string()
+    }
+}
+
+impl PartialEq<str> for EventName {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for EventName {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for EventName {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl crate::EventListener {
+    /// Get the name stored for an event, creating the event if it doesn't exist
+    ///
+    /// Registering a listener with the returned name reuses its allocation, and emitting
+    /// with it borrows the name for routing, alias resolution and the listener lookup.
+    /// Listeners still receive their own [`String`] copy of the name, see
+    /// [`crate::listener::ListenerCallback`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let tick = emitter.intern("tick");
+    /// emitter.on(&tick, Box::new(|_, frame| println!("frame {}", frame)));
+    /// for frame in 0..3 {
+    ///     emitter.emit(&tick, frame); // frame 0, frame 1, frame 2
+    /// }
+    /// assert!(tick.ptr_eq(&emitter.intern("tick")));
+    /// ```
+    /// ## Returns
+    /// [`EventName`]
+    pub fn intern(&mut self, name: &str) -> EventName {
+        self.ensure_event(name).name.clone()
+    }
+}
@@ -0,0 +1,423 @@
+use crate::listener::{ListenerCallback, ListenerHandle};
+use crate::EventListener;
+use std::time::{Duration, Instant};
+
+/// Rate limit allowing `max_events` emissions per `per` window, extra emissions are dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Max emissions per window
+    pub max_events: usize,
+    /// Window length
+    pub per: Duration,
+}
+
+/// What happens to emissions exceeding the rate limit of their namespace
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Drop the emission, emit returns [`crate::outcome::DropReason::RateLimited`]
+    #[default]
+    Lossy,
+    /// Panic, for namespaces whose emissions must never be lost
+    Strict,
+}
+
+/// Namespace configuration, unset options are inherited from the parent namespace or the emitter
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamespaceConfig {
+    /// Max listeners per event, `0` means unlimited
+    pub max_listeners: Option<usize>,
+    /// Emission rate limit shared by all events of the namespace
+    pub rate_limit: Option<RateLimit>,
+    /// What happens to emissions exceeding the rate limit, defaults to
+    /// [`OverflowPolicy::Lossy`]
+    pub overflow: Option<OverflowPolicy>,
+}
+
+/// Namespace configuration and rate limit state
+#[derive(Debug)]
+pub(crate) struct NamespaceState {
+    prefix: String,
+    config: NamespaceConfig,
+    window_start: Option<Instant>,
+    window_count: usize,
+}
+
+impl NamespaceState {
+    fn contains(&self, name: &str) -> bool {
+        name.len() > self.prefix.len()
+            && name.starts_with(&self.prefix)
+            && name[self.prefix.len()..].starts_with('.')
+    }
+}
+
+impl EventListener {
+    /// Set the configuration of a namespace
+    /// ## Parameters
+    /// * `prefix` - The namespace, events named `prefix.*` belong to it
+    /// * `config` - The namespace configuration
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::namespace::{NamespaceConfig, OverflowPolicy, RateLimit};
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.configure_namespace("metrics", NamespaceConfig {
+    ///     max_listeners: Some(100),
+    ///     rate_limit: Some(RateLimit { max_events: 1000, per: Duration::from_secs(1) }),
+    ///     overflow: Some(OverflowPolicy::Lossy),
+    /// });
+    /// emitter.configure_namespace("control", NamespaceConfig {
+    ///     max_listeners: Some(1),
+    ///     rate_limit: Some(RateLimit { max_events: 10, per: Duration::from_secs(1) }),
+    ///     overflow: Some(OverflowPolicy::Strict),
+    /// });
+    /// ```
+    pub fn configure_namespace(&mut self, prefix: &str, config: NamespaceConfig) {
+        match self.namespaces.iter_mut().find(|x| x.prefix == prefix) {
+            Some(state) => state.config = config,
+            None => self.namespaces.push(NamespaceState {
+                prefix: prefix.to_string(),
+                config,
+                window_start: None,
+                window_count: 0,
+            }),
+        }
+    }
+
+    /// Get the configuration of a namespace
+    /// ## Parameters
+    /// * `prefix` - The namespace
+    /// ## Returns
+    /// [`Option<&NamespaceConfig>`]
+    pub fn get_namespace_config(&self, prefix: &str) -> Option<&NamespaceConfig> {
+        self.namespaces
+            .iter()
+            .find(|x| x.prefix == prefix)
+            .map(|x| &x.config)
+    }
+
+    /// Get a view of the emitter where every event name is prefixed with `prefix.`
+    /// ## Parameters
+    /// * `prefix` - The namespace
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let mut metrics = emitter.namespace("metrics");
+    /// metrics.set_max_listeners(100);
+    /// metrics.on("cpu", Box::new(|name, data| {
+    ///  assert_eq!(name, "metrics.cpu");
+    /// }));
+    /// emitter.emit("metrics.cpu", "0.5".to_string());
+    /// ```
+    /// ## Returns
+    /// [`Namespace`]
+    pub fn namespace(&mut self, prefix: &str) -> Namespace<'_> {
+        Namespace {
+            emitter: self,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Also notify the listeners of parent events, like DOM event bubbling
+    ///
+    /// With bubbling enabled, emitting `net.socket.closed` calls the listeners of
+    /// `net.socket.closed`, then `net.socket`, then `net`. Parent listeners receive the
+    /// name of the emitted event. Bubbling is disabled by default.
+    /// ## Parameters
+    /// * `bubbling` - Whether emissions bubble up to parent events
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_bubbling(true);
+    /// emitter.on("net", Box::new(|name, data| {
+    ///  assert_eq!(name, "net.socket.closed");
+    ///  assert_eq!(data, "reset by peer");
+    /// }));
+    /// emitter.emit("net.socket.closed", "reset by peer");
+    /// ```
+    pub fn set_bubbling(&mut self, bubbling: bool) {
+        self.bubbling = bubbling;
+    }
+
+    /// Check whether emissions bubble up to parent events
+    /// ## Returns
+    /// [`bool`]
+    pub fn get_bubbling(&self) -> bool {
+        self.bubbling
+    }
+
+    /// Positions of the parent events an emission bubbles up to, nearest first
+    pub(crate) fn bubble_events(&self, name: &str) -> Vec<usize> {
+        if !self.bubbling {
+            return vec![];
+        }
+        name.rmatch_indices('.')
+            .filter_map(|(position, _)| self.event_index.get(&name[..position]).copied())
+            .collect()
+    }
+
+    /// Namespaces containing the event, most specific first
+    fn namespaces_of(&self, name: &str) -> Vec<usize> {
+        let mut found = (0..self.namespaces.len())
+            .filter(|x| self.namespaces[*x].contains(name))
+            .collect::<Vec<_>>();
+        found.sort_by_key(|x| std::cmp::Reverse(self.namespaces[*x].prefix.len()));
+        found
+    }
+
+    /// Max listeners for an event, taking event and namespace overrides into account
+    pub(crate) fn max_listeners_for(&self, name: &str) -> usize {
+        if let Some(entry) = self.event_max_listeners.iter().find(|x| x.0 == name) {
+            return entry.1;
+        }
+        self.namespaces_of(name)
+            .into_iter()
+            .find_map(|x| self.namespaces[x].config.max_listeners)
+            .unwrap_or(self.max_listeners)
+    }
+
+    /// Overflow policy for an event, taking namespace overrides into account
+    pub(crate) fn overflow_policy_for(&self, name: &str) -> OverflowPolicy {
+        self.namespaces_of(name)
+            .into_iter()
+            .find_map(|x| self.namespaces[x].config.overflow)
+            .unwrap_or_default()
+    }
+
+    /// Count an emission against its namespace rate limit
+    /// ## Returns
+    /// [`bool`] - `false` if the emission exceeds the rate limit and must be dropped
+    /// ## Panics
+    /// If the emission exceeds the rate limit under [`OverflowPolicy::Strict`]
+    pub(crate) fn acquire_rate_limit(&mut self, name: &str) -> bool {
+        let index = match self
+            .namespaces_of(name)
+            .into_iter()
+            .find(|x| self.namespaces[*x].config.rate_limit.is_some())
+        {
+            Some(index) => index,
+            None => return true,
+        };
+        let policy = self.overflow_policy_for(name);
+        let now = self.clock.now();
+        let state = &mut self.namespaces[index];
+        let limit = state.config.rate_limit.unwrap();
+        match state.window_start {
+            Some(start) if now.duration_since(start) < limit.per => {}
+            _ => {
+                state.window_start = Some(now);
+                state.window_count = 0;
+            }
+        }
+        if state.window_count >= limit.max_events {
+            if policy == OverflowPolicy::Strict {
+                panic!("Rate limit of namespace {} exceeded", state.prefix);
+            }
+            return false;
+        }
+        state.window_count += 1;
+        true
+    }
+}
+
+/// Namespaced view of an emitter
+pub struct Namespace<'a> {
+    emitter: &'a mut EventListener,
+    prefix: String,
+}
+
+impl<'a> Namespace<'a> {
+    /// Get the namespace prefix
+    /// ## Returns
+    /// [`&str`]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Get the full event name of an event in this namespace
+    /// ## Parameters
+    /// * `name` - The name of the event relative to the namespace
+    /// ## Returns
+    /// [`String`]
+    pub fn full_name(&self, name: &str) -> String {
+        format!("{}.{}", self.prefix, name)
+    }
+
+    /// Get a nested namespace, inheriting this namespace's configuration
+    /// ## Parameters
+    /// * `prefix` - The nested namespace
+    /// ## Returns
+    /// [`Namespace`]
+    pub fn namespace(&mut self, prefix: &str) -> Namespace<'_> {
+        let prefix = self.full_name(prefix);
+        self.emitter.namespace(&prefix)
+    }
+
+    /// Get the configuration set for this namespace
+    /// ## Returns
+    /// [`NamespaceConfig`] - Options which are not set here are inherited
+    pub fn config(&self) -> NamespaceConfig {
+        self.emitter
+            .get_namespace_config(&self.prefix)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Sets the maximum number of listeners per event in this namespace
+    /// ## Parameters
+    /// `max_listeners` - The maximum number of listeners that can be registered.
+    pub fn set_max_listeners(&mut self, max_listeners: usize) {
+        let mut config = self.config();
+        config.max_listeners = Some(max_listeners);
+        self.emitter.configure_namespace(&self.prefix, config);
+    }
+
+    /// Get max listeners for events in this namespace
+    /// ## Returns
+    /// [`usize`]
+    pub fn get_max_listeners(&self) -> usize {
+        self.emitter.max_listeners_for(&self.full_name("_"))
+    }
+
+    /// Limit the emission rate of the namespace, extra emissions are handled by the
+    /// overflow policy
+    /// ## Parameters
+    /// * `rate_limit` - The rate limit, `None` inherits the parent's
+    pub fn set_rate_limit(&mut self, rate_limit: Option<RateLimit>) {
+        let mut config = self.config();
+        config.rate_limit = rate_limit;
+        self.emitter.configure_namespace(&self.prefix, config);
+    }
+
+    /// Set whether emissions exceeding the rate limit are dropped or panic
+    /// ## Parameters
+    /// * `overflow` - The overflow policy, `None` inherits the parent's
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::namespace::{OverflowPolicy, RateLimit};
+    /// use rust_event_listener::outcome::{DropReason, EmitOutcome};
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// let mut metrics = emitter.namespace("metrics");
+    /// metrics.set_rate_limit(Some(RateLimit { max_events: 1, per: Duration::from_secs(60) }));
+    /// metrics.set_overflow_policy(Some(OverflowPolicy::Lossy));
+    /// metrics.on("cpu", Box::new(|_, _| {}));
+    /// metrics.emit("cpu", "0.5");
+    /// let dropped = metrics.emit("cpu", "0.6");
+    /// assert_eq!(dropped, EmitOutcome::DroppedByPolicy(DropReason::RateLimited));
+    /// ```
+    pub fn set_overflow_policy(&mut self, overflow: Option<OverflowPolicy>) {
+        let mut config = self.config();
+        config.overflow = overflow;
+        self.emitter.configure_namespace(&self.prefix, config);
+    }
+
+    /// Get the overflow policy of the namespace, inherited if not set here
+    /// ## Returns
+    /// [`OverflowPolicy`]
+    pub fn get_overflow_policy(&self) -> OverflowPolicy {
+        self.emitter.overflow_policy_for(&self.full_name("_"))
+    }
+
+    /// Add a new listener to an event of the namespace
+    /// ## Parameters
+    /// * `name` - The name of the event relative to the namespace
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        let name = self.full_name(name);
+        self.emitter.on(&name, callback)
+    }
+
+    /// Add a listener that will be called only once
+    /// ## Parameters
+    /// * `name` - The name of the event relative to the namespace
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        let name = self.full_name(name);
+        self.emitter.once(&name, callback)
+    }
+
+    /// Emit an event of the namespace
+    /// ## Parameters
+    /// * `name` - The name of the event relative to the namespace
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`]
+    /// ## Panics
+    /// If the event doesn't exist
+    pub fn emit<P: crate::payload::IntoPayload>(
+        &mut self,
+        name: &str,
+        data: P,
+    ) -> crate::outcome::EmitOutcome {
+        let name = self.full_name(name);
+        self.emitter.emit(&name, data)
+    }
+
+    /// Remove all listeners of an event of the namespace
+    /// ## Parameters
+    /// * `name` - The name of the event relative to the namespace
+    /// ## Returns
+    /// [`bool`] - `true` if the event was removed, `false` if it wasn't
+    pub fn remove_all_listeners(&mut self, name: &str) -> bool {
+        let name = self.full_name(name);
+        self.emitter.remove_all_listeners(&name)
+    }
+
+    /// Remove a single listener of an event of the namespace
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered on an event of the namespace
+    pub fn off(&mut self, handle: ListenerHandle) -> bool {
+        let owned = self.emitter.listener_event(handle).is_some_and(|x| {
+            x.strip_prefix(self.prefix.as_str())
+                .is_some_and(|x| x.starts_with('.'))
+        });
+        owned && self.emitter.off(handle)
+    }
+}
+
+impl<'a> crate::emitter::Emitter for Namespace<'a> {
+    type Callback = ListenerCallback;
+
+    fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        Namespace::on(self, name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        Namespace::once(self, name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> crate::outcome::EmitOutcome {
+        Namespace::emit(self, name, data)
+    }
+
+    fn try_emit(
+        &mut self,
+        name: &str,
+        data: String,
+    ) -> Result<crate::outcome::EmitOutcome, crate::outcome::EmitError> {
+        self.emitter.check_emit(&self.full_name(name), &data)?;
+        Ok(Namespace::emit(self, name, data))
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        Namespace::remove_all_listeners(self, name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        Namespace::off(self, handle)
+    }
+}
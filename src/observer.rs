@@ -0,0 +1,124 @@
+use crate::listener::{Listener, ListenerCallback, ListenerHandle, ListenerTypes};
+use crate::{Event, EventListener};
+use std::cell::{Ref, RefCell};
+
+/// Handle which can subscribe and inspect but can't emit or remove listeners
+///
+/// Meant to be handed to monitoring and debugging components. Observers share the
+/// emitter through a [`RefCell`], so several components can hold one while the owner
+/// keeps emitting.
+pub struct Observer<'a> {
+    emitter: &'a RefCell<EventListener>,
+}
+
+impl<'a> Observer<'a> {
+    /// Get a read-only observer handle
+    /// ## Parameters
+    /// * `emitter` - The shared emitter
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::observer::Observer;
+    /// use rust_event_listener::EventListener;
+    /// use std::cell::RefCell;
+    /// let emitter = RefCell::new(EventListener::new());
+    /// let tracer = Observer::new(&emitter);
+    /// let metrics = Observer::new(&emitter);
+    /// tracer.on("test", Box::new(|name, data| {
+    ///  println!("observed {}: {}", name, data);
+    /// }));
+    /// assert_eq!(metrics.get_listeners("test").len(), 1);
+    /// emitter.borrow_mut().emit("test", "1".to_string());
+    /// ```
+    /// ## Returns
+    /// [`Observer`]
+    pub fn new(emitter: &'a RefCell<EventListener>) -> Self {
+        Observer { emitter }
+    }
+
+    /// Add a new listener to the event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if the emitter is borrowed, e.g. by one
+    /// of its listeners
+    pub fn on(&self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        self.emitter
+            .borrow_mut()
+            .add_listener(name, Listener::new(ListenerTypes::On, callback))
+    }
+
+    /// Add a listener that will be called only once
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if the emitter is borrowed, e.g. by one
+    /// of its listeners
+    pub fn once(&self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        self.emitter
+            .borrow_mut()
+            .add_listener(name, Listener::new(ListenerTypes::Once, callback))
+    }
+
+    /// Get existing events
+    /// ## Returns
+    /// [`Ref<[Event]>`]
+    pub fn get_events(&self) -> Ref<'a, [Event]> {
+        Ref::map(self.emitter.borrow(), |x| x.events.as_slice())
+    }
+
+    /// Get existing event names
+    /// ## Returns
+    /// [`Vec<String>`]
+    pub fn get_event_names(&self) -> Vec<String> {
+        self.emitter.borrow().get_event_names()
+    }
+
+    /// Get all existent listeners of event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Ref<[Listener]>`] - Empty if the event doesn't exist
+    pub fn get_listeners(&self, name: &str) -> Ref<'a, [Listener]> {
+        Ref::map(self.emitter.borrow(), |x| x.listeners(name).unwrap_or(&[]))
+    }
+
+    /// Get the listeners of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<Ref<[Listener]>>`] - `None` if the event doesn't exist
+    pub fn listeners(&self, name: &str) -> Option<Ref<'a, [Listener]>> {
+        Ref::filter_map(self.emitter.borrow(), |x| x.listeners(name)).ok()
+    }
+
+    /// Get max listeners of the emitter
+    /// ## Returns
+    /// [`usize`]
+    pub fn get_max_listeners(&self) -> usize {
+        self.emitter.borrow().get_max_listeners()
+    }
+
+    /// Count listeners of every event matching a glob pattern
+    /// ## Parameters
+    /// * `pattern` - The glob pattern, see [`crate::glob::matches`]
+    /// ## Returns
+    /// [`usize`]
+    pub fn listener_count_matching(&self, pattern: &str) -> usize {
+        self.emitter.borrow().listener_count_matching(pattern)
+    }
+
+    /// Get the last payload emitted for a sticky event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<String>`]
+    pub fn get_sticky(&self, name: &str) -> Option<String> {
+        self.emitter.borrow().get_sticky(name).cloned()
+    }
+}
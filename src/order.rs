@@ -0,0 +1,44 @@
+use crate::listener::Listener;
+
+/// Reorder listeners so each one runs after the labeled listeners it depends on
+///
+/// Listeners keep their priority order unless a dependency requires otherwise.
+/// Dependencies on labels no listener carries are ignored.
+/// ## Parameters
+/// * `listeners` - The listeners of an event, ordered by priority
+/// ## Returns
+/// [`Result<(), Vec<String>>`] - The labels involved in a cycle, listeners are left untouched
+pub(crate) fn order_listeners(listeners: &mut Vec<Listener>) -> Result<(), Vec<String>> {
+    if listeners.iter().all(|x| x.after.is_empty()) {
+        return Ok(());
+    }
+    let mut pending = (0..listeners.len()).collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|&index| {
+            listeners[index].after.iter().all(|label| {
+                !pending
+                    .iter()
+                    .any(|&other| listeners[other].label.as_deref() == Some(label.as_str()))
+            })
+        });
+        match ready {
+            Some(position) => order.push(pending.remove(position)),
+            None => {
+                return Err(pending
+                    .iter()
+                    .filter_map(|&index| listeners[index].label.clone())
+                    .collect())
+            }
+        }
+    }
+    let mut slots = std::mem::take(listeners)
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+    *listeners = order
+        .into_iter()
+        .map(|index| slots[index].take().unwrap())
+        .collect();
+    Ok(())
+}
@@ -0,0 +1,145 @@
+/// Why an emission was dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+    /// The namespace rate limit was reached, see [`crate::namespace::RateLimit`]
+    RateLimited,
+    /// The queue was full, see [`crate::EventListener::set_queue_capacity`]
+    QueueFull,
+    /// A middleware cancelled the emission, see [`crate::EventListener::use_middleware`]
+    Cancelled,
+    /// The emitter was paused and its buffer was full, see [`crate::EventListener::pause`]
+    PauseBufferFull,
+    /// The event is outside the view it was emitted through, see [`crate::view::View`]
+    OutsideView,
+}
+
+/// Delivery status of an emission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitOutcome {
+    /// Listeners were called, the count excludes listeners skipped by filters or budgets
+    DeliveredTo(usize),
+    /// The emission was queued for [`crate::EventListener::process_pending`]
+    Queued,
+    /// The emission was buffered until the first listener registers
+    Buffered,
+    /// The emission was dropped
+    DroppedByPolicy(DropReason),
+    /// The event has no listeners
+    NoListeners,
+}
+
+impl EmitOutcome {
+    /// Check whether at least one listener was called
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_delivered(&self) -> bool {
+        matches!(self, EmitOutcome::DeliveredTo(n) if *n > 0)
+    }
+
+    /// Check whether the emission was dropped
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_dropped(&self) -> bool {
+        matches!(self, EmitOutcome::DroppedByPolicy(_))
+    }
+}
+
+/// Error returned by [`crate::EventListener::try_emit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmitError {
+    /// The event doesn't exist
+    UnknownEvent(String),
+    /// The emission was dropped
+    Dropped(DropReason),
+    /// [`crate::uncaught::ERROR_EVENT`] was emitted without listeners, with this payload
+    UnhandledError(String),
+}
+
+impl std::fmt::Display for EmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmitError::UnknownEvent(name) => write!(f, "Event doesn't exist: {}", name),
+            EmitError::Dropped(reason) => write!(f, "Emission dropped: {:?}", reason),
+            EmitError::UnhandledError(data) => write!(f, "Unhandled error: {}", data),
+        }
+    }
+}
+
+impl std::error::Error for EmitError {}
+
+impl crate::EventListener {
+    /// Emit an event without panicking if it doesn't exist
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`crate::payload::IntoPayload`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::outcome::EmitError;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("test", Box::new(|_, data| println!("{}", data)));
+    /// assert_eq!(emitter.try_emit("test", 1), Ok(1));
+    /// assert_eq!(
+    ///     emitter.try_emit("missing", 1),
+    ///     Err(EmitError::UnknownEvent("missing".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     emitter.try_emit("error", "disk full"),
+    ///     Err(EmitError::UnhandledError("disk full".to_string()))
+    /// );
+    /// ```
+    /// ## Returns
+    /// [`Result<usize, EmitError>`] - The number of called listeners, `0` if the emission
+    /// was queued or buffered
+    #[track_caller]
+    pub fn try_emit<P: crate::payload::IntoPayload>(
+        &mut self,
+        name: &str,
+        data: P,
+    ) -> Result<usize, EmitError> {
+        let data = data.into_payload();
+        self.check_emit(name, &data)?;
+        match self.emit(name, data) {
+            EmitOutcome::DeliveredTo(count) => Ok(count),
+            EmitOutcome::DroppedByPolicy(reason) => Err(EmitError::Dropped(reason)),
+            EmitOutcome::Queued | EmitOutcome::Buffered | EmitOutcome::NoListeners => Ok(0),
+        }
+    }
+
+    /// Check that an emission wouldn't panic, because its event doesn't exist or it emits
+    /// [`crate::uncaught::ERROR_EVENT`] without listeners
+    pub(crate) fn check_emit(&self, name: &str, data: &str) -> Result<(), EmitError> {
+        let resolved = self.resolve_event_name(name);
+        if resolved == crate::uncaught::ERROR_EVENT && !self.has_listeners(name) {
+            return Err(EmitError::UnhandledError(data.to_string()));
+        }
+        if !self.has_subscription(&resolved) {
+            return Err(EmitError::UnknownEvent(resolved.into_owned()));
+        }
+        Ok(())
+    }
+
+    /// Emit an event, returning whether a listener ran, like NodeJS `emitter.emit`
+    ///
+    /// Unknown events return `false` instead of panicking, so callers can fall back when
+    /// an emission would vanish.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`crate::payload::IntoPayload`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// if !emitter.emit_handled("crash", "disk full") {
+    ///     eprintln!("unhandled crash: disk full");
+    /// }
+    /// emitter.on("crash", Box::new(|_, reason| println!("reporting {}", reason)));
+    /// assert!(emitter.emit_handled("crash", "disk full"));
+    /// ```
+    /// ## Returns
+    /// [`bool`] - `true` if at least one listener was called
+    #[track_caller]
+    pub fn emit_handled<P: crate::payload::IntoPayload>(&mut self, name: &str, data: P) -> bool {
+        matches!(self.try_emit(name, data), Ok(called) if called > 0)
+    }
+}
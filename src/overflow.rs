@@ -0,0 +1,144 @@
+use crate::listener::{Listener, ListenerCallback, ListenerHandle, ListenerTypes};
+use crate::EventListener;
+
+/// What happens when a listener is added to an event that reached its max listener count
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Add the listener and print a possible memory leak warning once per event, like NodeJS
+    Warn,
+    /// Refuse the listener, [`EventListener::try_on`] returns [`MaxListenersExceeded`]
+    /// and [`EventListener::on`] panics with it
+    Error,
+    /// Add the listener silently
+    Ignore,
+    /// Panic
+    #[default]
+    Panic,
+}
+
+/// Error returned when a listener would exceed the max listener count of its event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxListenersExceeded {
+    /// The name of the event
+    pub event: String,
+    /// The max listener count of the event
+    pub max: usize,
+}
+
+impl std::fmt::Display for MaxListenersExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Max listeners reached: {} already has {} listeners",
+            self.event, self.max
+        )
+    }
+}
+
+impl std::error::Error for MaxListenersExceeded {}
+
+impl EventListener {
+    /// Set what happens when an event reaches its max listener count
+    /// ## Parameters
+    /// * `policy` - The overflow policy, defaults to [`OverflowPolicy::Panic`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::overflow::OverflowPolicy;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_max_listeners(1);
+    /// emitter.set_overflow_policy(OverflowPolicy::Warn);
+    /// emitter.on("test", Box::new(|_, _| {}));
+    /// emitter.on("test", Box::new(|_, _| {})); // prints a memory leak warning
+    /// assert_eq!(emitter.get_listeners("test").len(), 2);
+    /// ```
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+        self.overflow_policy = policy;
+    }
+
+    /// Get the overflow policy
+    /// ## Returns
+    /// [`OverflowPolicy`]
+    pub fn get_overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Add a new listener to the event, returning an error instead of panicking when the
+    /// overflow policy is [`OverflowPolicy::Error`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::overflow::OverflowPolicy;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.set_max_listeners(1);
+    /// emitter.set_overflow_policy(OverflowPolicy::Error);
+    /// assert!(emitter.try_on("test", Box::new(|_, _| {})).is_ok());
+    /// let error = emitter.try_on("test", Box::new(|_, _| {})).unwrap_err();
+    /// assert_eq!(error.max, 1);
+    /// ```
+    /// ## Returns
+    /// [`Result<ListenerHandle, MaxListenersExceeded>`]
+    /// ## Panics
+    /// If the max listener count is reached with [`OverflowPolicy::Panic`]
+    pub fn try_on(
+        &mut self,
+        name: &str,
+        callback: ListenerCallback,
+    ) -> Result<ListenerHandle, MaxListenersExceeded> {
+        self.try_add_listener(name, Listener::new(ListenerTypes::On, callback), false)
+    }
+
+    /// Add a listener that will be called only once, returning an error instead of
+    /// panicking when the overflow policy is [`OverflowPolicy::Error`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`Result<ListenerHandle, MaxListenersExceeded>`]
+    /// ## Panics
+    /// If the max listener count is reached with [`OverflowPolicy::Panic`]
+    pub fn try_once(
+        &mut self,
+        name: &str,
+        callback: ListenerCallback,
+    ) -> Result<ListenerHandle, MaxListenersExceeded> {
+        self.try_add_listener(name, Listener::new(ListenerTypes::Once, callback), false)
+    }
+
+    /// Check whether `adding` more listeners fit in an event, applying the overflow policy
+    /// ## Panics
+    /// If the listeners don't fit with [`OverflowPolicy::Panic`]
+    pub(crate) fn check_overflow(
+        &mut self,
+        name: &str,
+        adding: usize,
+    ) -> Result<(), MaxListenersExceeded> {
+        let max = self.max_listeners_for(name);
+        let existing = self.event(name).map(|x| x.data.len()).unwrap_or(0);
+        if max == 0 || existing + adding <= max {
+            return Ok(());
+        }
+        match self.overflow_policy {
+            OverflowPolicy::Panic => panic!("Max listeners reached"),
+            OverflowPolicy::Error => Err(MaxListenersExceeded {
+                event: name.to_string(),
+                max,
+            }),
+            OverflowPolicy::Warn => {
+                if !self.overflow_warned.iter().any(|x| x == name) {
+                    self.overflow_warned.push(name.to_string());
+                    eprintln!(
+                        "MaxListenersExceededWarning: Possible EventListener memory leak detected. {} {} listeners added. Use set_max_listeners() to increase limit",
+                        existing + adding,
+                        name
+                    );
+                }
+                Ok(())
+            }
+            OverflowPolicy::Ignore => Ok(()),
+        }
+    }
+}
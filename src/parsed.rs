@@ -0,0 +1,80 @@
+use crate::listener::ListenerHandle;
+use crate::EventListener;
+use std::any::{Any, TypeId};
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// Parsed payloads of the current emission, shared by every typed listener
+#[derive(Default)]
+pub(crate) struct ParseCache {
+    entries: Vec<(TypeId, String, Rc<dyn Any>)>,
+}
+
+impl ParseCache {
+    /// Forget every parsed payload, called when a new emission starts
+    pub(crate) fn invalidate(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl EventListener {
+    /// Add a listener receiving the payload parsed as `T`
+    ///
+    /// The payload is parsed once per emission no matter how many listeners of the event
+    /// expect the same type. Payloads which don't parse are skipped.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_parsed("port", |_, port: &u16| {
+    ///  println!("listening on {}", port);
+    /// });
+    /// emitter.on_parsed("port", |_, port: &u16| {
+    ///  assert_eq!(*port, 8080);
+    /// });
+    /// emitter.emit("port", "8080".to_string());
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_parsed<T, F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        T: FromStr + 'static,
+        F: Fn(String, &T) + 'static,
+    {
+        let cache = self.parse_cache.clone();
+        self.add_listener(
+            name,
+            crate::listener::Listener::new(
+                crate::listener::ListenerTypes::On,
+                Box::new(move |name, data| {
+                    let cached = cache
+                        .borrow()
+                        .entries
+                        .iter()
+                        .find(|x| x.0 == TypeId::of::<Option<T>>() && x.1 == data)
+                        .map(|x| x.2.clone());
+                    let parsed = match cached {
+                        Some(parsed) => parsed,
+                        None => {
+                            let parsed: Rc<dyn Any> = Rc::new(data.parse::<T>().ok());
+                            cache.borrow_mut().entries.push((
+                                TypeId::of::<Option<T>>(),
+                                data.clone(),
+                                parsed.clone(),
+                            ));
+                            parsed
+                        }
+                    };
+                    if let Some(Some(value)) = parsed.downcast_ref::<Option<T>>() {
+                        callback(name, value);
+                    }
+                }),
+            ),
+        )
+    }
+}
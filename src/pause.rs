@@ -0,0 +1,96 @@
+use crate::outcome::{DropReason, EmitOutcome};
+use crate::EventListener;
+use std::collections::VecDeque;
+
+/// Emissions buffered while paused
+pub(crate) type PauseBuffer = VecDeque<(String, String)>;
+
+/// Emission dropped when the pause buffer is full
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PauseOverflow {
+    /// Drop the oldest buffered emission to make room
+    DropOldest,
+    /// Drop the new emission
+    #[default]
+    DropNewest,
+}
+
+impl EventListener {
+    /// Pause the emitter, buffering emissions until [`EventListener::resume`]
+    ///
+    /// Meta-events such as `newListener` are still emitted while paused.
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.pause();
+    /// emitter.emit("config.loaded", "defaults"); // buffered, no listener yet
+    /// emitter.on("config.loaded", Box::new(|_, data| println!("config: {}", data)));
+    /// assert_eq!(emitter.resume(), 1); // config: defaults
+    /// ```
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resume the emitter, emitting the buffered emissions in order
+    /// ## Returns
+    /// [`usize`] - The number of flushed emissions
+    /// ## Panics
+    /// If a buffered event still doesn't exist
+    pub fn resume(&mut self) -> usize {
+        self.paused = false;
+        let mut flushed = 0;
+        while let Some((name, data)) = self.pause_buffer.pop_front() {
+            self.emit(&name, data);
+            flushed += 1;
+        }
+        flushed
+    }
+
+    /// Check whether the emitter is paused
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Limit the number of emissions buffered while paused
+    /// ## Parameters
+    /// * `capacity` - Max buffered emissions, `0` means unbounded
+    /// * `overflow` - The emission dropped when the buffer is full
+    pub fn set_pause_buffer(&mut self, capacity: usize, overflow: PauseOverflow) {
+        self.pause_capacity = capacity;
+        self.pause_overflow = overflow;
+        if capacity != 0 {
+            while self.pause_buffer.len() > capacity {
+                self.pause_buffer.pop_front();
+            }
+        }
+    }
+
+    /// Get the number of emissions buffered while paused
+    /// ## Returns
+    /// [`usize`]
+    pub fn paused_count(&self) -> usize {
+        self.pause_buffer.len()
+    }
+
+    /// Buffer an emission made while paused
+    pub(crate) fn buffer_paused(&mut self, name: &str, data: String) -> EmitOutcome {
+        if self.pause_capacity != 0 && self.pause_buffer.len() >= self.pause_capacity {
+            match self.pause_overflow {
+                PauseOverflow::DropNewest => {
+                    self.emit_meta_event("droppedEvent", name);
+                    return EmitOutcome::DroppedByPolicy(DropReason::PauseBufferFull);
+                }
+                PauseOverflow::DropOldest => {
+                    if let Some((dropped, _)) = self.pause_buffer.pop_front() {
+                        self.emit_meta_event("droppedEvent", &dropped);
+                    }
+                }
+            }
+        }
+        self.pause_buffer.push_back((name.to_string(), data));
+        EmitOutcome::Buffered
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+/// Conversion of a value into an event payload at the emit call site
+///
+/// Implemented for [`String`], references to any [`Display`] value (so `&str`, `&String`
+/// and `&user` work when `User: Display`), and owned numbers, `bool` and `char`.
+/// Implement it for your own types to emit them by value.
+/// ## Example
+/// ```
+/// use rust_event_listener::payload::IntoPayload;
+/// use rust_event_listener::EventListener;
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// impl IntoPayload for User {
+///     fn into_payload(self) -> String {
+///         format!("{{\"name\":\"{}\"}}", self.name)
+///     }
+/// }
+///
+/// let mut emitter = EventListener::new();
+/// emitter.on("port", Box::new(|_, port| assert_eq!(port, "8080")));
+/// emitter.on("user", Box::new(|_, user| println!("{}", user)));
+/// emitter.emit("port", 8080);
+/// emitter.emit("port", "8080");
+/// emitter.emit("user", User { name: "ada".to_string() });
+/// ```
+pub trait IntoPayload {
+    /// Convert the value into a payload
+    /// ## Returns
+    /// [`String`]
+    fn into_payload(self) -> String;
+}
+
+impl IntoPayload for String {
+    fn into_payload(self) -> String {
+        self
+    }
+}
+
+impl<T: Display + ?Sized> IntoPayload for &T {
+    fn into_payload(self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! display_payload {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoPayload for $ty {
+                fn into_payload(self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+display_payload!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+);
+
+impl crate::EventListener {
+    /// Emit an event, building the payload only if the emission is observed
+    ///
+    /// The payload is built when a listener would receive the emission, see
+    /// [`crate::EventListener::has_listeners`], or when the event keeps its payloads for
+    /// later listeners as a sticky or late-buffered event. Unobserved emissions aren't
+    /// dispatched, recorded or journaled.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `payload` - Builds the data to pass to the listeners
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::outcome::EmitOutcome;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let snapshot = || "state".repeat(1000);
+    /// emitter.on("snapshot", Box::new(|_, state| println!("{} bytes", state.len())));
+    /// emitter.emit_lazy("snapshot", snapshot); // 5000 bytes
+    /// emitter.remove_all_listeners("snapshot");
+    /// let outcome = emitter.emit_lazy("snapshot", || -> String { unreachable!() });
+    /// assert_eq!(outcome, EmitOutcome::NoListeners);
+    /// ```
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`] - [`crate::outcome::EmitOutcome::NoListeners`] if the
+    /// payload wasn't built
+    /// ## Panics
+    /// If the payload is built and the event doesn't exist
+    #[track_caller]
+    pub fn emit_lazy<P: IntoPayload, F: FnOnce() -> P>(
+        &mut self,
+        name: &str,
+        payload: F,
+    ) -> crate::outcome::EmitOutcome {
+        let resolved = self.resolve_event_name(name);
+        if !self.has_listeners(name)
+            && !self.is_sticky(&resolved)
+            && !self.is_buffered(&resolved)
+            && !self.has_history(&resolved)
+        {
+            return crate::outcome::EmitOutcome::NoListeners;
+        }
+        self.emit(name, payload())
+    }
+}
@@ -0,0 +1,295 @@
+//! Persistent journal files
+//!
+//! Journal files contain one JSON object per line, using the event layout of the
+//! [trace format](crate::trace).
+
+use crate::journal::{Journal, JournalEntry};
+use crate::EventListener;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// When appended entries are synced to disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Durability {
+    /// Sync after every entry, no entry is lost on crash
+    Always,
+    /// Sync at most once per interval, entries appended since the last sync can be lost
+    ///
+    /// Pending entries are also synced by [`JournalWriter::commit_due`], which
+    /// [`EventListener::run_timers`] calls for the attached writer, and when the writer
+    /// is dropped.
+    Interval(Duration),
+    /// Sync only on [`JournalWriter::commit`], uncommitted entries can be lost
+    OnCommit,
+}
+
+/// Append-only journal file writer with group commit
+///
+/// Entries are buffered and synced together according to the [`Durability`] mode,
+/// so high-rate logging doesn't pay one fsync per event.
+#[derive(Debug)]
+pub struct JournalWriter {
+    file: BufWriter<File>,
+    durability: Durability,
+    started: Instant,
+    /// Offset of the last entry written before this writer opened the file
+    base: Duration,
+    next_seq: u64,
+    last_sync: Instant,
+    durable_seq: Option<u64>,
+    error: Option<Error>,
+}
+
+impl JournalWriter {
+    /// Create or append to a journal file
+    ///
+    /// A trailing partial line left by a crash is truncated, and sequence numbers and
+    /// offsets continue from the last entry, so the file stays loadable by
+    /// [`Journal::load`] and its offsets stay monotonic across restarts.
+    /// ## Parameters
+    /// * `path` - The journal file path
+    /// * `durability` - The durability mode
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::persist::{Durability, JournalWriter};
+    /// let path = std::env::temp_dir().join("rust_event_listener_doc_writer.jsonl");
+    /// # let _ = std::fs::remove_file(&path);
+    /// let mut writer = JournalWriter::open(&path, Durability::OnCommit).unwrap();
+    /// writer.append("test", "1").unwrap();
+    /// writer.append("test", "2").unwrap();
+    /// assert_eq!(writer.durable_seq(), None);
+    /// writer.commit().unwrap();
+    /// assert_eq!(writer.durable_seq(), Some(1));
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    /// ## Returns
+    /// [`std::io::Result<JournalWriter>`]
+    pub fn open<P: AsRef<Path>>(path: P, durability: Durability) -> std::io::Result<Self> {
+        let last = match OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path.as_ref())
+        {
+            Ok(mut file) => recover(&mut file)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+        let next_seq = last.as_ref().map_or(0, |x| x.seq + 1);
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(JournalWriter {
+            file: BufWriter::new(file),
+            durability,
+            started: Instant::now(),
+            base: last.map_or(Duration::ZERO, |x| x.offset),
+            next_seq,
+            last_sync: Instant::now(),
+            durable_seq: next_seq.checked_sub(1),
+            error: None,
+        })
+    }
+
+    /// Get the durability mode
+    /// ## Returns
+    /// [`Durability`]
+    pub fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    /// Get the sequence number of the last entry guaranteed to be on disk
+    /// ## Returns
+    /// [`Option<u64>`] - `None` if nothing was synced yet
+    pub fn durable_seq(&self) -> Option<u64> {
+        self.durable_seq
+    }
+
+    /// Get the number of appended entries not synced to disk yet
+    /// ## Returns
+    /// [`u64`]
+    pub fn pending(&self) -> u64 {
+        self.next_seq - self.durable_seq.map_or(0, |x| x + 1)
+    }
+
+    /// Append an entry
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `payload` - The emitted payload
+    /// ## Returns
+    /// [`std::io::Result<()>`]
+    pub fn append(&mut self, name: &str, payload: &str) -> std::io::Result<()> {
+        let entry = JournalEntry {
+            seq: self.next_seq,
+            name: name.to_string(),
+            payload: payload.to_string(),
+            offset: self.base + self.started.elapsed(),
+            location: None,
+        };
+        writeln!(self.file, "{}", crate::trace::entry_to_json(&entry))?;
+        self.next_seq += 1;
+        match self.durability {
+            Durability::Always => self.commit(),
+            Durability::Interval(interval) if self.last_sync.elapsed() >= interval => self.commit(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sync every appended entry to disk at once
+    /// ## Returns
+    /// [`std::io::Result<()>`]
+    pub fn commit(&mut self) -> std::io::Result<()> {
+        self.file.flush()?;
+        self.file.get_ref().sync_data()?;
+        self.last_sync = Instant::now();
+        self.durable_seq = self.next_seq.checked_sub(1);
+        Ok(())
+    }
+
+    /// Sync pending entries if the [`Durability::Interval`] interval elapsed since the last sync
+    ///
+    /// Lets entries appended right before a quiet period reach the disk without waiting
+    /// for the next append.
+    /// ## Returns
+    /// [`std::io::Result<bool>`] - `true` if entries were synced
+    pub fn commit_due(&mut self) -> std::io::Result<bool> {
+        match self.durability {
+            Durability::Interval(interval)
+                if self.pending() > 0 && self.last_sync.elapsed() >= interval =>
+            {
+                self.commit().map(|_| true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Take the first error raised while the writer was attached to an emitter
+    /// ## Returns
+    /// [`Option<Error>`]
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+}
+
+impl Drop for JournalWriter {
+    fn drop(&mut self) {
+        if matches!(self.durability, Durability::Interval(_)) && self.pending() > 0 {
+            let _ = self.commit();
+        }
+    }
+}
+
+/// Truncate a trailing partial line left by a crash and read the last entry of a
+/// journal file
+///
+/// The tail is read in growing chunks, so opening a large journal doesn't read it all.
+fn recover(file: &mut File) -> std::io::Result<Option<JournalEntry>> {
+    let len = file.seek(SeekFrom::End(0))?;
+    let mut complete = None;
+    let mut chunk = 4096u64;
+    loop {
+        let start = len.saturating_sub(chunk);
+        let mut tail = vec![0u8; (len - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut tail)?;
+        if complete.is_none() {
+            complete = tail
+                .iter()
+                .rposition(|x| *x == b'\n')
+                .map(|x| start + x as u64 + 1);
+        }
+        let end = complete.map_or(0, |x| (x - start) as usize);
+        let mut lines = tail[..end].split(|x| *x == b'\n').collect::<Vec<_>>();
+        if start > 0 {
+            // The first line of the chunk may be cut
+            lines.remove(0);
+        }
+        let last = lines.iter().rev().find_map(|line| {
+            let value = crate::json::parse(std::str::from_utf8(line).ok()?).ok()?;
+            crate::trace::entry_from_json(&value).ok()
+        });
+        if last.is_some() || start == 0 {
+            let complete = complete.unwrap_or(0);
+            if complete < len {
+                file.set_len(complete)?;
+                file.sync_data()?;
+            }
+            return Ok(last);
+        }
+        chunk *= 2;
+    }
+}
+
+impl Journal {
+    /// Read a journal file written by [`JournalWriter`]
+    /// ## Parameters
+    /// * `path` - The journal file path
+    /// ## Returns
+    /// [`std::io::Result<Journal>`] - Fails with [`ErrorKind::InvalidData`] for malformed lines
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Journal> {
+        let mut journal = Journal::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = crate::json::parse(&line)
+                .and_then(|x| crate::trace::entry_from_json(&x))
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            journal.entries.push(entry);
+        }
+        Ok(journal)
+    }
+}
+
+impl EventListener {
+    /// Persist every emission with a journal writer
+    /// ## Parameters
+    /// * `writer` - The journal writer, `None` detaches the current one
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::persist::{Durability, JournalWriter};
+    /// use rust_event_listener::journal::Journal;
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let path = std::env::temp_dir().join("rust_event_listener_doc_persist.jsonl");
+    /// # let _ = std::fs::remove_file(&path);
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("test", Box::new(|_, _| {}));
+    /// let writer = JournalWriter::open(&path, Durability::Interval(Duration::from_millis(50)));
+    /// emitter.set_journal_writer(Some(writer.unwrap()));
+    /// emitter.emit("test", "1".to_string());
+    /// emitter.journal_writer_mut().unwrap().commit().unwrap();
+    /// assert_eq!(Journal::load(&path).unwrap().len(), 1);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    /// ## Returns
+    /// [`Option<JournalWriter>`] - The previously attached writer
+    pub fn set_journal_writer(&mut self, writer: Option<JournalWriter>) -> Option<JournalWriter> {
+        std::mem::replace(&mut self.journal_writer, writer)
+    }
+
+    /// Get the attached journal writer
+    /// ## Returns
+    /// [`Option<&mut JournalWriter>`]
+    pub fn journal_writer_mut(&mut self) -> Option<&mut JournalWriter> {
+        self.journal_writer.as_mut()
+    }
+
+    /// Append an emission to the attached journal writer, keeping the first error
+    pub(crate) fn persist_emission(&mut self, name: &str, data: &str) {
+        if let Some(writer) = &mut self.journal_writer {
+            if let Err(e) = writer.append(name, data) {
+                writer.error.get_or_insert(e);
+            }
+        }
+    }
+
+    /// Sync the attached journal writer if its interval elapsed, keeping the first error
+    pub(crate) fn commit_due_journal(&mut self) {
+        if let Some(writer) = &mut self.journal_writer {
+            if let Err(e) = writer.commit_due() {
+                writer.error.get_or_insert(e);
+            }
+        }
+    }
+}
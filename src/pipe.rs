@@ -0,0 +1,75 @@
+use crate::listener::ListenerHandle;
+use crate::EventListener;
+
+impl EventListener {
+    /// Forward emissions of an event to another emitter
+    ///
+    /// Forwarded emissions are queued on the target like ingested messages and dispatched
+    /// by its [`EventListener::process_ingested`], so the emitters don't borrow each other.
+    /// The pipe is removed once the target is dropped.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `target` - The emitter receiving the emissions
+    /// * `rename` - The name emissions are forwarded as, `None` keeps the name
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut bus = EventListener::new();
+    /// let mut storage = EventListener::new();
+    /// bus.on("storage.full", Box::new(|_, disk| println!("alert: {} is full", disk)));
+    /// storage.pipe("full", &bus, Some("storage.full"));
+    /// storage.emit("full", "/dev/sda1");
+    /// assert_eq!(bus.process_ingested(), 1); // alert: /dev/sda1 is full
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn pipe(
+        &mut self,
+        name: &str,
+        target: &EventListener,
+        rename: Option<&str>,
+    ) -> ListenerHandle {
+        let target = target.downgrade();
+        let rename = rename.map(|x| x.to_string());
+        self.on_while(name, move |name, data| {
+            target.emit(rename.as_deref().unwrap_or(&name), data)
+        })
+    }
+
+    /// Forward emissions of every event except meta-events to another emitter
+    /// ## Parameters
+    /// * `target` - The emitter receiving the emissions
+    /// * `prefix` - Namespace forwarded emissions are moved to, `None` keeps the names
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut bus = EventListener::new();
+    /// let mut auth = EventListener::new();
+    /// bus.on_pattern("auth.*", Box::new(|name, data| println!("{}: {}", name, data)));
+    /// auth.pipe_all(&bus, Some("auth"));
+    /// auth.emit("login", "ada");
+    /// assert_eq!(bus.process_ingested(), 1); // auth.login: ada
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn pipe_all(&mut self, target: &EventListener, prefix: Option<&str>) -> ListenerHandle {
+        let target = target.downgrade();
+        let prefix = prefix.map(|x| x.to_string());
+        self.mark_pattern("*");
+        self.on_while("*", move |name, data| {
+            if crate::META_EVENTS.contains(&name.as_str())
+                || name == crate::uncaught::LISTENER_ERROR
+            {
+                return true;
+            }
+            match &prefix {
+                Some(prefix) => target.emit(&format!("{}.{}", prefix, name), data),
+                None => target.emit(&name, data),
+            }
+        })
+    }
+}
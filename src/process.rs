@@ -0,0 +1,142 @@
+//! Child-process event source
+//!
+//! [`EventListener::spawn_process`] runs a command and turns its output into events of
+//! the given prefix, delivered by [`EventListener::process_ingested`]:
+//!
+//! * `<prefix>.stdout` - Each stdout line
+//! * `<prefix>.stderr` - Each stderr line
+//! * `<prefix>.exit` - The exit code once both streams are closed, or `signal` if the
+//!   process was terminated by a signal
+
+use crate::EventListener;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Handle of a spawned child process
+#[derive(Debug)]
+pub struct ProcessHandle {
+    id: u32,
+    child: Arc<Mutex<Child>>,
+    waiter: JoinHandle<()>,
+}
+
+impl ProcessHandle {
+    /// Get the OS process id
+    /// ## Returns
+    /// [`u32`]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Kill the process, `<prefix>.exit` is still emitted
+    /// ## Returns
+    /// [`std::io::Result<()>`]
+    pub fn kill(&self) -> std::io::Result<()> {
+        self.child.lock().unwrap().kill()
+    }
+
+    /// Block until the process exited and every event was queued
+    pub fn join(self) {
+        let _ = self.waiter.join();
+    }
+}
+
+/// Forward lines of a stream as events
+fn pump<R: Read + Send + 'static>(
+    stream: R,
+    inbox: Sender<(String, String)>,
+    name: String,
+) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        for line in BufReader::new(stream).lines() {
+            match line {
+                Ok(line) => {
+                    if inbox.send((name.clone(), line)).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    })
+}
+
+impl EventListener {
+    /// Spawn a child process emitting its output and exit status as events
+    ///
+    /// stdout and stderr are captured, stdin is closed. The events are created if they
+    /// don't exist.
+    /// ## Parameters
+    /// * `command` - The command to run
+    /// * `prefix` - The prefix of the emitted events
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::process::Command;
+    /// let mut emitter = EventListener::new();
+    /// let mut command = Command::new("rustc");
+    /// command.arg("--version");
+    /// let process = emitter.spawn_process(command, "proc").unwrap();
+    /// emitter.on("proc.stdout", Box::new(|_, line| println!("{}", line)));
+    /// emitter.on("proc.exit", Box::new(|_, code| assert_eq!(code, "0")));
+    /// process.join();
+    /// emitter.process_ingested();
+    /// ```
+    /// ## Returns
+    /// [`std::io::Result<ProcessHandle>`] - Fails if the process can't be spawned
+    pub fn spawn_process(
+        &mut self,
+        mut command: Command,
+        prefix: &str,
+    ) -> std::io::Result<ProcessHandle> {
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        for stream in ["stdout", "stderr", "exit"] {
+            let name = format!("{}.{}", prefix, stream);
+            self.ensure_event(&name);
+        }
+        let readers = vec![
+            pump(
+                child.stdout.take().unwrap(),
+                self.ingest_sender.clone(),
+                format!("{}.stdout", prefix),
+            ),
+            pump(
+                child.stderr.take().unwrap(),
+                self.ingest_sender.clone(),
+                format!("{}.stderr", prefix),
+            ),
+        ];
+        let id = child.id();
+        let child = Arc::new(Mutex::new(child));
+        let waited = child.clone();
+        let inbox = self.ingest_sender.clone();
+        let exit = format!("{}.exit", prefix);
+        let waiter = std::thread::spawn(move || {
+            for reader in readers {
+                let _ = reader.join();
+            }
+            let status = loop {
+                match waited.lock().unwrap().try_wait() {
+                    Ok(Some(status)) => break Some(status),
+                    Ok(None) => {}
+                    Err(_) => break None,
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            };
+            let code = match status.and_then(|x| x.code()) {
+                Some(code) => code.to_string(),
+                None => "signal".to_string(),
+            };
+            let _ = inbox.send((exit, code));
+        });
+        Ok(ProcessHandle { id, child, waiter })
+    }
+}
@@ -0,0 +1,363 @@
+use crate::outcome::{DropReason, EmitOutcome};
+use crate::payload::IntoPayload;
+use crate::EventListener;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Order in which queued events of different priorities are dispatched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FairnessPolicy {
+    /// Always dispatch the highest priority first, low priorities can starve
+    Strict,
+    /// Raise the priority of waiting events by one every `step` dispatches
+    Aging {
+        /// Dispatches needed to gain one priority level, `0` is treated as `1`
+        step: u32,
+    },
+}
+
+impl Default for FairnessPolicy {
+    fn default() -> Self {
+        FairnessPolicy::Aging { step: 8 }
+    }
+}
+
+/// Queue fill level relative to its capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PressureLevel {
+    /// Below half of the capacity, or the queue is unbounded
+    Low,
+    /// Between half and 80% of the capacity
+    Elevated,
+    /// Above 80% of the capacity
+    High,
+    /// The queue is full, new emissions are dropped
+    Full,
+}
+
+impl PressureLevel {
+    /// Get the level name, used as `pressureChanged` payload
+    /// ## Returns
+    /// [`&str`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PressureLevel::Low => "low",
+            PressureLevel::Elevated => "elevated",
+            PressureLevel::High => "high",
+            PressureLevel::Full => "full",
+        }
+    }
+}
+
+/// Queue pressure seen by producers of an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueuePressure {
+    /// Queued emissions of the event
+    pub depth: usize,
+    /// Queued emissions of every event
+    pub total: usize,
+    /// Queue capacity, `0` means unbounded
+    pub capacity: usize,
+    /// Fill level of the queue
+    pub level: PressureLevel,
+}
+
+/// Emission waiting in the queue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedEvent {
+    /// Event name
+    pub name: String,
+    /// Event payload
+    pub data: String,
+    /// Priority, higher priorities are dispatched first
+    pub priority: i32,
+    /// Dispatch counter value when the event was queued
+    enqueued_at: u64,
+    /// Arrival counter value, breaks ties between priorities
+    seq: u64,
+    /// Instant after which the event is dropped instead of dispatched
+    pub expires_at: Option<std::time::Instant>,
+}
+
+/// Queued emissions, kept in arrival order per priority
+///
+/// Within a priority the oldest emission has waited longest and has the largest aging
+/// boost, so only the front of each priority competes for the next dispatch.
+#[derive(Debug, Default)]
+pub(crate) struct PendingQueue {
+    levels: BTreeMap<i32, VecDeque<QueuedEvent>>,
+    len: usize,
+    next_seq: u64,
+}
+
+impl PendingQueue {
+    /// Get the number of queued emissions
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Queue an emission behind the others of its priority
+    fn push(&mut self, mut event: QueuedEvent) {
+        event.seq = self.next_seq;
+        self.next_seq += 1;
+        self.levels
+            .entry(event.priority)
+            .or_default()
+            .push_back(event);
+        self.len += 1;
+    }
+
+    /// Iterate the queued emissions in arrival order
+    fn iter(&self) -> impl Iterator<Item = &QueuedEvent> {
+        let mut events = self.levels.values().flatten().collect::<Vec<_>>();
+        events.sort_unstable_by_key(|x| x.seq);
+        events.into_iter()
+    }
+
+    /// Remove the next emission to dispatch
+    /// ## Parameters
+    /// * `tick` - The current dispatch counter value
+    /// * `fairness` - The fairness policy
+    fn pop(&mut self, tick: u64, fairness: FairnessPolicy) -> Option<QueuedEvent> {
+        let mut best: Option<(i32, i64, u64)> = None;
+        for (priority, level) in &self.levels {
+            let event = match level.front() {
+                Some(event) => event,
+                None => continue,
+            };
+            let boost = match fairness {
+                FairnessPolicy::Strict => 0,
+                FairnessPolicy::Aging { step } => {
+                    ((tick - event.enqueued_at) / step.max(1) as u64) as i64
+                }
+            };
+            let effective = event.priority as i64 + boost;
+            if best.is_none_or(|x| effective > x.1 || (effective == x.1 && event.seq < x.2)) {
+                best = Some((*priority, effective, event.seq));
+            }
+        }
+        let priority = best?.0;
+        let level = self.levels.get_mut(&priority)?;
+        let event = level.pop_front();
+        if level.is_empty() {
+            self.levels.remove(&priority);
+        }
+        self.len -= 1;
+        event
+    }
+}
+
+impl EventListener {
+    /// Queue an emission with default priority, dispatched by [`EventListener::process_pending`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Returns
+    /// [`EmitOutcome`] - [`EmitOutcome::Queued`], or dropped if the queue is full
+    pub fn emit_queued<P: IntoPayload>(&mut self, name: &str, data: P) -> EmitOutcome {
+        self.emit_queued_with_priority(name, data, 0)
+    }
+
+    /// Queue an emission with a priority
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// * `priority` - The priority, higher priorities are dispatched first
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("job", Box::new(|_, data| {
+    ///  println!("{}", data);
+    /// }));
+    /// emitter.emit_queued_with_priority("job", "bulk".to_string(), 0);
+    /// emitter.emit_queued_with_priority("job", "urgent".to_string(), 10);
+    /// assert_eq!(emitter.process_pending(0), 2); // urgent, bulk
+    /// ```
+    /// ## Returns
+    /// [`EmitOutcome`] - [`EmitOutcome::Queued`], or dropped if the queue is full
+    pub fn emit_queued_with_priority<P: IntoPayload>(
+        &mut self,
+        name: &str,
+        data: P,
+        priority: i32,
+    ) -> EmitOutcome {
+        let expires_at = self.expiry_for(name);
+        self.enqueue(name, data.into_payload(), priority, expires_at)
+    }
+
+    /// Queue an emission, dropping it if the queue is full
+    pub(crate) fn enqueue(
+        &mut self,
+        name: &str,
+        data: String,
+        priority: i32,
+        expires_at: Option<std::time::Instant>,
+    ) -> EmitOutcome {
+        if self.queue_capacity != 0 && self.queue.len() >= self.queue_capacity {
+            self.emit_meta_event("droppedEvent", name);
+            return EmitOutcome::DroppedByPolicy(DropReason::QueueFull);
+        }
+        self.queue.push(QueuedEvent {
+            name: name.to_string(),
+            data,
+            priority,
+            enqueued_at: self.queue_tick,
+            seq: 0,
+            expires_at,
+        });
+        self.update_pressure();
+        EmitOutcome::Queued
+    }
+
+    /// Limit the number of queued emissions, extra emissions are dropped
+    ///
+    /// `pressureChanged` is emitted with the new [`PressureLevel`] name whenever the fill
+    /// level changes, so producers can slow down before emissions are dropped.
+    /// ## Parameters
+    /// * `capacity` - The queue capacity, `0` means unbounded
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::queue::PressureLevel;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("pressureChanged", Box::new(|_, level| {
+    ///  println!("queue pressure is now {}", level);
+    /// }));
+    /// emitter.on("sample", Box::new(|_, _| {}));
+    /// emitter.set_queue_capacity(2);
+    /// emitter.emit_queued("sample", "1".to_string());
+    /// assert_eq!(emitter.pressure("sample").level, PressureLevel::Elevated);
+    /// emitter.emit_queued("sample", "2".to_string());
+    /// assert_eq!(emitter.pressure("sample").level, PressureLevel::Full);
+    /// ```
+    pub fn set_queue_capacity(&mut self, capacity: usize) {
+        self.queue_capacity = capacity;
+        self.update_pressure();
+    }
+
+    /// Get the queue capacity
+    /// ## Returns
+    /// [`usize`] - `0` means unbounded
+    pub fn get_queue_capacity(&self) -> usize {
+        self.queue_capacity
+    }
+
+    /// Get the queue pressure seen by producers of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`QueuePressure`]
+    pub fn pressure(&self, name: &str) -> QueuePressure {
+        QueuePressure {
+            depth: self.queue.iter().filter(|x| x.name == name).count(),
+            total: self.queue.len(),
+            capacity: self.queue_capacity,
+            level: self.pressure_level(),
+        }
+    }
+
+    /// Compute the fill level of the queue
+    fn pressure_level(&self) -> PressureLevel {
+        let (total, capacity) = (self.queue.len(), self.queue_capacity);
+        if capacity == 0 || total * 2 < capacity {
+            PressureLevel::Low
+        } else if total >= capacity {
+            PressureLevel::Full
+        } else if total * 5 > capacity * 4 {
+            PressureLevel::High
+        } else {
+            PressureLevel::Elevated
+        }
+    }
+
+    /// Emit `pressureChanged` if the fill level changed
+    fn update_pressure(&mut self) {
+        let level = self.pressure_level();
+        if level != self.last_pressure {
+            self.last_pressure = level;
+            self.emit_meta_event("pressureChanged", level.as_str());
+        }
+    }
+
+    /// Set the fairness policy of queued dispatch
+    /// ## Parameters
+    /// * `policy` - The fairness policy, defaults to [`FairnessPolicy::Aging`]
+    pub fn set_fairness_policy(&mut self, policy: FairnessPolicy) {
+        self.fairness = policy;
+    }
+
+    /// Get the fairness policy of queued dispatch
+    /// ## Returns
+    /// [`FairnessPolicy`]
+    pub fn get_fairness_policy(&self) -> FairnessPolicy {
+        self.fairness
+    }
+
+    /// Get the queued emissions in arrival order
+    /// ## Returns
+    /// [`Vec<&QueuedEvent>`]
+    pub fn get_pending(&self) -> Vec<&QueuedEvent> {
+        self.queue.iter().collect()
+    }
+
+    /// Get the number of queued emissions
+    /// ## Returns
+    /// [`usize`]
+    pub fn pending_count(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Dispatch queued emissions following the fairness policy
+    ///
+    /// Expired emissions are dropped without counting towards `max`.
+    /// ## Parameters
+    /// * `max` - Max number of emissions to dispatch, `0` dispatches until the queue is empty
+    /// ## Returns
+    /// [`usize`] - The number of dispatched emissions
+    /// ## Panics
+    /// If a queued event doesn't exist
+    pub fn process_pending(&mut self, max: usize) -> usize {
+        let mut processed = 0;
+        while max == 0 || processed < max {
+            let event = match self.queue.pop(self.queue_tick, self.fairness) {
+                Some(event) => event,
+                None => break,
+            };
+            self.queue_tick += 1;
+            self.update_pressure();
+            if self.check_expired(event.expires_at) {
+                continue;
+            }
+            self.emit_inline(&event.name, event.data);
+            processed += 1;
+        }
+        processed
+    }
+
+    /// Dispatch the emissions queued so far, like the event flush of a game loop frame
+    ///
+    /// At most as many emissions as were queued when called are dispatched, emissions
+    /// queued during the flush, such as meta-events of events in queued dispatch mode,
+    /// wait for the next call.
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("input", Box::new(|_, key| println!("pressed {}", key)));
+    /// emitter.emit_queued("input", 'w');
+    /// emitter.emit_queued("input", 'a');
+    /// // end of frame
+    /// assert_eq!(emitter.run(), 2); // pressed w, pressed a
+    /// assert_eq!(emitter.pending_count(), 0);
+    /// ```
+    /// ## Returns
+    /// [`usize`] - The number of dispatched emissions
+    /// ## Panics
+    /// If a queued event doesn't exist
+    pub fn run(&mut self) -> usize {
+        match self.queue.len() {
+            0 => 0,
+            queued => self.process_pending(queued),
+        }
+    }
+}
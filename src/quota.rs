@@ -0,0 +1,164 @@
+use crate::EventListener;
+use std::time::{Duration, Instant};
+
+/// Resource limits of a listener group
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GroupQuota {
+    /// Max listener calls, `None` means unlimited
+    pub max_calls: Option<u64>,
+    /// Max time spent in listener callbacks, `None` means unlimited
+    pub max_time: Option<Duration>,
+    /// Pause the group once the quota is exceeded
+    pub pause: bool,
+}
+
+/// Resources used by a listener group since its usage was last reset
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GroupUsage {
+    /// Listener calls
+    pub calls: u64,
+    /// Time spent in listener callbacks
+    pub time: Duration,
+}
+
+/// Accounting state of a listener group
+#[derive(Debug, Default)]
+pub(crate) struct GroupAccount {
+    name: String,
+    usage: GroupUsage,
+    quota: Option<GroupQuota>,
+    paused: bool,
+    exceeded: bool,
+}
+
+/// Check whether a group is paused
+pub(crate) fn is_paused(accounts: &[GroupAccount], group: &str) -> bool {
+    accounts.iter().any(|x| x.name == group && x.paused)
+}
+
+/// Get the account of a group, creating it if needed
+fn find_or_insert<'a>(accounts: &'a mut Vec<GroupAccount>, group: &str) -> &'a mut GroupAccount {
+    match accounts.iter().position(|x| x.name == group) {
+        Some(index) => &mut accounts[index],
+        None => {
+            accounts.push(GroupAccount {
+                name: group.to_string(),
+                ..Default::default()
+            });
+            accounts.last_mut().unwrap()
+        }
+    }
+}
+
+/// Account a listener call to its group
+/// ## Returns
+/// [`bool`] - `true` if the call made the group exceed its quota
+pub(crate) fn account(accounts: &mut Vec<GroupAccount>, group: &str, started: Instant) -> bool {
+    let elapsed = started.elapsed();
+    let account = find_or_insert(accounts, group);
+    account.usage.calls += 1;
+    account.usage.time += elapsed;
+    let quota = match account.quota {
+        Some(quota) if !account.exceeded => quota,
+        _ => return false,
+    };
+    let exceeded = quota.max_calls.is_some_and(|x| account.usage.calls > x)
+        || quota.max_time.is_some_and(|x| account.usage.time > x);
+    if exceeded {
+        account.exceeded = true;
+        account.paused |= quota.pause;
+    }
+    exceeded
+}
+
+impl EventListener {
+    fn group_account(&mut self, group: &str) -> &mut GroupAccount {
+        find_or_insert(&mut self.group_accounts, group)
+    }
+
+    /// Set the resource quota of a listener group
+    ///
+    /// When a group exceeds its quota `quotaExceeded` is emitted once with the group name
+    /// as payload, until its usage is reset.
+    /// ## Parameters
+    /// * `group` - The group name, see [`crate::builder::ListenBuilder::group`]
+    /// * `quota` - The quota, `None` removes it
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::quota::GroupQuota;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.listen("tick").group("plugin").call(Box::new(|_, _| {}));
+    /// emitter.on("quotaExceeded", Box::new(|_, group| println!("{} is paused", group)));
+    /// emitter.set_group_quota("plugin", Some(GroupQuota {
+    ///     max_calls: Some(2),
+    ///     pause: true,
+    ///     ..Default::default()
+    /// }));
+    /// for tick in 0..5 {
+    ///     emitter.emit("tick", tick);
+    /// }
+    /// assert!(emitter.is_group_paused("plugin"));
+    /// assert_eq!(emitter.group_usage("plugin").calls, 3);
+    /// ```
+    pub fn set_group_quota(&mut self, group: &str, quota: Option<GroupQuota>) {
+        self.group_account(group).quota = quota;
+    }
+
+    /// Get the resource quota of a listener group
+    /// ## Parameters
+    /// * `group` - The group name
+    /// ## Returns
+    /// [`Option<GroupQuota>`]
+    pub fn get_group_quota(&self, group: &str) -> Option<GroupQuota> {
+        self.group_accounts
+            .iter()
+            .find(|x| x.name == group)
+            .and_then(|x| x.quota)
+    }
+
+    /// Get the resources used by a listener group
+    /// ## Parameters
+    /// * `group` - The group name
+    /// ## Returns
+    /// [`GroupUsage`]
+    pub fn group_usage(&self, group: &str) -> GroupUsage {
+        self.group_accounts
+            .iter()
+            .find(|x| x.name == group)
+            .map(|x| x.usage)
+            .unwrap_or_default()
+    }
+
+    /// Reset the resources used by a listener group, it can exceed its quota again
+    /// ## Parameters
+    /// * `group` - The group name
+    pub fn reset_group_usage(&mut self, group: &str) {
+        let account = self.group_account(group);
+        account.usage = GroupUsage::default();
+        account.exceeded = false;
+    }
+
+    /// Stop calling the listeners of a group
+    /// ## Parameters
+    /// * `group` - The group name
+    pub fn pause_group(&mut self, group: &str) {
+        self.group_account(group).paused = true;
+    }
+
+    /// Resume calling the listeners of a paused group
+    /// ## Parameters
+    /// * `group` - The group name
+    pub fn resume_group(&mut self, group: &str) {
+        self.group_account(group).paused = false;
+    }
+
+    /// Check whether a group is paused
+    /// ## Parameters
+    /// * `group` - The group name
+    /// ## Returns
+    /// [`bool`]
+    pub fn is_group_paused(&self, group: &str) -> bool {
+        is_paused(&self.group_accounts, group)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::EventListener;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+/// Pending wait for subscribers of an event
+#[derive(Debug)]
+pub(crate) struct SubscriberWatch {
+    name: String,
+    count: usize,
+    ready: bool,
+    waker: Option<Waker>,
+}
+
+/// Future resolving once an event has enough subscribers, see [`EventListener::subscribers_ready`]
+///
+/// Registering a listener wakes the future. A timer thread enforces the timeout, it stops
+/// as soon as the future resolves or is dropped.
+#[derive(Debug)]
+pub struct SubscribersReady {
+    watch: Rc<RefCell<SubscriberWatch>>,
+    deadline: Instant,
+    /// Dropped to stop the timer thread
+    timer: Option<std::sync::mpsc::Sender<()>>,
+}
+
+impl Future for SubscribersReady {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        if self.watch.borrow().ready {
+            self.timer = None;
+            return Poll::Ready(true);
+        }
+        let now = Instant::now();
+        if now >= self.deadline {
+            self.timer = None;
+            return Poll::Ready(false);
+        }
+        self.watch.borrow_mut().waker = Some(cx.waker().clone());
+        if self.timer.is_none() {
+            let (stop, stopped) = std::sync::mpsc::channel::<()>();
+            self.timer = Some(stop);
+            let waker = cx.waker().clone();
+            let wait = self.deadline - now;
+            std::thread::spawn(move || {
+                if let Err(std::sync::mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(wait)
+                {
+                    waker.wake();
+                }
+            });
+        }
+        Poll::Pending
+    }
+}
+
+impl EventListener {
+    /// Get the number of listeners of an event, including async listeners
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`usize`]
+    pub fn subscriber_count(&self, name: &str) -> usize {
+        let name = self.resolve_alias(name);
+        self.event(&name).map(|x| x.data.len()).unwrap_or(0)
+            + self
+                .async_listeners
+                .iter()
+                .filter(|x| x.name == name)
+                .count()
+    }
+
+    /// Create a future resolving once an event has at least `count` subscribers
+    ///
+    /// The future doesn't borrow the emitter, so async tasks can await it while other
+    /// tasks register listeners. Threads sharing a [`crate::sync::SyncEventListener`] can
+    /// block with [`crate::sync::SyncEventListener::wait_for_subscribers`] instead.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `count` - The number of subscribers to wait for
+    /// * `timeout` - Max time to wait
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::executor::{Executor, ThreadExecutor};
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// let ready = emitter.subscribers_ready("start", 1, Duration::from_secs(1));
+    /// emitter.on("start", Box::new(|_, _| println!("consumer attached")));
+    /// ThreadExecutor.block_on(Box::pin(async move {
+    ///  assert!(ready.await);
+    /// }));
+    /// ```
+    /// ## Returns
+    /// [`SubscribersReady`] - Outputs `false` if the timeout elapsed first
+    pub fn subscribers_ready(
+        &mut self,
+        name: &str,
+        count: usize,
+        timeout: Duration,
+    ) -> SubscribersReady {
+        let name = self.resolve_alias(name);
+        let ready = self.subscriber_count(&name) >= count;
+        let watch = Rc::new(RefCell::new(SubscriberWatch {
+            name: name.into_owned(),
+            count,
+            ready,
+            waker: None,
+        }));
+        if !ready {
+            self.subscriber_watches.retain(|x| x.strong_count() > 0);
+            self.subscriber_watches.push(Rc::downgrade(&watch));
+        }
+        SubscribersReady {
+            watch,
+            deadline: Instant::now() + timeout,
+            timer: None,
+        }
+    }
+
+    /// Resolve subscriber watches of an event after a listener registered
+    pub(crate) fn notify_subscriber_watches(&mut self, name: &str) {
+        if self.subscriber_watches.is_empty() {
+            return;
+        }
+        let count = self.subscriber_count(name);
+        self.subscriber_watches.retain(|x| match Weak::upgrade(x) {
+            Some(watch) => {
+                let mut watch = watch.borrow_mut();
+                if watch.name == name && count >= watch.count {
+                    watch.ready = true;
+                    if let Some(waker) = watch.waker.take() {
+                        waker.wake();
+                    }
+                    false
+                } else {
+                    true
+                }
+            }
+            None => false,
+        });
+    }
+}
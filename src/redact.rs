@@ -0,0 +1,57 @@
+use crate::EventListener;
+
+/// Function rewriting a payload before it leaves the live listeners
+pub type Redaction = Box<dyn Fn(&str) -> String>;
+
+impl EventListener {
+    /// Redact payloads of matching events before they're recorded
+    ///
+    /// Redactions apply to the journal, the journal writer and the ring journal, while
+    /// live listeners still receive the full payload. Several redactions matching the same
+    /// event are applied in registration order.
+    /// ## Parameters
+    /// * `pattern` - Event name or glob pattern, see [`crate::glob::matches`]
+    /// * `redaction` - The redaction function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.start_journal();
+    /// emitter.add_redaction("user.*", Box::new(|data| {
+    ///  data.split(':').next().unwrap_or_default().to_string() + ":<redacted>"
+    /// }));
+    /// emitter.on("user.login", Box::new(|_, data| {
+    ///  assert_eq!(data, "ada:ada@example.com");
+    /// }));
+    /// emitter.emit("user.login", "ada:ada@example.com");
+    /// let journal = emitter.journal().unwrap();
+    /// assert_eq!(journal.entries()[0].payload, "ada:<redacted>");
+    /// ```
+    pub fn add_redaction(&mut self, pattern: &str, redaction: Redaction) {
+        self.redactions.push((pattern.to_string(), redaction));
+    }
+
+    /// Remove every redaction registered with a pattern
+    /// ## Parameters
+    /// * `pattern` - The pattern the redactions were registered with
+    /// ## Returns
+    /// [`usize`] - The number of removed redactions
+    pub fn remove_redactions(&mut self, pattern: &str) -> usize {
+        let before = self.redactions.len();
+        self.redactions.retain(|x| x.0 != pattern);
+        before - self.redactions.len()
+    }
+
+    /// Apply the redactions matching an event to a payload
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The payload
+    /// ## Returns
+    /// [`String`] - The redacted payload
+    pub fn redact(&self, name: &str, data: &str) -> String {
+        self.redactions
+            .iter()
+            .filter(|x| crate::glob::matches(&x.0, name))
+            .fold(data.to_string(), |data, x| (x.1)(&data))
+    }
+}
@@ -0,0 +1,146 @@
+use crate::emitter::Emitter;
+use crate::journal::{Journal, JournalEntry, ReplayError, ReplayReport, ReplaySpeed};
+use std::time::Duration;
+
+/// Predicate over recorded entries
+pub type EntryPredicate = Box<dyn Fn(&JournalEntry) -> bool>;
+
+/// Projection rewriting the payload of recorded entries
+pub type EntryProjection = Box<dyn Fn(&JournalEntry) -> String>;
+
+/// Selection of journal entries to replay
+///
+/// Every option narrows the selection, an empty query selects every entry.
+#[derive(Default)]
+pub struct ReplayQuery {
+    patterns: Vec<String>,
+    predicates: Vec<EntryPredicate>,
+    from: Option<Duration>,
+    until: Option<Duration>,
+    projection: Option<EntryProjection>,
+}
+
+impl ReplayQuery {
+    /// Create a query selecting every entry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select entries whose event name matches a glob pattern, may be repeated
+    /// ## Parameters
+    /// * `pattern` - Event name or glob pattern, see [`crate::glob::matches`]
+    pub fn name(mut self, pattern: &str) -> Self {
+        self.patterns.push(pattern.to_string());
+        self
+    }
+
+    /// Select entries matching a predicate, may be repeated
+    /// ## Parameters
+    /// * `predicate` - The entry predicate
+    pub fn filter<F: Fn(&JournalEntry) -> bool + 'static>(mut self, predicate: F) -> Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Select entries recorded at or after an offset
+    /// ## Parameters
+    /// * `offset` - Time elapsed since the journal started
+    pub fn from(mut self, offset: Duration) -> Self {
+        self.from = Some(offset);
+        self
+    }
+
+    /// Select entries recorded before an offset
+    /// ## Parameters
+    /// * `offset` - Time elapsed since the journal started
+    pub fn until(mut self, offset: Duration) -> Self {
+        self.until = Some(offset);
+        self
+    }
+
+    /// Rewrite the payload of selected entries
+    /// ## Parameters
+    /// * `projection` - The projection
+    pub fn project<F: Fn(&JournalEntry) -> String + 'static>(mut self, projection: F) -> Self {
+        self.projection = Some(Box::new(projection));
+        self
+    }
+
+    /// Check whether the query selects an entry
+    /// ## Parameters
+    /// * `entry` - The entry
+    /// ## Returns
+    /// [`bool`]
+    pub fn matches(&self, entry: &JournalEntry) -> bool {
+        (self.patterns.is_empty()
+            || self
+                .patterns
+                .iter()
+                .any(|x| crate::glob::matches(x, &entry.name)))
+            && self.from.is_none_or(|x| entry.offset >= x)
+            && self.until.is_none_or(|x| entry.offset < x)
+            && self.predicates.iter().all(|x| x(entry))
+    }
+}
+
+impl Journal {
+    /// Select entries with a query, applying its projection
+    /// ## Parameters
+    /// * `query` - The query
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::journal::Journal;
+    /// use rust_event_listener::replay::ReplayQuery;
+    /// let mut journal = Journal::new();
+    /// journal.record("cart.add", "apple:2");
+    /// journal.record("user.login", "ada");
+    /// journal.record("cart.add", "pear:1");
+    /// let query = ReplayQuery::new()
+    ///     .name("cart.*")
+    ///     .filter(|entry| entry.payload.starts_with("apple"))
+    ///     .project(|entry| entry.payload.split(':').nth(1).unwrap_or_default().to_string());
+    /// let selected = journal.select(&query);
+    /// assert_eq!(selected.len(), 1);
+    /// assert_eq!(selected[0].payload, "2");
+    /// ```
+    /// ## Returns
+    /// [`Vec<JournalEntry>`]
+    pub fn select(&self, query: &ReplayQuery) -> Vec<JournalEntry> {
+        self.entries
+            .iter()
+            .filter(|x| query.matches(x))
+            .map(|x| match &query.projection {
+                Some(projection) => JournalEntry {
+                    payload: projection(x),
+                    ..x.clone()
+                },
+                None => x.clone(),
+            })
+            .collect()
+    }
+
+    /// Replay entries selected by a query into an emitter
+    ///
+    /// Entries the emitter rejects are skipped, see [`Journal::replay_into`].
+    /// ## Parameters
+    /// * `emitter` - The target emitter
+    /// * `speed` - The replay speed, timing is relative to the earliest selected entry,
+    ///   entries recorded out of order are replayed in journal order without waiting
+    /// * `query` - The query
+    /// ## Returns
+    /// [`Result<ReplayReport, ReplayError>`] - An error if the speed is invalid, before
+    /// any entry is replayed
+    pub fn replay_query_into<E: Emitter + ?Sized>(
+        &self,
+        emitter: &mut E,
+        speed: ReplaySpeed,
+        query: &ReplayQuery,
+    ) -> Result<ReplayReport, ReplayError> {
+        let mut selected = self.select(query);
+        let first = selected.iter().map(|x| x.offset).min().unwrap_or_default();
+        for entry in &mut selected {
+            entry.offset -= first;
+        }
+        crate::journal::replay_entries(&selected, emitter, speed)
+    }
+}
@@ -0,0 +1,267 @@
+//! Fixed-size ring journal files
+//!
+//! A ring file is preallocated with a fixed number of fixed-size slots and never grows,
+//! the oldest entries are overwritten once it is full. Writes go to the OS page cache
+//! without syncing and the header is only rewritten by [`RingJournal::flush`] or on drop,
+//! which keeps recording down to one slot write per entry, cheap enough to leave enabled
+//! in production (flight-recorder style). After an incident, [`RingJournal::snapshot`] turns the ring
+//! into a regular [`Journal`] which can be exported as a portable trace.
+//!
+//! Layout, all integers little endian:
+//!
+//! * Header (32 bytes): magic `RELRING1`, slot size `u32`, slot count `u32`,
+//!   next sequence number `u64` as of the last flush, creation time in microseconds since
+//!   the unix epoch `u64`
+//! * Slots: entry length `u32` followed by the entry as a JSON object, `0` marks an empty slot
+
+use crate::journal::{Journal, JournalEntry};
+use crate::EventListener;
+use std::fs::{File, OpenOptions};
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 8] = b"RELRING1";
+const HEADER_SIZE: u64 = 32;
+
+/// Fixed-size ring journal file
+#[derive(Debug)]
+pub struct RingJournal {
+    file: File,
+    slot_size: u32,
+    slots: u32,
+    next_seq: u64,
+    created: u64,
+    header_dirty: bool,
+    error: Option<Error>,
+}
+
+fn unix_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_micros() as u64)
+        .unwrap_or(0)
+}
+
+impl RingJournal {
+    /// Open a ring file, creating it if it doesn't exist or has a different geometry
+    /// ## Parameters
+    /// * `path` - The ring file path
+    /// * `slots` - The number of entries kept
+    /// * `slot_size` - The max size of a serialized entry in bytes, including the length prefix
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::ring::RingJournal;
+    /// let path = std::env::temp_dir().join("rust_event_listener_doc_ring.bin");
+    /// # let _ = std::fs::remove_file(&path);
+    /// let mut ring = RingJournal::open(&path, 2, 256).unwrap();
+    /// ring.append("tick", "1").unwrap();
+    /// ring.append("tick", "2").unwrap();
+    /// ring.append("tick", "3").unwrap();
+    /// let snapshot = ring.snapshot().unwrap();
+    /// assert_eq!(snapshot.entries().iter().map(|x| x.payload.as_str()).collect::<Vec<_>>(), vec!["2", "3"]);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    /// ## Returns
+    /// [`std::io::Result<RingJournal>`]
+    pub fn open<P: AsRef<Path>>(path: P, slots: u32, slot_size: u32) -> std::io::Result<Self> {
+        if slots == 0 || slot_size <= 4 {
+            return Err(Error::new(ErrorKind::InvalidInput, "Invalid ring geometry"));
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let mut header = [0u8; HEADER_SIZE as usize];
+        let existing = file.read_exact(&mut header).is_ok()
+            && &header[0..8] == MAGIC
+            && u32::from_le_bytes(header[8..12].try_into().unwrap()) == slot_size
+            && u32::from_le_bytes(header[12..16].try_into().unwrap()) == slots;
+        let mut ring = RingJournal {
+            file,
+            slot_size,
+            slots,
+            next_seq: 0,
+            created: unix_micros(),
+            header_dirty: false,
+            error: None,
+        };
+        if existing {
+            ring.created = u64::from_le_bytes(header[24..32].try_into().unwrap());
+            let flushed = u64::from_le_bytes(header[16..24].try_into().unwrap());
+            // Entries appended after the last header flush are recovered from the slots
+            let recorded = ring.read_slots()?.0.iter().map(|x| x.seq + 1).max();
+            ring.next_seq = recorded.map_or(flushed, |x| x.max(flushed));
+        } else {
+            let size = HEADER_SIZE + slots as u64 * slot_size as u64;
+            ring.file.set_len(0)?;
+            ring.file.set_len(size)?;
+            ring.write_header()?;
+        }
+        Ok(ring)
+    }
+
+    fn write_header(&mut self) -> std::io::Result<()> {
+        let mut header = [0u8; HEADER_SIZE as usize];
+        header[0..8].copy_from_slice(MAGIC);
+        header[8..12].copy_from_slice(&self.slot_size.to_le_bytes());
+        header[12..16].copy_from_slice(&self.slots.to_le_bytes());
+        header[16..24].copy_from_slice(&self.next_seq.to_le_bytes());
+        header[24..32].copy_from_slice(&self.created.to_le_bytes());
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&header)?;
+        self.header_dirty = false;
+        Ok(())
+    }
+
+    /// Write the header if entries were appended since the last flush
+    ///
+    /// Called on drop. Reopening a ring whose header wasn't flushed, e.g. after a crash,
+    /// recovers the sequence number from the slots.
+    /// ## Returns
+    /// [`std::io::Result<()>`]
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        if self.header_dirty {
+            self.write_header()?;
+        }
+        Ok(())
+    }
+
+    /// Get the number of entries kept by the ring
+    /// ## Returns
+    /// [`u32`]
+    pub fn capacity(&self) -> u32 {
+        self.slots
+    }
+
+    /// Get the number of entries appended since the ring file was created
+    /// ## Returns
+    /// [`u64`]
+    pub fn total_appended(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// Append an entry, overwriting the oldest one when the ring is full
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `payload` - The emitted payload
+    /// ## Returns
+    /// [`std::io::Result<()>`] - Fails with [`ErrorKind::InvalidInput`] if the entry doesn't fit in a slot
+    pub fn append(&mut self, name: &str, payload: &str) -> std::io::Result<()> {
+        let entry = JournalEntry {
+            seq: self.next_seq,
+            name: name.to_string(),
+            payload: payload.to_string(),
+            offset: Duration::from_micros(unix_micros().saturating_sub(self.created)),
+            location: None,
+        };
+        let serialized = crate::trace::entry_to_json(&entry);
+        if serialized.len() + 4 > self.slot_size as usize {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Entry doesn't fit in a ring slot",
+            ));
+        }
+        let slot = self.next_seq % self.slots as u64;
+        self.file
+            .seek(SeekFrom::Start(HEADER_SIZE + slot * self.slot_size as u64))?;
+        self.file
+            .write_all(&(serialized.len() as u32).to_le_bytes())?;
+        self.file.write_all(serialized.as_bytes())?;
+        self.next_seq += 1;
+        self.header_dirty = true;
+        Ok(())
+    }
+
+    /// Read the ring into a journal, oldest entry first
+    ///
+    /// Corrupted or torn slots, e.g. written during a crash, are skipped and counted in
+    /// the `corrupted_slots` metadata value.
+    /// ## Returns
+    /// [`std::io::Result<Journal>`]
+    pub fn snapshot(&mut self) -> std::io::Result<Journal> {
+        let (mut entries, corrupted) = self.read_slots()?;
+        entries.sort_by_key(|x| x.seq);
+        let mut journal = Journal::new();
+        journal.set_metadata("source", "ring");
+        journal.set_metadata("created_unix_us", &self.created.to_string());
+        if corrupted > 0 {
+            journal.set_metadata("corrupted_slots", &corrupted.to_string());
+        }
+        journal.entries = entries;
+        Ok(journal)
+    }
+
+    /// Take the first error raised while the ring was attached to an emitter
+    /// ## Returns
+    /// [`Option<Error>`]
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+
+    /// Read the entries of every used slot, in slot order
+    /// ## Returns
+    /// [`std::io::Result<(Vec<JournalEntry>, usize)>`] - The entries and the number of
+    /// skipped corrupted slots
+    fn read_slots(&mut self) -> std::io::Result<(Vec<JournalEntry>, usize)> {
+        let mut entries = vec![];
+        let mut corrupted = 0;
+        let mut slot = vec![0u8; self.slot_size as usize];
+        for index in 0..self.slots as u64 {
+            self.file
+                .seek(SeekFrom::Start(HEADER_SIZE + index * self.slot_size as u64))?;
+            self.file.read_exact(&mut slot)?;
+            let length = u32::from_le_bytes(slot[0..4].try_into().unwrap()) as usize;
+            if length == 0 {
+                continue;
+            }
+            let entry = slot
+                .get(4..4 + length)
+                .and_then(|x| std::str::from_utf8(x).ok())
+                .and_then(|x| crate::json::parse(x).ok())
+                .and_then(|x| crate::trace::entry_from_json(&x).ok());
+            match entry {
+                Some(entry) => entries.push(entry),
+                None => corrupted += 1,
+            }
+        }
+        Ok((entries, corrupted))
+    }
+}
+
+impl Drop for RingJournal {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl EventListener {
+    /// Record every emission into a ring journal
+    /// ## Parameters
+    /// * `ring` - The ring journal, `None` detaches the current one
+    /// ## Returns
+    /// [`Option<RingJournal>`] - The previously attached ring
+    pub fn set_ring_journal(&mut self, ring: Option<RingJournal>) -> Option<RingJournal> {
+        std::mem::replace(&mut self.ring_journal, ring)
+    }
+
+    /// Get the attached ring journal
+    /// ## Returns
+    /// [`Option<&mut RingJournal>`]
+    pub fn ring_journal_mut(&mut self) -> Option<&mut RingJournal> {
+        self.ring_journal.as_mut()
+    }
+
+    /// Append an emission to the attached ring, keeping the first error
+    ///
+    /// Entries which don't fit in a slot are skipped, see [`RingJournal::take_error`].
+    pub(crate) fn record_ring(&mut self, name: &str, data: &str) {
+        if let Some(ring) = &mut self.ring_journal {
+            if let Err(e) = ring.append(name, data) {
+                ring.error.get_or_insert(e);
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::EventListener;
+use std::borrow::Cow;
+
+/// Routing rule returning the new name of an event, or `None` to leave it unchanged
+pub type RouteRule = Box<dyn Fn(&str) -> Option<String>>;
+
+impl EventListener {
+    /// Redirect emissions of an event to another event
+    /// ## Parameters
+    /// * `from` - The emitted event name
+    /// * `to` - The event name listeners are looked up with
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.rename_event("user:created", "user.created");
+    /// emitter.on("user.created", Box::new(|name, data| {
+    ///  assert_eq!(name, "user.created");
+    /// }));
+    /// emitter.emit("user:created", "42".to_string());
+    /// ```
+    pub fn rename_event(&mut self, from: &str, to: &str) {
+        let from = from.to_string();
+        let to = to.to_string();
+        self.add_route(Box::new(
+            move |name| {
+                if name == from {
+                    Some(to.clone())
+                } else {
+                    None
+                }
+            },
+        ));
+    }
+
+    /// Add a routing rule applied to emitted event names before listener lookup
+    ///
+    /// Rules are tried in registration order and the first one returning a name wins.
+    /// ## Parameters
+    /// * `rule` - The routing rule
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.add_route(Box::new(|name| name.contains(':').then(|| name.replace(':', "."))));
+    /// emitter.on("order.paid", Box::new(|_, _| {}));
+    /// emitter.emit("order:paid", "7".to_string());
+    /// ```
+    pub fn add_route(&mut self, rule: RouteRule) {
+        self.routes.push(rule);
+    }
+
+    /// Remove every routing rule
+    pub fn clear_routes(&mut self) {
+        self.routes.clear();
+    }
+
+    /// Resolve the name listeners of an emitted event are looked up with
+    /// ## Parameters
+    /// * `name` - The emitted event name
+    /// ## Returns
+    /// [`Cow<str>`] - Borrows `name` when no rule renames it
+    pub fn route_event_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self.routes.iter().find_map(|rule| rule(name)) {
+            Some(routed) => Cow::Owned(routed),
+            None => Cow::Borrowed(name),
+        }
+    }
+
+    /// Resolve an emitted event name through routing rules, then aliases
+    pub(crate) fn resolve_event_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self.route_event_name(name) {
+            Cow::Borrowed(name) => self.resolve_alias(name),
+            Cow::Owned(routed) => {
+                let aliased = match self.resolve_alias(&routed) {
+                    Cow::Owned(aliased) => Some(aliased),
+                    Cow::Borrowed(_) => None,
+                };
+                Cow::Owned(aliased.unwrap_or(routed))
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::listener::Listener;
+use crate::EventListener;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Selects which listeners of an event receive an emission
+pub trait Router {
+    /// Select listeners for an emission
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The emitted payload
+    /// * `listeners` - The listeners of the event in dispatch order
+    /// ## Returns
+    /// [`Vec<usize>`] - Indexes of the selected listeners, called in the returned order
+    fn route(&mut self, name: &str, data: &str, listeners: &[Listener]) -> Vec<usize>;
+}
+
+/// Deliver to every listener, the default behavior
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Broadcast;
+
+impl Router for Broadcast {
+    fn route(&mut self, _name: &str, _data: &str, listeners: &[Listener]) -> Vec<usize> {
+        (0..listeners.len()).collect()
+    }
+}
+
+/// Deliver each emission to a single listener, rotating between listeners
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Anycast {
+    next: usize,
+}
+
+impl Router for Anycast {
+    fn route(&mut self, _name: &str, _data: &str, listeners: &[Listener]) -> Vec<usize> {
+        if listeners.is_empty() {
+            return vec![];
+        }
+        let index = self.next % listeners.len();
+        self.next = index + 1;
+        vec![index]
+    }
+}
+
+/// Deliver emissions with the same key to the same listener
+pub struct Keyed {
+    key: Box<dyn Fn(&str) -> String>,
+}
+
+impl Keyed {
+    /// Create a keyed router
+    /// ## Parameters
+    /// * `key` - Extracts the routing key from a payload
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::router::Keyed;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("order", Box::new(|_, data| println!("worker 1: {}", data)));
+    /// emitter.on("order", Box::new(|_, data| println!("worker 2: {}", data)));
+    /// emitter.set_router("order", Box::new(Keyed::new(|data| {
+    ///  data.split(':').next().unwrap_or_default().to_string()
+    /// })));
+    /// emitter.emit("order", "customer-1:created".to_string());
+    /// emitter.emit("order", "customer-1:paid".to_string()); // same worker
+    /// ```
+    pub fn new<F: Fn(&str) -> String + 'static>(key: F) -> Self {
+        Keyed { key: Box::new(key) }
+    }
+}
+
+impl Router for Keyed {
+    fn route(&mut self, _name: &str, data: &str, listeners: &[Listener]) -> Vec<usize> {
+        if listeners.is_empty() {
+            return vec![];
+        }
+        let mut hasher = DefaultHasher::new();
+        (self.key)(data).hash(&mut hasher);
+        vec![(hasher.finish() % listeners.len() as u64) as usize]
+    }
+}
+
+/// Deliver to the first listener whose filter accepts the payload
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FirstMatch;
+
+impl Router for FirstMatch {
+    fn route(&mut self, _name: &str, data: &str, listeners: &[Listener]) -> Vec<usize> {
+        let data = data.to_string();
+        listeners
+            .iter()
+            .position(|x| x.filter.as_ref().is_none_or(|filter| filter(&data)))
+            .into_iter()
+            .collect()
+    }
+}
+
+impl EventListener {
+    /// Install a router selecting which listeners of an event receive emissions
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `router` - The router, see [`Broadcast`], [`Anycast`], [`Keyed`] and [`FirstMatch`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::router::Anycast;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("job", Box::new(|_, data| println!("worker 1: {}", data)));
+    /// emitter.on("job", Box::new(|_, data| println!("worker 2: {}", data)));
+    /// emitter.set_router("job", Box::new(Anycast::default()));
+    /// emitter.emit("job", "a".to_string()); // worker 1: a
+    /// emitter.emit("job", "b".to_string()); // worker 2: b
+    /// ```
+    pub fn set_router(&mut self, name: &str, router: Box<dyn Router>) {
+        match self.routers.iter_mut().find(|x| x.0 == name) {
+            Some(entry) => entry.1 = router,
+            None => self.routers.push((name.to_string(), router)),
+        }
+    }
+
+    /// Remove the router of an event, restoring broadcast delivery
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if a router was removed
+    pub fn remove_router(&mut self, name: &str) -> bool {
+        let before = self.routers.len();
+        self.routers.retain(|x| x.0 != name);
+        before != self.routers.len()
+    }
+}
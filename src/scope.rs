@@ -0,0 +1,195 @@
+use crate::listener::{ListenerCallback, ListenerHandle};
+use crate::subscription::Subscription;
+use crate::EventListener;
+use std::cell::RefCell;
+
+/// Child handle whose listeners are removed when it is dropped
+///
+/// Listeners of a scope are registered on the parent emitter, so emissions flow both
+/// ways: listeners of the scope receive events emitted on the parent, and events emitted
+/// through the scope reach the parent's listeners. Scopes share the parent through a
+/// [`RefCell`], so any number of them can be open at once, one per connection or
+/// document.
+pub struct Scope<'a> {
+    emitter: &'a RefCell<EventListener>,
+    listeners: Vec<(String, Subscription)>,
+}
+
+impl<'a> Scope<'a> {
+    /// Open a scope on a shared emitter
+    /// ## Parameters
+    /// * `emitter` - The parent emitter
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::scope::Scope;
+    /// use rust_event_listener::EventListener;
+    /// use std::cell::RefCell;
+    /// let emitter = RefCell::new(EventListener::new());
+    /// emitter.borrow_mut().on("message", Box::new(|_, data| println!("server got {}", data)));
+    /// let mut first = Scope::new(&emitter);
+    /// let mut second = Scope::new(&emitter);
+    /// first.on("message", Box::new(|_, data| println!("first got {}", data)));
+    /// second.on("message", Box::new(|_, data| println!("second got {}", data)));
+    /// second.on("close", Box::new(|_, _| println!("second closed")));
+    /// first.emit("message", "hello"); // server got hello, first got hello, second got hello
+    /// drop(second);
+    /// assert_eq!(emitter.borrow().listener_count("message"), 2);
+    /// assert_eq!(emitter.borrow().listener_count("close"), 0);
+    /// ```
+    /// ## Returns
+    /// [`Scope`]
+    pub fn new(emitter: &'a RefCell<EventListener>) -> Self {
+        Scope {
+            emitter,
+            listeners: vec![],
+        }
+    }
+
+    /// Add a new listener to the event, removed with the scope
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if the emitter is borrowed, e.g. by one
+    /// of its listeners
+    pub fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        let subscription = self.emitter.borrow_mut().subscribe(name, callback);
+        let handle = subscription.handle();
+        self.listeners.push((name.to_string(), subscription));
+        handle
+    }
+
+    /// Add a listener that will be called only once, removed with the scope if not called
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if the emitter is borrowed, e.g. by one
+    /// of its listeners
+    pub fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        let subscription = self.emitter.borrow_mut().subscribe_once(name, callback);
+        let handle = subscription.handle();
+        self.listeners.push((name.to_string(), subscription));
+        handle
+    }
+
+    /// Emit an event on the parent emitter
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`crate::payload::IntoPayload`]
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`]
+    /// ## Panics
+    /// If the event doesn't exist, or if the emitter is borrowed, e.g. by one of its
+    /// listeners
+    #[track_caller]
+    pub fn emit<P: crate::payload::IntoPayload>(
+        &self,
+        name: &str,
+        data: P,
+    ) -> crate::outcome::EmitOutcome {
+        self.emitter.borrow_mut().emit(name, data)
+    }
+
+    /// Remove a single listener of the scope
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered through this scope
+    /// ## Panics
+    /// If the emitter is borrowed, e.g. by one of its listeners
+    pub fn off(&mut self, handle: ListenerHandle) -> bool {
+        match self.listeners.iter().position(|x| x.1.handle() == handle) {
+            Some(position) => {
+                let handle = self.listeners.remove(position).1.forget();
+                self.emitter.borrow_mut().off(handle)
+            }
+            None => false,
+        }
+    }
+
+    /// Remove the listeners of an event registered through this scope
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`usize`] - The number of removed listeners
+    /// ## Panics
+    /// If the emitter is borrowed, e.g. by one of its listeners
+    pub fn remove_all_listeners(&mut self, name: &str) -> usize {
+        let mut emitter = self.emitter.borrow_mut();
+        let mut removed = 0;
+        let mut index = 0;
+        while index < self.listeners.len() {
+            if self.listeners[index].0 == name {
+                let handle = self.listeners.remove(index).1.forget();
+                removed += emitter.off(handle) as usize;
+            } else {
+                index += 1;
+            }
+        }
+        removed
+    }
+
+    /// Open a sibling scope on the same parent, its listeners are removed when it is dropped
+    /// ## Returns
+    /// [`Scope`]
+    pub fn scope(&self) -> Scope<'a> {
+        Scope::new(self.emitter)
+    }
+
+    /// Get the parent emitter, listeners added through it outlive the scope
+    /// ## Returns
+    /// [`&RefCell<EventListener>`]
+    pub fn emitter(&self) -> &'a RefCell<EventListener> {
+        self.emitter
+    }
+}
+
+impl<'a> crate::emitter::Emitter for Scope<'a> {
+    type Callback = ListenerCallback;
+
+    fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        Scope::on(self, name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        Scope::once(self, name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> crate::outcome::EmitOutcome {
+        Scope::emit(self, name, data)
+    }
+
+    fn try_emit(
+        &mut self,
+        name: &str,
+        data: String,
+    ) -> Result<crate::outcome::EmitOutcome, crate::outcome::EmitError> {
+        self.emitter.borrow().check_emit(name, &data)?;
+        Ok(Scope::emit(self, name, data))
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        Scope::remove_all_listeners(self, name) > 0
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        Scope::off(self, handle)
+    }
+}
+
+impl<'a> Drop for Scope<'a> {
+    /// Remove the listeners right away, or before the parent's next emission or
+    /// registration if it is borrowed
+    fn drop(&mut self) {
+        if let Ok(mut emitter) = self.emitter.try_borrow_mut() {
+            for (_, subscription) in self.listeners.drain(..) {
+                emitter.off(subscription.forget());
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::emitter::Emitter;
+use crate::outcome::{EmitError, EmitOutcome};
+use crate::payload::IntoPayload;
+
+/// Adapter forwarding `(name, payload)` pairs into an emitter
+///
+/// Any iterator pipeline can be piped into the emitter with [`Extend::extend`]. With the
+/// `futures` feature the adapter is a `futures::Sink`, so streams can be piped into the
+/// emitter with `stream.forward(emitter.sink())`, emit errors ending the pipeline.
+/// ## Example
+/// ```
+/// use rust_event_listener::emitter::Emitter;
+/// use rust_event_listener::EventListener;
+/// let mut emitter = EventListener::new();
+/// emitter.on("line", Box::new(|name, data| {
+///  println!("{}: {}", name, data);
+/// }));
+/// let lines = vec!["a", "b"].into_iter().map(|x| ("line".to_string(), x.to_string()));
+/// let mut sink = emitter.sink();
+/// sink.extend(lines);
+/// assert_eq!(sink.sent(), 2);
+/// ```
+pub struct EmitSink<'a, E: Emitter + ?Sized> {
+    emitter: &'a mut E,
+    sent: usize,
+}
+
+impl<'a, E: Emitter + ?Sized> EmitSink<'a, E> {
+    /// Create a sink emitting into the given emitter
+    /// ## Parameters
+    /// * `emitter` - The target emitter
+    pub fn new(emitter: &'a mut E) -> Self {
+        EmitSink { emitter, sent: 0 }
+    }
+
+    /// Emit a single item
+    /// ## Parameters
+    /// * `item` - The event name and payload
+    /// ## Panics
+    /// If the target emitter panics while emitting, e.g. the event doesn't exist
+    pub fn send(&mut self, item: (String, String)) {
+        self.emitter.emit(&item.0, item.1);
+        self.sent += 1;
+    }
+
+    /// Emit a single item, returning an error instead of panicking if the event doesn't exist
+    /// ## Parameters
+    /// * `item` - The event name and payload, see [`IntoPayload`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::emitter::Emitter;
+    /// use rust_event_listener::outcome::EmitError;
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let mut sink = emitter.sink();
+    /// assert_eq!(
+    ///     sink.try_send(("missing", 1)),
+    ///     Err(EmitError::UnknownEvent("missing".to_string()))
+    /// );
+    /// assert_eq!(sink.sent(), 0);
+    /// ```
+    /// ## Returns
+    /// [`Result<EmitOutcome, EmitError>`], see [`Emitter::try_emit`]
+    pub fn try_send<N: AsRef<str>, P: IntoPayload>(
+        &mut self,
+        item: (N, P),
+    ) -> Result<EmitOutcome, EmitError> {
+        let outcome = self
+            .emitter
+            .try_emit(item.0.as_ref(), item.1.into_payload())?;
+        self.sent += 1;
+        Ok(outcome)
+    }
+
+    /// Number of items emitted through this sink
+    /// ## Returns
+    /// [`usize`]
+    pub fn sent(&self) -> usize {
+        self.sent
+    }
+}
+
+/// ## Panics
+/// If the target emitter panics while emitting, use [`EmitSink::try_send`] or the
+/// `futures::Sink` implementation to handle errors
+impl<'a, E: Emitter + ?Sized> Extend<(String, String)> for EmitSink<'a, E> {
+    fn extend<T: IntoIterator<Item = (String, String)>>(&mut self, iter: T) {
+        for item in iter {
+            self.send(item);
+        }
+    }
+}
+
+/// Items are emitted by `start_send`, so the sink is always ready and never buffers
+#[cfg(feature = "futures")]
+impl<'a, E: Emitter + ?Sized, P: IntoPayload> futures_sink::Sink<(String, P)> for EmitSink<'a, E> {
+    type Error = EmitError;
+
+    fn poll_ready(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), EmitError>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: std::pin::Pin<&mut Self>, item: (String, P)) -> Result<(), EmitError> {
+        self.get_mut().try_send(item).map(|_| ())
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), EmitError>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), EmitError>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+}
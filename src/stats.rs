@@ -0,0 +1,179 @@
+use crate::listener::ListenerHandle;
+use crate::EventListener;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Invocation counters of a listener, or dispatch counters of an event
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallStats {
+    /// Number of calls
+    pub calls: u64,
+    /// Start of the last call
+    pub last_called: Option<Instant>,
+    /// Time spent in all calls
+    pub total: Duration,
+}
+
+impl CallStats {
+    /// Count a call started at `started` and finished now
+    pub(crate) fn record(&mut self, started: Instant) {
+        self.calls += 1;
+        self.last_called = Some(started);
+        self.total += started.elapsed();
+    }
+
+    /// Get the average time spent in a call
+    /// ## Returns
+    /// [`Duration`] - Zero if never called
+    pub fn average(&self) -> Duration {
+        match self.calls {
+            0 => Duration::ZERO,
+            calls => self.total / calls.min(u32::MAX as u64) as u32,
+        }
+    }
+}
+
+/// Counters of a listener in a [`StatsReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListenerStats {
+    /// The listener
+    pub handle: ListenerHandle,
+    /// The listener label, see [`crate::listener::Listener::label`]
+    pub label: Option<String>,
+    /// The listener counters
+    pub stats: CallStats,
+}
+
+/// Counters of an event and its listeners in a [`StatsReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventStats {
+    /// The name of the event
+    pub name: String,
+    /// Dispatches of the event, counting emissions reaching it through patterns and bubbling
+    pub stats: CallStats,
+    /// Counters of the registered listeners
+    pub listeners: Vec<ListenerStats>,
+}
+
+/// Statistics of every event of an emitter
+///
+/// [`Display`] prints one line per event followed by its listeners, slowest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatsReport {
+    /// Events that were dispatched at least once, or have listeners
+    pub events: Vec<EventStats>,
+}
+
+impl StatsReport {
+    /// Get the listeners with the highest total time, slowest first
+    /// ## Parameters
+    /// * `count` - Max number of listeners
+    /// ## Returns
+    /// [`Vec<(&str, &ListenerStats)>`] - The event name and the listener counters
+    pub fn slowest(&self, count: usize) -> Vec<(&str, &ListenerStats)> {
+        let mut listeners = self
+            .events
+            .iter()
+            .flat_map(|x| x.listeners.iter().map(move |y| (x.name.as_str(), y)))
+            .collect::<Vec<_>>();
+        listeners.sort_by_key(|x| std::cmp::Reverse(x.1.stats.total));
+        listeners.truncate(count);
+        listeners
+    }
+}
+
+impl Display for StatsReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for event in &self.events {
+            writeln!(
+                f,
+                "{}: {} dispatches, {:?} total",
+                event.name, event.stats.calls, event.stats.total
+            )?;
+            let mut listeners = event.listeners.iter().collect::<Vec<_>>();
+            listeners.sort_by_key(|x| std::cmp::Reverse(x.stats.total));
+            for listener in listeners {
+                write!(f, "  #{}", listener.handle.0)?;
+                if let Some(label) = &listener.label {
+                    write!(f, " ({})", label)?;
+                }
+                writeln!(
+                    f,
+                    ": {} calls, {:?} total, {:?} average",
+                    listener.stats.calls,
+                    listener.stats.total,
+                    listener.stats.average()
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EventListener {
+    /// Get call counters and timings of every event and listener
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("resize", Box::new(|_, _| std::thread::sleep(std::time::Duration::from_millis(2))));
+    /// emitter.on("resize", Box::new(|_, _| {}));
+    /// emitter.emit("resize", "800x600");
+    /// emitter.emit("resize", "1024x768");
+    /// let report = emitter.stats();
+    /// let (event, slowest) = report.slowest(1)[0];
+    /// assert_eq!(event, "resize");
+    /// assert_eq!(slowest.stats.calls, 2);
+    /// print!("{}", report);
+    /// // resize: 2 dispatches, 4.3ms total
+    /// //   #1: 2 calls, 4.2ms total, 2.1ms average
+    /// //   #2: 2 calls, 1.2µs total, 600ns average
+    /// ```
+    /// ## Returns
+    /// [`StatsReport`]
+    pub fn stats(&self) -> StatsReport {
+        StatsReport {
+            events: self
+                .events
+                .iter()
+                .filter(|x| x.stats.calls > 0 || !x.data.is_empty())
+                .map(|x| EventStats {
+                    name: x.name.to_string(),
+                    stats: x.stats,
+                    listeners: x
+                        .data
+                        .iter()
+                        .map(|y| ListenerStats {
+                            handle: y.handle(),
+                            label: y.label.clone(),
+                            stats: y.stats,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Get the counters of a listener
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`Option<CallStats>`] - `None` if the listener isn't registered
+    pub fn listener_stats(&self, handle: ListenerHandle) -> Option<CallStats> {
+        self.events
+            .iter()
+            .flat_map(|x| x.data.iter())
+            .find(|x| x.handle() == handle)
+            .map(|x| x.stats)
+    }
+
+    /// Reset the counters of every event and listener
+    pub fn reset_stats(&mut self) {
+        for event in &mut self.events {
+            event.stats = CallStats::default();
+            for listener in &mut event.data {
+                listener.stats = CallStats::default();
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::EventListener;
+
+impl EventListener {
+    /// Remember the last payload emitted for an event
+    ///
+    /// The sticky value is handed to listener init hooks, see [`crate::builder::ListenBuilder::init`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.make_sticky("theme");
+    /// emitter.on("theme", Box::new(|_, _| {}));
+    /// emitter.emit("theme", "dark".to_string());
+    /// assert_eq!(emitter.get_sticky("theme"), Some(&"dark".to_string()));
+    /// ```
+    pub fn make_sticky(&mut self, name: &str) {
+        if !self.sticky.iter().any(|x| x.0 == name) {
+            self.sticky.push((name.to_string(), None));
+        }
+    }
+
+    /// Get the last payload emitted for a sticky event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<&String>`] - `None` if the event isn't sticky or wasn't emitted yet
+    pub fn get_sticky(&self, name: &str) -> Option<&String> {
+        self.sticky
+            .iter()
+            .find(|x| x.0 == name)
+            .and_then(|x| x.1.as_ref())
+    }
+
+    /// Check whether an event is sticky
+    pub(crate) fn is_sticky(&self, name: &str) -> bool {
+        self.sticky.iter().any(|x| x.0 == name)
+    }
+
+    /// Store the payload of a sticky event
+    pub(crate) fn record_sticky(&mut self, name: &str, data: &str) {
+        if let Some(sticky) = self.sticky.iter_mut().find(|x| x.0 == name) {
+            sticky.1 = Some(data.to_string());
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use crate::subscription::Subscription;
+use crate::EventListener;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::Thread;
+use std::time::{Duration, Instant};
+
+/// Bounded buffer between the emitter and an [`EventStream`]
+#[derive(Debug)]
+struct StreamState {
+    items: VecDeque<String>,
+    capacity: usize,
+    dropped: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+/// Closes the stream when the listener feeding it is dropped
+struct StreamCloser(Arc<Mutex<StreamState>>);
+
+impl Drop for StreamCloser {
+    fn drop(&mut self) {
+        let mut state = self.0.lock().unwrap();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Asynchronous stream of the payloads of an event, see [`EventListener::event_stream`]
+///
+/// The stream ends once its listener is removed, by [`EventListener::remove_all_listeners`]
+/// or by dropping the emitter. Dropping the stream removes its listener. The stream is
+/// [`Send`], so it can be consumed on another thread or executor than the emitter's.
+/// With the `futures` feature it implements `futures::Stream`.
+#[derive(Debug)]
+pub struct EventStream {
+    state: Arc<Mutex<StreamState>>,
+    subscription: Subscription,
+}
+
+impl EventStream {
+    /// Poll the next payload, with the same contract as `Stream::poll_next`
+    ///
+    /// The waker is stored next to the buffer and woken by the next emission or when
+    /// the stream ends, from whichever thread emits.
+    /// ## Parameters
+    /// * `cx` - The task context
+    /// ## Returns
+    /// [`Poll<Option<String>>`] - `Ready(None)` once the stream ended
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(item) = state.items.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Wait for the next payload
+    /// ## Returns
+    /// [`Next`] - Resolves to `None` once the stream ended
+    #[allow(clippy::should_implement_trait)] // mirrors `StreamExt::next`, not `Iterator::next`
+    pub fn next(&mut self) -> Next<'_> {
+        Next { stream: self }
+    }
+
+    /// Take the next buffered payload without waiting
+    /// ## Returns
+    /// [`Option<String>`]
+    pub fn try_next(&mut self) -> Option<String> {
+        self.state.lock().unwrap().items.pop_front()
+    }
+
+    /// Get the number of payloads dropped because the buffer was full
+    /// ## Returns
+    /// [`u64`]
+    pub fn dropped(&self) -> u64 {
+        self.state.lock().unwrap().dropped
+    }
+
+    /// Get the handle of the listener feeding the stream
+    /// ## Returns
+    /// [`crate::listener::ListenerHandle`]
+    pub fn handle(&self) -> crate::listener::ListenerHandle {
+        self.subscription.handle()
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_core::Stream for EventStream {
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        EventStream::poll_next(self.get_mut(), cx)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_core::FusedStream for EventStream {
+    fn is_terminated(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.closed && state.items.is_empty()
+    }
+}
+
+/// Future returned by [`EventStream::next`]
+#[derive(Debug)]
+pub struct Next<'a> {
+    stream: &'a mut EventStream,
+}
+
+impl Future for Next<'_> {
+    type Output = Option<String>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        self.stream.poll_next(cx)
+    }
+}
+
+/// Thread waking a [`WaitFor`] at its deadline, stopped when dropped
+#[derive(Debug)]
+struct Timer {
+    waker: Arc<Mutex<Waker>>,
+    cancelled: Arc<AtomicBool>,
+    thread: Thread,
+}
+
+impl Timer {
+    /// Start a thread parking until the deadline, or until the timer is dropped
+    fn start(deadline: Instant, waker: Waker) -> Self {
+        let waker = Arc::new(Mutex::new(waker));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (wake, stop) = (waker.clone(), cancelled.clone());
+        let thread = std::thread::spawn(move || loop {
+            if stop.load(Ordering::Acquire) {
+                return;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                wake.lock().unwrap().wake_by_ref();
+                return;
+            }
+            std::thread::park_timeout(deadline - now);
+        })
+        .thread()
+        .clone();
+        Timer {
+            waker,
+            cancelled,
+            thread,
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Release);
+        self.thread.unpark();
+    }
+}
+
+/// Future resolving with the payload of the next emission, see [`EventListener::wait_for`]
+///
+/// A timeout runs on a helper thread, which exits as soon as the future resolves or is
+/// dropped.
+#[derive(Debug)]
+pub struct WaitFor {
+    stream: EventStream,
+    deadline: Option<Instant>,
+    timer: Option<Timer>,
+}
+
+impl Future for WaitFor {
+    type Output = Option<String>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        if let Poll::Ready(item) = self.stream.poll_next(cx) {
+            self.timer = None;
+            return Poll::Ready(item);
+        }
+        let deadline = match self.deadline {
+            Some(deadline) => deadline,
+            None => return Poll::Pending,
+        };
+        if Instant::now() >= deadline {
+            self.timer = None;
+            return Poll::Ready(None);
+        }
+        match &self.timer {
+            Some(timer) => timer.waker.lock().unwrap().clone_from(cx.waker()),
+            None => self.timer = Some(Timer::start(deadline, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl EventListener {
+    /// Consume the payloads of an event as an asynchronous stream
+    ///
+    /// Payloads are pushed into a bounded buffer, emissions arriving while it is full are
+    /// dropped and counted by [`EventStream::dropped`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `capacity` - The buffer capacity, `0` means unbounded
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::executor::{Executor, ThreadExecutor};
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let mut ticks = emitter.event_stream("tick", 16);
+    /// emitter.emit("tick", 1);
+    /// emitter.emit("tick", 2);
+    /// emitter.remove_all_listeners("tick");
+    /// ThreadExecutor.block_on(Box::pin(async move {
+    ///  while let Some(tick) = ticks.next().await {
+    ///   println!("tick {}", tick); // tick 1, tick 2
+    ///  }
+    /// }));
+    /// ```
+    /// ## Returns
+    /// [`EventStream`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn event_stream(&mut self, name: &str, capacity: usize) -> EventStream {
+        self.stream_listener(name, capacity, false)
+    }
+
+    /// Wait for the next emission of an event, like NodeJS `events.once(emitter, name)`
+    ///
+    /// The future doesn't borrow the emitter. Dropping it before the event is emitted
+    /// removes its listener.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::executor::{Executor, ThreadExecutor};
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let ready = emitter.wait_for("ready");
+    /// emitter.emit("ready", "v1.2");
+    /// ThreadExecutor.block_on(Box::pin(async move {
+    ///  assert_eq!(ready.await.as_deref(), Some("v1.2"));
+    /// }));
+    /// ```
+    /// ## Returns
+    /// [`WaitFor`] - Resolves to the payload, or `None` if the listener was removed first
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn wait_for(&mut self, name: &str) -> WaitFor {
+        WaitFor {
+            stream: self.stream_listener(name, 1, true),
+            deadline: None,
+            timer: None,
+        }
+    }
+
+    /// Wait for the next emission of an event, giving up after a timeout
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `timeout` - Max time to wait
+    /// ## Returns
+    /// [`WaitFor`] - Resolves to the payload, or `None` on timeout
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn wait_for_timeout(&mut self, name: &str, timeout: Duration) -> WaitFor {
+        WaitFor {
+            stream: self.stream_listener(name, 1, true),
+            deadline: Some(Instant::now() + timeout),
+            timer: None,
+        }
+    }
+
+    /// Register a listener pushing payloads into a new stream
+    fn stream_listener(&mut self, name: &str, capacity: usize, once: bool) -> EventStream {
+        let state = Arc::new(Mutex::new(StreamState {
+            items: VecDeque::new(),
+            capacity,
+            dropped: 0,
+            closed: false,
+            waker: None,
+        }));
+        let closer = StreamCloser(state.clone());
+        let callback: crate::listener::ListenerCallback = Box::new(move |_, data| {
+            let mut state = closer.0.lock().unwrap();
+            if state.capacity != 0 && state.items.len() >= state.capacity {
+                state.dropped += 1;
+                return;
+            }
+            state.items.push_back(data);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        let subscription = if once {
+            self.subscribe_once(name, callback)
+        } else {
+            self.subscribe(name, callback)
+        };
+        EventStream {
+            state,
+            subscription,
+        }
+    }
+}
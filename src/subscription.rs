@@ -0,0 +1,153 @@
+use crate::listener::{ListenerCallback, ListenerHandle};
+use crate::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// Guard removing its listener when dropped
+///
+/// Removal is applied by the emitter before its next emission or registration, so a
+/// dropped listener is never called again. The guard doesn't borrow the emitter and can
+/// be stored in the struct whose lifetime the listener should follow.
+#[derive(Debug)]
+#[must_use = "the listener is removed as soon as the subscription is dropped"]
+pub struct Subscription {
+    handle: ListenerHandle,
+    unsubscribe: Option<Sender<ListenerHandle>>,
+}
+
+impl Subscription {
+    /// Get the handle of the guarded listener
+    /// ## Returns
+    /// [`ListenerHandle`]
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Drop the guard without removing the listener
+    /// ## Returns
+    /// [`ListenerHandle`] - Removes the listener with [`EventListener::off`]
+    pub fn forget(mut self) -> ListenerHandle {
+        self.unsubscribe = None;
+        self.handle
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            let _ = unsubscribe.send(self.handle);
+        }
+    }
+}
+
+impl EventListener {
+    /// Add a new listener to the event, removed when the returned guard is dropped
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// {
+    ///     let _subscription = emitter.subscribe("tick", Box::new(|_, data| {
+    ///      println!("tick {}", data);
+    ///     }));
+    ///     emitter.emit("tick", 1); // tick 1
+    /// }
+    /// emitter.emit("tick", 2);
+    /// assert!(emitter.get_listeners("tick").is_empty());
+    /// ```
+    /// ## Returns
+    /// [`Subscription`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn subscribe(&mut self, name: &str, callback: ListenerCallback) -> Subscription {
+        let handle = self.on(name, callback);
+        self.guard(handle)
+    }
+
+    /// Add a listener that will be called only once, removed early when the returned
+    /// guard is dropped
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`Subscription`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn subscribe_once(&mut self, name: &str, callback: ListenerCallback) -> Subscription {
+        let handle = self.once(name, callback);
+        self.guard(handle)
+    }
+
+    /// Tie an already registered listener to a guard
+    /// ## Parameters
+    /// * `handle` - The listener handle
+    /// ## Returns
+    /// [`Subscription`]
+    pub fn guard(&self, handle: ListenerHandle) -> Subscription {
+        Subscription {
+            handle,
+            unsubscribe: Some(self.unsubscribe_sender.clone()),
+        }
+    }
+
+    /// Receive the payloads of an event over a channel, without registering a closure
+    ///
+    /// Emissions fan out to listeners and channel subscribers alike. The receiver can be
+    /// moved to another thread or task; once it is dropped, its listener is removed before
+    /// the next emission or registration.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let prices = emitter.subscribe_broadcast("price");
+    /// let consumer = std::thread::spawn(move || prices.iter().take(2).collect::<Vec<_>>());
+    /// emitter.emit("price", 10);
+    /// emitter.emit("price", 11);
+    /// assert_eq!(consumer.join().unwrap(), vec!["10", "11"]);
+    /// ```
+    /// ## Returns
+    /// [`Receiver<String>`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn subscribe_broadcast(&mut self, name: &str) -> Receiver<String> {
+        let (sender, receiver) = channel();
+        self.on_while(name, move |_, data| sender.send(data).is_ok());
+        receiver
+    }
+
+    /// Add a listener removed once its callback returns `false`
+    ///
+    /// Removal is applied before the next emission or registration, like a dropped
+    /// [`Subscription`].
+    pub(crate) fn on_while<F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        F: Fn(String, String) -> bool + 'static,
+    {
+        let guard = Rc::new(RefCell::new(None::<Subscription>));
+        let owner = guard.clone();
+        let subscription = self.subscribe(
+            name,
+            Box::new(move |name, data| {
+                if !callback(name, data) {
+                    owner.borrow_mut().take();
+                }
+            }),
+        );
+        let handle = subscription.handle();
+        *guard.borrow_mut() = Some(subscription);
+        handle
+    }
+
+    /// Remove listeners whose subscription guards were dropped
+    pub(crate) fn drain_unsubscribed(&mut self) {
+        while let Ok(handle) = self.unsubscribe_receiver.try_recv() {
+            self.remove_listener_by_handle(handle);
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Thread-safe emitter
+//!
+//! [`SyncEventListener`] is a cheaply cloneable handle to a shared listener table, so
+//! worker threads can register listeners and emit events through `&self`. Callbacks must
+//! be `Send + Sync` and are called on the emitting thread, outside of the table lock, so
+//! a callback may emit or register listeners itself.
+//!
+//! Each event keeps its listeners in a copy-on-write snapshot: an emission clones the
+//! [`Arc`] of the list and iterates it without holding the lock, while `on`, `off` and
+//! `remove_all_listeners` copy the list only if an emission is still iterating it. A
+//! re-entrant change is therefore safe and takes effect from the next emission, except
+//! that a removed listener which hasn't been reached yet is skipped.
+use crate::listener::{ListenerHandle, ListenerTypes};
+use crate::overflow::MaxListenersExceeded;
+use crate::payload::IntoPayload;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Thread-safe listener callback
+pub type SyncListenerCallback = Box<dyn Fn(String, String) + Send + Sync>;
+
+/// Listener registered on a [`SyncEventListener`]
+#[derive(Clone)]
+struct SyncListener {
+    id: u64,
+    rtype: ListenerTypes,
+    callback: Arc<SyncListenerCallback>,
+    /// Set by the first emission reaching a once listener
+    fired: Arc<AtomicBool>,
+    /// Cleared on removal, so in-progress emissions skip the listener
+    active: Arc<AtomicBool>,
+}
+
+impl SyncListener {
+    /// Check whether an emission should call this listener, claiming once listeners
+    fn claim(&self, fired_once: &mut Vec<u64>) -> bool {
+        if !self.active.load(Ordering::Acquire) {
+            return false;
+        }
+        if self.rtype == ListenerTypes::Once {
+            if self.fired.swap(true, Ordering::AcqRel) {
+                return false;
+            }
+            fired_once.push(self.id);
+        }
+        true
+    }
+}
+
+/// Copy-on-write listener list of an event
+type SyncListeners = Arc<Vec<SyncListener>>;
+
+/// Listener table shared by clones of a [`SyncEventListener`]
+struct SyncState {
+    events: Vec<(String, SyncListeners)>,
+    max_listeners: usize,
+}
+
+/// Thread-safe, cloneable emitter
+/// ## Example
+/// ```
+/// use rust_event_listener::sync::SyncEventListener;
+/// let emitter = SyncEventListener::new();
+/// emitter.on("job", Box::new(|_, data| println!("done {}", data)));
+/// let workers = (0..4)
+///     .map(|id| {
+///         let emitter = emitter.clone();
+///         std::thread::spawn(move || emitter.emit("job", id))
+///     })
+///     .collect::<Vec<_>>();
+/// for worker in workers {
+///     assert_eq!(worker.join().unwrap(), 1);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SyncEventListener {
+    state: Arc<RwLock<SyncState>>,
+    next_id: Arc<AtomicU64>,
+    /// Registration counter, signalled after each registration
+    registered: Arc<(Mutex<u64>, Condvar)>,
+}
+
+impl SyncEventListener {
+    /// Create a new SyncEventListener
+    pub fn new() -> Self {
+        SyncEventListener {
+            state: Arc::new(RwLock::new(SyncState {
+                events: vec![],
+                max_listeners: 10,
+            })),
+            next_id: Arc::new(AtomicU64::new(0)),
+            registered: Arc::new((Mutex::new(0), Condvar::new())),
+        }
+    }
+
+    /// Sets the maximum number of listeners of an event
+    /// ## Parameters
+    /// * `max_listeners` - The maximum number of listeners
+    pub fn set_max_listeners(&self, max_listeners: usize) {
+        self.state.write().unwrap().max_listeners = max_listeners;
+    }
+
+    /// Get max listeners for this SyncEventListener
+    /// ## Returns
+    /// [`usize`]
+    pub fn get_max_listeners(&self) -> usize {
+        self.state.read().unwrap().max_listeners
+    }
+
+    /// Add a new listener to the event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`] - Removes the listener with [`SyncEventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached, the emitter stays usable from other threads
+    pub fn on(&self, name: &str, callback: SyncListenerCallback) -> ListenerHandle {
+        self.add_listener(name, ListenerTypes::On, callback)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Add a listener that will be called only once, even by concurrent emissions
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`] - Removes the listener with [`SyncEventListener::off`]
+    /// ## Panics
+    /// If the max listener count is reached, the emitter stays usable from other threads
+    pub fn once(&self, name: &str, callback: SyncListenerCallback) -> ListenerHandle {
+        self.add_listener(name, ListenerTypes::Once, callback)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Add a new listener to the event, returning an error instead of panicking when the
+    /// max listener count is reached
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::sync::SyncEventListener;
+    /// let emitter = SyncEventListener::new();
+    /// emitter.set_max_listeners(1);
+    /// assert!(emitter.try_on("test", Box::new(|_, _| {})).is_ok());
+    /// let error = emitter.try_on("test", Box::new(|_, _| {})).unwrap_err();
+    /// assert_eq!(error.max, 1);
+    /// assert_eq!(emitter.emit("test", "still usable"), 1);
+    /// ```
+    /// ## Returns
+    /// [`Result<ListenerHandle, MaxListenersExceeded>`]
+    pub fn try_on(
+        &self,
+        name: &str,
+        callback: SyncListenerCallback,
+    ) -> Result<ListenerHandle, MaxListenersExceeded> {
+        self.add_listener(name, ListenerTypes::On, callback)
+    }
+
+    /// Add a listener that will be called only once, returning an error instead of
+    /// panicking when the max listener count is reached
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`Result<ListenerHandle, MaxListenersExceeded>`]
+    pub fn try_once(
+        &self,
+        name: &str,
+        callback: SyncListenerCallback,
+    ) -> Result<ListenerHandle, MaxListenersExceeded> {
+        self.add_listener(name, ListenerTypes::Once, callback)
+    }
+
+    /// Register a listener, the limit is checked under the table lock but the error is
+    /// returned after releasing it, so a refused listener doesn't poison the table
+    fn add_listener(
+        &self,
+        name: &str,
+        rtype: ListenerTypes,
+        callback: SyncListenerCallback,
+    ) -> Result<ListenerHandle, MaxListenersExceeded> {
+        let mut state = self.state.write().unwrap();
+        let max_listeners = state.max_listeners;
+        let index = match state.events.iter().position(|x| x.0 == name) {
+            Some(index) => index,
+            None => {
+                state.events.push((name.to_string(), Arc::new(vec![])));
+                state.events.len() - 1
+            }
+        };
+        let listeners = &mut state.events[index].1;
+        if listeners.len() >= max_listeners {
+            return Err(MaxListenersExceeded {
+                event: name.to_string(),
+                max: max_listeners,
+            });
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        Arc::make_mut(listeners).push(SyncListener {
+            id,
+            rtype,
+            callback: Arc::new(callback),
+            fired: Arc::new(AtomicBool::new(false)),
+            active: Arc::new(AtomicBool::new(true)),
+        });
+        drop(state);
+        let (registered, condvar) = &*self.registered;
+        *registered.lock().unwrap() += 1;
+        condvar.notify_all();
+        Ok(ListenerHandle(id))
+    }
+
+    /// Remove a single listener
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener was already removed
+    pub fn off(&self, handle: ListenerHandle) -> bool {
+        let mut state = self.state.write().unwrap();
+        for (_, listeners) in &mut state.events {
+            if let Some(position) = listeners.iter().position(|x| x.id == handle.0) {
+                let listener = Arc::make_mut(listeners).remove(position);
+                listener.active.store(false, Ordering::Release);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Remove all listeners of event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if the event exists
+    pub fn remove_all_listeners(&self, name: &str) -> bool {
+        let mut state = self.state.write().unwrap();
+        match state.events.iter_mut().find(|x| x.0 == name) {
+            Some((_, listeners)) => {
+                for listener in listeners.iter() {
+                    listener.active.store(false, Ordering::Release);
+                }
+                *listeners = Arc::new(vec![]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the number of listeners of an event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`usize`]
+    pub fn listener_count(&self, name: &str) -> usize {
+        let state = self.state.read().unwrap();
+        state
+            .events
+            .iter()
+            .find(|x| x.0 == name)
+            .map(|x| x.1.len())
+            .unwrap_or(0)
+    }
+
+    /// Block until an event has at least `count` listeners or the timeout elapses
+    ///
+    /// Wakes up as soon as another thread registers the missing listeners, so producers
+    /// can wait for their consumers before emitting.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `count` - The number of listeners to wait for
+    /// * `timeout` - Max time to wait
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::sync::SyncEventListener;
+    /// use std::time::Duration;
+    /// let emitter = SyncEventListener::new();
+    /// let consumer = emitter.clone();
+    /// std::thread::spawn(move || {
+    ///     consumer.on("job", Box::new(|_, job| println!("running {}", job)));
+    /// });
+    /// assert!(emitter.wait_for_subscribers("job", 1, Duration::from_secs(5)));
+    /// emitter.emit("job", 1); // running 1
+    /// ```
+    /// ## Returns
+    /// [`bool`] - `false` if the timeout elapsed first
+    pub fn wait_for_subscribers(&self, name: &str, count: usize, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let (registered, condvar) = &*self.registered;
+        let mut generation = registered.lock().unwrap();
+        loop {
+            if self.listener_count(name) >= count {
+                return true;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            generation = condvar.wait_timeout(generation, deadline - now).unwrap().0;
+        }
+    }
+
+    /// Emit an event
+    ///
+    /// Listeners registered while the emission is in progress receive the next one,
+    /// listeners removed while it is in progress aren't called if not reached yet.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Returns
+    /// [`usize`] - The number of called listeners
+    /// ## Panics
+    /// If the event doesn't exist
+    pub fn emit<P: IntoPayload>(&self, name: &str, data: P) -> usize {
+        let data = data.into_payload();
+        let snapshot = self.snapshot(name);
+        let mut called = 0;
+        let mut fired_once = vec![];
+        for listener in snapshot.iter() {
+            if !listener.claim(&mut fired_once) {
+                continue;
+            }
+            (listener.callback)(name.to_string(), data.clone());
+            called += 1;
+        }
+        drop(snapshot);
+        self.remove_fired(name, fired_once);
+        called
+    }
+
+    /// Emit an event, calling its listeners concurrently on scoped threads
+    ///
+    /// Listeners are split across [`std::thread::available_parallelism`] threads and
+    /// the call returns once all of them finished, so the order between listeners isn't
+    /// kept. Listeners removed during the emission are still called.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners, see [`IntoPayload`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::sync::SyncEventListener;
+    /// let emitter = SyncEventListener::new();
+    /// emitter.set_max_listeners(16);
+    /// for shard in 0..16u64 {
+    ///     emitter.on("frame", Box::new(move |_, frame| {
+    ///      let checksum = (0..100_000u64).fold(shard, |acc, x| acc.wrapping_mul(31) ^ x);
+    ///      println!("shard {} of frame {}: {:x}", shard, frame, checksum);
+    ///     }));
+    /// }
+    /// assert_eq!(emitter.emit_parallel("frame", 1), 16);
+    /// ```
+    /// ## Returns
+    /// [`usize`] - The number of called listeners
+    /// ## Panics
+    /// If the event doesn't exist, or if a listener panics
+    pub fn emit_parallel<P: IntoPayload>(&self, name: &str, data: P) -> usize {
+        let data = data.into_payload();
+        let snapshot = self.snapshot(name);
+        let mut fired_once = vec![];
+        let claimed = snapshot
+            .iter()
+            .filter(|x| x.claim(&mut fired_once))
+            .collect::<Vec<_>>();
+        let threads = std::thread::available_parallelism().map_or(1, |x| x.get());
+        let chunk = claimed.len().div_ceil(threads).max(1);
+        std::thread::scope(|scope| {
+            for listeners in claimed.chunks(chunk) {
+                let data = &data;
+                scope.spawn(move || {
+                    for listener in listeners {
+                        (listener.callback)(name.to_string(), data.clone());
+                    }
+                });
+            }
+        });
+        let called = claimed.len();
+        drop(claimed);
+        drop(snapshot);
+        self.remove_fired(name, fired_once);
+        called
+    }
+
+    /// Get the listener snapshot of an event
+    fn snapshot(&self, name: &str) -> SyncListeners {
+        let state = self.state.read().unwrap();
+        state
+            .events
+            .iter()
+            .find(|x| x.0 == name)
+            .expect("Event doesn't exist")
+            .1
+            .clone()
+    }
+
+    /// Remove once listeners fired by an emission
+    fn remove_fired(&self, name: &str, fired_once: Vec<u64>) {
+        if !fired_once.is_empty() {
+            let mut state = self.state.write().unwrap();
+            if let Some((_, listeners)) = state.events.iter_mut().find(|x| x.0 == name) {
+                if listeners.iter().any(|x| fired_once.contains(&x.id)) {
+                    Arc::make_mut(listeners).retain(|x| !fired_once.contains(&x.id));
+                }
+            }
+        }
+    }
+}
+
+/// Get the process-wide emitter, created on first use
+///
+/// Every call returns the same [`SyncEventListener`], so small applications can share one
+/// event bus without passing an emitter around.
+/// ## Example
+/// ```
+/// use rust_event_listener::sync::global;
+///
+/// fn start_server() {
+///     global().emit("server.started", 8080);
+/// }
+///
+/// global().on("server.started", Box::new(|_, port| println!("listening on {}", port)));
+/// start_server(); // listening on 8080
+/// ```
+/// ## Returns
+/// [`&'static SyncEventListener`]
+#[cfg(feature = "global")]
+pub fn global() -> &'static SyncEventListener {
+    static GLOBAL: std::sync::OnceLock<SyncEventListener> = std::sync::OnceLock::new();
+    GLOBAL.get_or_init(SyncEventListener::new)
+}
+
+impl Default for SyncEventListener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for SyncEventListener {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let state = self.state.read().unwrap();
+        f.debug_struct("SyncEventListener")
+            .field(
+                "events",
+                &state
+                    .events
+                    .iter()
+                    .map(|x| (&x.0, x.1.len()))
+                    .collect::<Vec<_>>(),
+            )
+            .field("max_listeners", &state.max_listeners)
+            .finish()
+    }
+}
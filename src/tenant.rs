@@ -0,0 +1,238 @@
+//! Multi-tenant partitions
+//!
+//! A [`Tenant`] handle partitions events by tenant id: events of tenant `acme` are
+//! stored as `@acme/<event>`, so listeners registered through a handle only receive
+//! emissions of the same tenant. Subscribing to another tenant's events requires a
+//! [`CrossTenant`] capability, which only the owner of the emitter can create.
+
+use crate::listener::{ListenerCallback, ListenerHandle, ListenerTypes};
+use crate::EventListener;
+
+/// Capability allowing a tenant handle to subscribe to other tenants' events
+#[derive(Debug)]
+pub struct CrossTenant {
+    _private: (),
+}
+
+/// Get the partitioned event name of a tenant's event
+/// ## Parameters
+/// * `tenant` - The tenant id
+/// * `name` - The name of the event
+/// ## Returns
+/// [`String`]
+pub fn tenant_event_name(tenant: &str, name: &str) -> String {
+    format!("@{}/{}", tenant, name)
+}
+
+/// Check whether a tenant id can be used as a partition
+///
+/// Ids must be non-empty and can't contain `/` or `@`, otherwise tenant `a/b` and event
+/// `c` would share a partitioned name with tenant `a` and event `b/c`.
+/// ## Parameters
+/// * `id` - The tenant id
+/// ## Returns
+/// [`bool`]
+pub fn is_valid_tenant_id(id: &str) -> bool {
+    !id.is_empty() && !id.contains(['/', '@'])
+}
+
+/// Panic if a tenant id can't be used as a partition
+fn check_tenant_id(id: &str) {
+    if !is_valid_tenant_id(id) {
+        panic!("Invalid tenant id: {:?}", id);
+    }
+}
+
+/// Split a partitioned event name into its tenant id and event name
+/// ## Parameters
+/// * `name` - The partitioned event name
+/// ## Returns
+/// [`Option<(&str, &str)>`] - `None` if the event doesn't belong to a tenant
+pub fn split_tenant(name: &str) -> Option<(&str, &str)> {
+    name.strip_prefix('@')?.split_once('/')
+}
+
+/// Handle emitting and subscribing within a tenant's partition
+pub struct Tenant<'a> {
+    emitter: &'a mut EventListener,
+    id: String,
+}
+
+impl<'a> Tenant<'a> {
+    /// Get the tenant id
+    /// ## Returns
+    /// [`&str`]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Add a new listener to an event of the tenant
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        let name = tenant_event_name(&self.id, name);
+        self.emitter.on(&name, callback)
+    }
+
+    /// Add a listener that will be called only once
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        let name = tenant_event_name(&self.id, name);
+        self.emitter.once(&name, callback)
+    }
+
+    /// Add a listener to an event of another tenant
+    /// ## Parameters
+    /// * `_capability` - The cross-tenant capability, see [`EventListener::cross_tenant`]
+    /// * `tenant` - The other tenant id
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached, or if the tenant id is invalid, see
+    /// [`is_valid_tenant_id`]
+    pub fn on_tenant(
+        &mut self,
+        _capability: &CrossTenant,
+        tenant: &str,
+        name: &str,
+        callback: ListenerCallback,
+    ) -> ListenerHandle {
+        check_tenant_id(tenant);
+        let name = tenant_event_name(tenant, name);
+        self.emitter.add_listener(
+            &name,
+            crate::listener::Listener::new(ListenerTypes::On, callback),
+        )
+    }
+
+    /// Emit an event of the tenant
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`]
+    /// ## Panics
+    /// If the event doesn't exist
+    pub fn emit<P: crate::payload::IntoPayload>(
+        &mut self,
+        name: &str,
+        data: P,
+    ) -> crate::outcome::EmitOutcome {
+        let name = tenant_event_name(&self.id, name);
+        self.emitter.emit(&name, data)
+    }
+
+    /// Remove all listeners of an event of the tenant
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if the event was removed, `false` if it wasn't
+    pub fn remove_all_listeners(&mut self, name: &str) -> bool {
+        let name = tenant_event_name(&self.id, name);
+        self.emitter.remove_all_listeners(&name)
+    }
+
+    /// Remove a single listener of an event of the tenant
+    ///
+    /// Listeners added to other tenants' events with [`Tenant::on_tenant`] are removed
+    /// through the emitter, see [`EventListener::off`].
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered on an event of the tenant
+    pub fn off(&mut self, handle: ListenerHandle) -> bool {
+        let owned = self
+            .emitter
+            .listener_event(handle)
+            .and_then(split_tenant)
+            .is_some_and(|x| x.0 == self.id);
+        owned && self.emitter.off(handle)
+    }
+}
+
+impl<'a> crate::emitter::Emitter for Tenant<'a> {
+    type Callback = ListenerCallback;
+
+    fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        Tenant::on(self, name, callback)
+    }
+
+    fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        Tenant::once(self, name, callback)
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> crate::outcome::EmitOutcome {
+        Tenant::emit(self, name, data)
+    }
+
+    fn try_emit(
+        &mut self,
+        name: &str,
+        data: String,
+    ) -> Result<crate::outcome::EmitOutcome, crate::outcome::EmitError> {
+        self.emitter
+            .check_emit(&tenant_event_name(&self.id, name), &data)?;
+        Ok(Tenant::emit(self, name, data))
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        Tenant::remove_all_listeners(self, name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        Tenant::off(self, handle)
+    }
+}
+
+impl EventListener {
+    /// Get a handle partitioned to a tenant
+    /// ## Parameters
+    /// * `id` - The tenant id
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// emitter.tenant("acme").on("order", Box::new(|name, data| {
+    ///  assert_eq!(name, "@acme/order");
+    ///  println!("acme order {}", data);
+    /// }));
+    /// emitter.tenant("globex").on("order", Box::new(|_, _| unreachable!()));
+    /// emitter.tenant("acme").emit("order", 1);
+    ///
+    /// let audit = emitter.cross_tenant();
+    /// emitter.tenant("ops").on_tenant(&audit, "globex", "order", Box::new(|_, data| {
+    ///  println!("audit globex order {}", data);
+    /// }));
+    /// ```
+    /// ## Returns
+    /// [`Tenant`]
+    /// ## Panics
+    /// If the tenant id is invalid, see [`is_valid_tenant_id`]
+    pub fn tenant(&mut self, id: &str) -> Tenant<'_> {
+        check_tenant_id(id);
+        Tenant {
+            emitter: self,
+            id: id.to_string(),
+        }
+    }
+
+    /// Create a capability allowing tenant handles to subscribe across tenants
+    /// ## Returns
+    /// [`CrossTenant`]
+    pub fn cross_tenant(&self) -> CrossTenant {
+        CrossTenant { _private: () }
+    }
+}
@@ -0,0 +1,185 @@
+//! Portable JSON trace format
+//!
+//! A trace is a single JSON object:
+//!
+//! ```json
+//! {
+//!   "format": "rust_event_listener.trace",
+//!   "version": 1,
+//!   "metadata": { "host": "worker-1" },
+//!   "events": [
+//!     { "seq": 0, "name": "user.created", "offset_us": 0, "payload": "42" },
+//!     { "seq": 1, "name": "user.deleted", "offset_us": 1500, "payload": "42" }
+//!   ]
+//! }
+//! ```
+//!
+//! * `format` - Always `rust_event_listener.trace`
+//! * `version` - Format version, currently `1`
+//! * `metadata` - Free-form string key/value pairs, [`Journal::metadata`]
+//! * `events` - Recorded emissions in order, `offset_us` is the time elapsed since
+//!   the journal started in microseconds, and the optional `location` is the
+//!   `file:line:column` of the emit call, see [`JournalEntry::location`]
+
+use crate::journal::{Journal, JournalEntry};
+use crate::json::{self, Value};
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::time::Duration;
+
+/// Value of the `format` field
+pub const TRACE_FORMAT: &str = "rust_event_listener.trace";
+
+/// Current trace format version
+pub const TRACE_VERSION: u64 = 1;
+
+/// Serialize a single entry as a JSON object
+pub(crate) fn entry_to_json(entry: &JournalEntry) -> String {
+    let mut out = String::new();
+    out.push_str("{\"seq\":");
+    out.push_str(&entry.seq.to_string());
+    out.push_str(",\"name\":");
+    json::write_string(&mut out, &entry.name);
+    out.push_str(",\"offset_us\":");
+    out.push_str(&entry.offset.as_micros().to_string());
+    out.push_str(",\"payload\":");
+    json::write_string(&mut out, &entry.payload);
+    if let Some(location) = &entry.location {
+        out.push_str(",\"location\":");
+        json::write_string(&mut out, location);
+    }
+    out.push('}');
+    out
+}
+
+/// Deserialize a single entry from a JSON object
+pub(crate) fn entry_from_json(value: &Value) -> Result<JournalEntry, String> {
+    Ok(JournalEntry {
+        seq: value
+            .get("seq")
+            .and_then(Value::as_u64)
+            .ok_or("Missing event seq")?,
+        name: value
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or("Missing event name")?
+            .to_string(),
+        payload: value
+            .get("payload")
+            .and_then(Value::as_str)
+            .ok_or("Missing event payload")?
+            .to_string(),
+        offset: Duration::from_micros(
+            value
+                .get("offset_us")
+                .and_then(Value::as_u64)
+                .ok_or("Missing event offset_us")?,
+        ),
+        location: value
+            .get("location")
+            .and_then(Value::as_str)
+            .map(|x| x.to_string()),
+    })
+}
+
+impl Journal {
+    /// Serialize the journal as a trace document
+    /// ## Returns
+    /// [`String`]
+    pub fn to_trace(&self) -> String {
+        let mut out = String::new();
+        out.push_str("{\n  \"format\": ");
+        json::write_string(&mut out, TRACE_FORMAT);
+        out.push_str(",\n  \"version\": ");
+        out.push_str(&TRACE_VERSION.to_string());
+        out.push_str(",\n  \"metadata\": {");
+        for (index, (key, value)) in self.metadata.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            out.push_str("\n    ");
+            json::write_string(&mut out, key);
+            out.push_str(": ");
+            json::write_string(&mut out, value);
+        }
+        if !self.metadata.is_empty() {
+            out.push_str("\n  ");
+        }
+        out.push_str("},\n  \"events\": [");
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            out.push_str("\n    ");
+            out.push_str(&entry_to_json(entry));
+        }
+        if !self.entries.is_empty() {
+            out.push_str("\n  ");
+        }
+        out.push_str("]\n}\n");
+        out
+    }
+
+    /// Parse a trace document
+    /// ## Parameters
+    /// * `trace` - The trace document
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::journal::Journal;
+    /// let mut journal = Journal::new();
+    /// journal.set_metadata("host", "worker-1");
+    /// journal.record("test", "1");
+    /// let imported = Journal::from_trace(&journal.to_trace()).unwrap();
+    /// assert_eq!(imported.entries()[0].name, "test");
+    /// assert_eq!(imported.entries()[0].payload, "1");
+    /// assert_eq!(imported.metadata(), journal.metadata());
+    /// ```
+    /// ## Returns
+    /// [`Result<Journal, String>`] - The parsed journal or a description of the problem
+    pub fn from_trace(trace: &str) -> Result<Journal, String> {
+        let document = json::parse(trace)?;
+        if document.get("format").and_then(Value::as_str) != Some(TRACE_FORMAT) {
+            return Err("Not a rust_event_listener trace".to_string());
+        }
+        let version = document.get("version").ok_or("Missing trace version")?;
+        match version.as_u64() {
+            Some(TRACE_VERSION) => {}
+            Some(version) => return Err(format!("Unsupported trace version {}", version)),
+            None => return Err("Trace version must be an unsigned integer".to_string()),
+        }
+        let mut journal = Journal::new();
+        if let Some(metadata) = document.get("metadata") {
+            for (key, value) in metadata.as_object().ok_or("Invalid trace metadata")? {
+                let value = value.as_str().ok_or("Invalid trace metadata value")?;
+                journal.set_metadata(key, value);
+            }
+        }
+        for entry in document
+            .get("events")
+            .and_then(Value::as_array)
+            .ok_or("Missing trace events")?
+        {
+            journal.entries.push(entry_from_json(entry)?);
+        }
+        Ok(journal)
+    }
+
+    /// Write the journal to a trace file
+    /// ## Parameters
+    /// * `path` - The trace file path
+    /// ## Returns
+    /// [`std::io::Result<()>`]
+    pub fn export_trace<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_trace())
+    }
+
+    /// Read a journal from a trace file
+    /// ## Parameters
+    /// * `path` - The trace file path
+    /// ## Returns
+    /// [`std::io::Result<Journal>`] - Fails with [`ErrorKind::InvalidData`] for malformed traces
+    pub fn import_trace<P: AsRef<Path>>(path: P) -> std::io::Result<Journal> {
+        let trace = std::fs::read_to_string(path)?;
+        Journal::from_trace(&trace).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::EventListener;
+use std::time::{Duration, Instant};
+
+impl EventListener {
+    /// Set how long emissions of an event stay valid while waiting
+    ///
+    /// Queued emissions, see [`EventListener::emit_queued`], and buffered emissions, see
+    /// [`EventListener::buffer_until_listener`], are dropped once expired instead of being
+    /// delivered, and counted by [`EventListener::expired_count`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `ttl` - The time to live, `None` keeps emissions until delivered
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::time::Duration;
+    /// let mut emitter = EventListener::new();
+    /// emitter.on("sensor", Box::new(|_, reading| println!("reading {}", reading)));
+    /// emitter.set_event_ttl("sensor", Some(Duration::from_millis(1)));
+    /// emitter.emit_queued("sensor", "21.5".to_string());
+    /// std::thread::sleep(Duration::from_millis(2));
+    /// assert_eq!(emitter.process_pending(0), 0);
+    /// assert_eq!(emitter.expired_count(), 1);
+    /// ```
+    pub fn set_event_ttl(&mut self, name: &str, ttl: Option<Duration>) {
+        self.event_ttls.retain(|x| x.0 != name);
+        if let Some(ttl) = ttl {
+            self.event_ttls.push((name.to_string(), ttl));
+        }
+    }
+
+    /// Get the time to live of an event's emissions
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`Option<Duration>`]
+    pub fn get_event_ttl(&self, name: &str) -> Option<Duration> {
+        self.event_ttls.iter().find(|x| x.0 == name).map(|x| x.1)
+    }
+
+    /// Queue an emission expiring after `ttl`, regardless of the event's time to live
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// * `ttl` - The time to live
+    /// ## Returns
+    /// [`crate::outcome::EmitOutcome`] - Queued, or dropped if the queue is full
+    pub fn emit_queued_with_ttl(
+        &mut self,
+        name: &str,
+        data: String,
+        ttl: Duration,
+    ) -> crate::outcome::EmitOutcome {
+        let expires_at = self.clock.now() + ttl;
+        self.enqueue(name, data, 0, Some(expires_at))
+    }
+
+    /// Get the number of emissions dropped because they expired
+    /// ## Returns
+    /// [`u64`]
+    pub fn expired_count(&self) -> u64 {
+        self.expired
+    }
+
+    /// Get the expiry of an emission of an event made now
+    pub(crate) fn expiry_for(&self, name: &str) -> Option<Instant> {
+        self.get_event_ttl(name).map(|x| self.clock.now() + x)
+    }
+
+    /// Check whether an emission expired, counting it if so
+    pub(crate) fn check_expired(&mut self, expires_at: Option<Instant>) -> bool {
+        let now = self.clock.now();
+        let expired = expires_at.is_some_and(|x| x <= now);
+        if expired {
+            self.expired += 1;
+        }
+        expired
+    }
+}
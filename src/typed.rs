@@ -0,0 +1,160 @@
+use crate::listener::ListenerHandle;
+use crate::outcome::EmitOutcome;
+use crate::EventListener;
+use std::any::{type_name, TypeId};
+
+/// Get the event name carrying values of type `T`
+///
+/// The name is derived from the [`TypeId`] of `T`, so distinct types never share an event.
+/// ## Returns
+/// [`String`]
+pub fn typed_event_name<T: 'static>() -> String {
+    format!("type.{:?}", TypeId::of::<T>())
+}
+
+impl EventListener {
+    /// Add a listener receiving every value of type `T` emitted with
+    /// [`EventListener::emit_typed`]
+    /// ## Parameters
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    ///
+    /// struct UserCreated {
+    ///     id: u32,
+    /// }
+    ///
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_typed(|event: &UserCreated| println!("user {}", event.id));
+    /// emitter.emit_typed(UserCreated { id: 7 }); // user 7
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_typed<T, F>(&mut self, callback: F) -> ListenerHandle
+    where
+        T: 'static,
+        F: Fn(&T) + 'static,
+    {
+        self.on_value(&typed_event_name::<T>(), move |_, value: &T| {
+            callback(value)
+        })
+    }
+
+    /// Emit a value to the listeners of its type
+    ///
+    /// String listeners of [`typed_event_name`] receive the type name.
+    /// ## Parameters
+    /// * `value` - The value
+    /// ## Returns
+    /// [`EmitOutcome`] - [`EmitOutcome::NoListeners`] if no listener was ever added for `T`
+    #[track_caller]
+    pub fn emit_typed<T: 'static>(&mut self, value: T) -> EmitOutcome {
+        let name = typed_event_name::<T>();
+        if self.event(&name).is_none() {
+            return EmitOutcome::NoListeners;
+        }
+        self.emit_value_as(&name, value, type_name::<T>().to_string())
+    }
+}
+
+/// Declare an emitter with strongly typed methods for each of its events
+///
+/// Each event lists its name, then the names of its listen and emit methods, then its
+/// optional payload type. Payload types must implement [`std::fmt::Debug`], string
+/// listeners added through [`EventListener`] receive the debug representation.
+/// ## Example
+/// ```
+/// use rust_event_listener::events;
+///
+/// #[derive(Debug)]
+/// pub struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// events! {
+///     /// Events of a window
+///     pub struct WindowEvents {
+///         click => on_click, emit_click(Point),
+///         close => on_close, emit_close,
+///     }
+/// }
+///
+/// let mut window = WindowEvents::new();
+/// window.on_click(|point| println!("click at {}, {}", point.x, point.y));
+/// window.on_close(|| println!("closed"));
+/// window.emit_click(Point { x: 3, y: 4 }); // click at 3, 4
+/// assert!(window.emit_close().is_delivered()); // closed
+/// ```
+#[macro_export]
+macro_rules! events {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($event:ident => $on:ident, $emit:ident $(($payload:ty))?),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            emitter: $crate::EventListener,
+        }
+
+        impl $name {
+            /// Create a new emitter
+            pub fn new() -> Self {
+                $name {
+                    emitter: $crate::EventListener::new(),
+                }
+            }
+
+            /// Get the underlying emitter, for untyped listeners and emitter options
+            pub fn emitter(&mut self) -> &mut $crate::EventListener {
+                &mut self.emitter
+            }
+
+            $($crate::events!(@methods $event, $on, $emit $(, $payload)?);)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+    (@methods $event:ident, $on:ident, $emit:ident, $payload:ty) => {
+        #[doc = concat!("Add a listener to `", stringify!($event), "`")]
+        pub fn $on<F: Fn(&$payload) + 'static>(
+            &mut self,
+            callback: F,
+        ) -> $crate::listener::ListenerHandle {
+            self.emitter
+                .on_value(stringify!($event), move |_, value: &$payload| callback(value))
+        }
+
+        #[doc = concat!("Emit `", stringify!($event), "`")]
+        pub fn $emit(&mut self, value: $payload) -> $crate::outcome::EmitOutcome {
+            if self.emitter.get_event(stringify!($event)).is_none() {
+                return $crate::outcome::EmitOutcome::NoListeners;
+            }
+            self.emitter.emit_value(stringify!($event), value)
+        }
+    };
+    (@methods $event:ident, $on:ident, $emit:ident) => {
+        #[doc = concat!("Add a listener to `", stringify!($event), "`")]
+        pub fn $on<F: Fn() + 'static>(&mut self, callback: F) -> $crate::listener::ListenerHandle {
+            self.emitter
+                .on(stringify!($event), Box::new(move |_, _| callback()))
+        }
+
+        #[doc = concat!("Emit `", stringify!($event), "`")]
+        pub fn $emit(&mut self) -> $crate::outcome::EmitOutcome {
+            if self.emitter.get_event(stringify!($event)).is_none() {
+                return $crate::outcome::EmitOutcome::NoListeners;
+            }
+            self.emitter.emit(stringify!($event), "")
+        }
+    };
+}
@@ -0,0 +1,198 @@
+//! Listener failure policy
+//!
+//! Panicking listeners are caught during emission. When the emitter has listeners for
+//! `listenerError` they receive a [`ListenerError`] payload, otherwise the configured
+//! [`UncaughtPolicy`] decides what happens, mirroring Node's `uncaughtException`.
+
+use crate::json;
+use crate::listener::{Listener, ListenerCallback, ListenerHandle};
+use crate::EventListener;
+use std::any::Any;
+
+/// Event emitted when a listener panics
+pub const LISTENER_ERROR: &str = "listenerError";
+
+/// Event that must have a listener when emitted, see [`EventListener::emit`]
+pub const ERROR_EVENT: &str = "error";
+
+/// Failure of a listener
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListenerError {
+    /// The event being emitted
+    pub event: String,
+    /// The failed listener
+    pub listener: ListenerHandle,
+    /// The panic message
+    pub message: String,
+}
+
+impl ListenerError {
+    /// Describe a caught panic
+    pub(crate) fn from_panic(event: &str, listener: ListenerHandle, panic: &dyn Any) -> Self {
+        let message = match panic.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => panic
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_else(|| "Box<dyn Any>".to_string()),
+        };
+        ListenerError {
+            event: event.to_string(),
+            listener,
+            message,
+        }
+    }
+
+    /// Serialize the error as the JSON payload of `listenerError`
+    ///
+    /// `{"event":"name","listener":1,"message":"panic message"}`
+    /// ## Returns
+    /// [`String`]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"event\":");
+        json::write_string(&mut out, &self.event);
+        out.push_str(&format!(",\"listener\":{},\"message\":", self.listener.0));
+        json::write_string(&mut out, &self.message);
+        out.push('}');
+        out
+    }
+
+    /// Parse the JSON payload of `listenerError`
+    /// ## Parameters
+    /// * `payload` - The payload
+    /// ## Returns
+    /// [`Option<ListenerError>`] - `None` if the payload is malformed
+    pub fn from_json(payload: &str) -> Option<Self> {
+        let value = json::parse(payload).ok()?;
+        Some(ListenerError {
+            event: value.get("event")?.as_str()?.to_string(),
+            listener: ListenerHandle(value.get("listener")?.as_u64()?),
+            message: value.get("message")?.as_str()?.to_string(),
+        })
+    }
+}
+
+/// Result of a listener call, with the caught panic if it failed
+pub(crate) type CallResult = Result<(), (ListenerError, Box<dyn Any + Send>)>;
+
+/// What happens when a listener panics and nothing listens to `listenerError`
+#[derive(Default)]
+pub enum UncaughtPolicy {
+    /// Resume unwinding out of `emit` once the emission is cleaned up, skipping the
+    /// remaining listeners
+    #[default]
+    Resume,
+    /// Print the error to stderr and call the remaining listeners
+    LogAndContinue,
+    /// Abort the process
+    Abort,
+    /// Call a hook and the remaining listeners
+    Hook(Box<dyn Fn(&ListenerError)>),
+}
+
+impl std::fmt::Debug for UncaughtPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UncaughtPolicy::Resume => write!(f, "Resume"),
+            UncaughtPolicy::LogAndContinue => write!(f, "LogAndContinue"),
+            UncaughtPolicy::Abort => write!(f, "Abort"),
+            UncaughtPolicy::Hook(_) => write!(f, "Hook(f')"),
+        }
+    }
+}
+
+impl UncaughtPolicy {
+    /// Apply the policy to a caught panic
+    /// ## Returns
+    /// [`Option`] - The panic to resume once the caller restored its state
+    pub(crate) fn apply(
+        &self,
+        error: &ListenerError,
+        panic: Box<dyn Any + Send>,
+    ) -> Option<Box<dyn Any + Send>> {
+        match self {
+            UncaughtPolicy::Resume => return Some(panic),
+            UncaughtPolicy::LogAndContinue => eprintln!(
+                "listener {:?} of {} panicked: {}",
+                error.listener, error.event, error.message
+            ),
+            UncaughtPolicy::Abort => std::process::abort(),
+            UncaughtPolicy::Hook(hook) => hook(error),
+        }
+        None
+    }
+}
+
+/// Call a listener, catching its panic
+/// ## Returns
+/// [`Result`] - The error and the panic payload if the listener panicked
+pub(crate) fn call_listener(listener: &Listener, name: &str, data: &str) -> CallResult {
+    call_callback(&listener.callback, listener.handle(), name, data)
+}
+
+/// Call a callback stored outside of a [`Listener`], catching its panic like [`call_listener`]
+pub(crate) fn call_callback(
+    callback: &ListenerCallback,
+    handle: ListenerHandle,
+    name: &str,
+    data: &str,
+) -> CallResult {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        callback(name.to_string(), data.to_string())
+    }))
+    .map_err(|panic| (ListenerError::from_panic(name, handle, &*panic), panic))
+}
+
+impl EventListener {
+    /// Check whether panics of listeners of an event are reported with `listenerError`
+    pub(crate) fn reports_listener_errors(&self, name: &str) -> bool {
+        name != LISTENER_ERROR
+            && self
+                .event(LISTENER_ERROR)
+                .is_some_and(|x| !x.data.is_empty())
+    }
+
+    /// Report a caught panic with `listenerError` or the uncaught policy
+    pub(crate) fn report_listener_error(
+        &mut self,
+        error: ListenerError,
+        panic: Box<dyn Any + Send>,
+    ) {
+        if self.reports_listener_errors(&error.event) {
+            self.emit(LISTENER_ERROR, error.to_json());
+        } else if let Some(panic) = self.uncaught_policy.apply(&error, panic) {
+            std::panic::resume_unwind(panic);
+        }
+    }
+
+    /// Set the policy applied when a listener panics and nothing listens to `listenerError`
+    /// ## Parameters
+    /// * `policy` - The policy, defaults to [`UncaughtPolicy::Resume`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::uncaught::UncaughtPolicy;
+    /// use rust_event_listener::EventListener;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// let failures = Rc::new(RefCell::new(vec![]));
+    /// let mut emitter = EventListener::new();
+    /// let seen = failures.clone();
+    /// emitter.set_uncaught_policy(UncaughtPolicy::Hook(Box::new(move |error| {
+    ///  seen.borrow_mut().push(error.message.clone());
+    /// })));
+    /// emitter.on("tick", Box::new(|_, _| panic!("boom")));
+    /// emitter.on("tick", Box::new(|_, data| println!("still called: {}", data)));
+    /// emitter.emit("tick", 1);
+    /// assert_eq!(*failures.borrow(), vec!["boom".to_string()]);
+    /// ```
+    pub fn set_uncaught_policy(&mut self, policy: UncaughtPolicy) {
+        self.uncaught_policy = policy;
+    }
+
+    /// Get the policy applied when a listener panics and nothing listens to `listenerError`
+    /// ## Returns
+    /// [`&UncaughtPolicy`]
+    pub fn get_uncaught_policy(&self) -> &UncaughtPolicy {
+        &self.uncaught_policy
+    }
+}
@@ -0,0 +1,149 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes, SharedCallback};
+use crate::EventListener;
+use std::any::TypeId;
+
+/// Error returned when a callback is already registered on an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateListener {
+    /// The name of the event
+    pub event: String,
+    /// The handle of the registered listener
+    pub existing: ListenerHandle,
+}
+
+impl std::fmt::Display for DuplicateListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Duplicate listener: {} already has this callback as listener {}",
+            self.event, self.existing.0
+        )
+    }
+}
+
+impl std::error::Error for DuplicateListener {}
+
+impl EventListener {
+    /// Add a new listener to the event, refusing callbacks already registered on it
+    ///
+    /// Callbacks are the same when they have the same type: the same function, or
+    /// closures created by the same expression, whatever they capture. Only listeners
+    /// added with this method are compared.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    ///
+    /// fn index_document(_: String, path: String) {
+    ///     println!("indexing {}", path);
+    /// }
+    ///
+    /// let mut emitter = EventListener::new();
+    /// let handle = emitter.on_unique("saved", index_document).unwrap();
+    /// let duplicate = emitter.on_unique("saved", index_document).unwrap_err();
+    /// assert_eq!(duplicate.existing, handle);
+    /// emitter.emit("saved", "notes.md"); // indexing notes.md
+    /// ```
+    /// ## Returns
+    /// [`Result<ListenerHandle, DuplicateListener>`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_unique<F>(
+        &mut self,
+        name: &str,
+        callback: F,
+    ) -> Result<ListenerHandle, DuplicateListener>
+    where
+        F: Fn(String, String) + 'static,
+    {
+        if let Some(existing) = self.find_callback(name, &callback) {
+            return Err(DuplicateListener {
+                event: name.to_string(),
+                existing,
+            });
+        }
+        let mut listener = Listener::new(ListenerTypes::On, Box::new(callback));
+        listener.identity = Some(TypeId::of::<F>());
+        Ok(self.add_listener(name, listener))
+    }
+
+    /// Find a listener added with [`EventListener::on_unique`] using the same callback
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `_callback` - The callback to look for, only its type is compared
+    /// ## Returns
+    /// [`Option<ListenerHandle>`]
+    pub fn find_callback<F: 'static>(&self, name: &str, _callback: &F) -> Option<ListenerHandle> {
+        let identity = TypeId::of::<F>();
+        self.listeners(name)?
+            .iter()
+            .find(|x| x.identity == Some(identity))
+            .map(|x| x.handle())
+    }
+
+    /// Add a new listener to the event with a shared callback
+    ///
+    /// Keep a clone of the callback to remove the listener with
+    /// [`EventListener::off_callback`] instead of its handle.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_shared(&mut self, name: &str, callback: SharedCallback) -> ListenerHandle {
+        let address = shared_address(&callback);
+        let mut listener = Listener::new(
+            ListenerTypes::On,
+            Box::new(move |name, data| callback(name, data)),
+        );
+        listener.shared = Some(address);
+        self.add_listener(name, listener)
+    }
+
+    /// Remove one listener added with [`EventListener::on_shared`] using this callback
+    ///
+    /// Like Node's `off(name, fn)`, the most recently added registration is removed when
+    /// the callback was added several times. Callbacks are compared by pointer.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback passed to [`EventListener::on_shared`]
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::listener::SharedCallback;
+    /// use rust_event_listener::EventListener;
+    /// use std::sync::Arc;
+    /// let mut emitter = EventListener::new();
+    /// let log: SharedCallback = Arc::new(|_, data| println!("{}", data));
+    /// emitter.on_shared("message", log.clone());
+    /// emitter.on_shared("message", log.clone());
+    /// assert!(emitter.off_callback("message", &log));
+    /// emitter.emit("message", "hello"); // hello
+    /// assert!(emitter.off_callback("message", &log));
+    /// assert!(!emitter.off_callback("message", &log));
+    /// ```
+    /// ## Returns
+    /// [`bool`] - `false` if the callback isn't registered on the event
+    pub fn off_callback(&mut self, name: &str, callback: &SharedCallback) -> bool {
+        let address = shared_address(callback);
+        let handle = self.listeners(name).and_then(|listeners| {
+            listeners
+                .iter()
+                .rev()
+                .find(|x| x.shared == Some(address))
+                .map(|x| x.handle())
+        });
+        match handle {
+            Some(handle) => self.off(handle),
+            None => false,
+        }
+    }
+}
+
+/// Address of a shared callback, ignoring its vtable
+fn shared_address(callback: &SharedCallback) -> usize {
+    std::sync::Arc::as_ptr(callback) as *const () as usize
+}
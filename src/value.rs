@@ -0,0 +1,175 @@
+use crate::listener::{Listener, ListenerHandle, ListenerTypes};
+use crate::outcome::EmitOutcome;
+use crate::EventListener;
+use std::any::Any;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Value of the emission in progress, shared with value listeners
+pub(crate) type ValueSlot = Rc<std::cell::RefCell<Option<(String, Rc<dyn Any>)>>>;
+
+impl EventListener {
+    /// Add a listener receiving values emitted with [`EventListener::emit_value`]
+    ///
+    /// The listener is skipped for emissions carrying a value of another type, and for
+    /// emissions made with [`EventListener::emit`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    ///
+    /// #[derive(Debug)]
+    /// enum Message {
+    ///     Text(String),
+    ///     Volume(i8),
+    /// }
+    ///
+    /// let mut emitter = EventListener::new();
+    /// emitter.on_value("message", |_, message: &Message| {
+    ///  if let Message::Volume(level) = message {
+    ///   assert_eq!(*level, -3);
+    ///  }
+    /// });
+    /// emitter.on("message", Box::new(|_, debug| println!("{}", debug))); // Volume(-3)
+    /// emitter.emit_value("message", Message::Volume(-3));
+    /// emitter.emit_value("message", Message::Text("hi".to_string()));
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_value<T, F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        T: 'static,
+        F: Fn(String, &T) + 'static,
+    {
+        let slot = self.value_slot.clone();
+        self.add_listener(
+            name,
+            Listener::new(
+                ListenerTypes::On,
+                Box::new(move |name, _| {
+                    let value = match &*slot.borrow() {
+                        Some((event, value)) if *event == name => value.clone(),
+                        _ => return,
+                    };
+                    if let Some(value) = value.downcast_ref::<T>() {
+                        callback(name, value);
+                    }
+                }),
+            ),
+        )
+    }
+
+    /// Emit a value without serializing it
+    ///
+    /// Value listeners of the same type receive a reference to the value, string listeners
+    /// receive its [`Debug`] representation. The value is only available to listeners
+    /// called during this emission, queued dispatch delivers the string only.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `value` - The value
+    /// ## Returns
+    /// [`EmitOutcome`]
+    /// ## Panics
+    /// If the event doesn't exist
+    #[track_caller]
+    pub fn emit_value<T: Debug + 'static>(&mut self, name: &str, value: T) -> EmitOutcome {
+        let data = format!("{:?}", value);
+        self.emit_value_as(name, value, data)
+    }
+
+    /// Add a listener receiving payloads emitted with [`EventListener::emit_shared`]
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function, receiving a clone of the shared payload
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_shared_payload<T, F>(&mut self, name: &str, callback: F) -> ListenerHandle
+    where
+        T: 'static,
+        F: Fn(String, Arc<T>) + 'static,
+    {
+        self.on_value(name, move |name, value: &Arc<T>| {
+            callback(name, value.clone())
+        })
+    }
+
+    /// Emit a payload shared between listeners instead of copied for each of them
+    ///
+    /// Shared payload listeners of the same type receive a clone of the [`Arc`], so the
+    /// payload is never copied and can be kept or sent to another thread. String
+    /// listeners receive the payload type name, like with [`EventListener::emit_typed`].
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `payload` - The payload
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::sync::Arc;
+    /// let mut emitter = EventListener::new();
+    /// let frame = Arc::new(vec![0u8; 8 * 1024 * 1024]);
+    /// for encoder in ["h264", "vp9", "av1"] {
+    ///     let source = frame.clone();
+    ///     emitter.on_shared_payload("frame", move |_, data: Arc<Vec<u8>>| {
+    ///      assert!(Arc::ptr_eq(&data, &source));
+    ///      println!("{} encoding {} bytes", encoder, data.len());
+    ///     });
+    /// }
+    /// emitter.emit_shared("frame", frame);
+    /// ```
+    /// ## Returns
+    /// [`EmitOutcome`]
+    /// ## Panics
+    /// If the event doesn't exist
+    #[track_caller]
+    pub fn emit_shared<T: 'static>(&mut self, name: &str, payload: Arc<T>) -> EmitOutcome {
+        self.emit_value_as(name, payload, std::any::type_name::<T>().to_string())
+    }
+
+    /// Emit a value with the given string payload for string listeners
+    #[track_caller]
+    pub(crate) fn emit_value_as<T: 'static>(
+        &mut self,
+        name: &str,
+        value: T,
+        data: String,
+    ) -> EmitOutcome {
+        let resolved = self.resolve_event_name(name);
+        let previous = self
+            .value_slot
+            .borrow_mut()
+            .replace((resolved.into_owned(), Rc::new(value)));
+        let outcome =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.emit(name, data)));
+        *self.value_slot.borrow_mut() = previous;
+        outcome.unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
+
+/// Emit several values at once as a tuple payload
+///
+/// `emit!(emitter, name, a, b, c)` is [`EventListener::emit_value`] with the tuple
+/// `(a, b, c)`, listen with [`EventListener::on_value`] and the matching tuple type.
+/// String listeners receive the [`Debug`] representation of the tuple.
+/// ## Example
+/// ```
+/// use rust_event_listener::{emit, EventListener};
+/// let mut emitter = EventListener::new();
+/// emitter.on_value("pos_changed", |_, &(x, y, z): &(f32, f32, f32)| {
+///  println!("moved to {}, {}, {}", x, y, z);
+/// });
+/// emitter.on("pos_changed", Box::new(|_, data| assert_eq!(data, "(1.0, 2.5, -4.0)")));
+/// emit!(emitter, "pos_changed", 1.0f32, 2.5f32, -4.0f32); // moved to 1, 2.5, -4
+/// ```
+#[macro_export]
+macro_rules! emit {
+    ($emitter:expr, $name:expr $(, $value:expr)* $(,)?) => {
+        $emitter.emit_value($name, ($($value,)*))
+    };
+}
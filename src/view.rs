@@ -0,0 +1,195 @@
+use crate::listener::{ListenerCallback, ListenerHandle};
+use crate::outcome::{DropReason, EmitOutcome};
+use crate::EventListener;
+use std::cell::RefCell;
+
+/// Predicate deciding which event names are visible through a [`View`]
+pub type ViewFilter = Box<dyn Fn(&str) -> bool>;
+
+/// Restricted handle exposing only the events accepted by its filter
+///
+/// Subscribing to or emitting an event outside the view is refused, so a view
+/// can be handed to a subsystem as its entire world. Views share the emitter through a
+/// [`RefCell`], so each subsystem can hold its own.
+pub struct View<'a> {
+    emitter: &'a RefCell<EventListener>,
+    filter: ViewFilter,
+}
+
+impl<'a> View<'a> {
+    /// Get a restricted handle exposing only events matching the filter
+    /// ## Parameters
+    /// * `emitter` - The shared emitter
+    /// * `filter` - The event name predicate
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::view::View;
+    /// use rust_event_listener::EventListener;
+    /// use std::cell::RefCell;
+    /// let emitter = RefCell::new(EventListener::new());
+    /// let ui = View::new(&emitter, |name| name.starts_with("ui."));
+    /// let db = View::new(&emitter, |name| name.starts_with("db."));
+    /// assert!(ui.on("ui.click", Box::new(|name, data| {
+    ///  println!("{}: {}", name, data);
+    /// })).is_some());
+    /// assert!(ui.on("db.query", Box::new(|_, _| {})).is_none());
+    /// assert!(db.on("db.query", Box::new(|_, _| {})).is_some());
+    /// assert!(ui.emit("ui.click", "1".to_string()).is_delivered());
+    /// ```
+    /// ## Returns
+    /// [`View`]
+    pub fn new<F: Fn(&str) -> bool + 'static>(
+        emitter: &'a RefCell<EventListener>,
+        filter: F,
+    ) -> Self {
+        View {
+            emitter,
+            filter: Box::new(filter),
+        }
+    }
+
+    /// Check whether an event is visible through this view
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`]
+    pub fn contains(&self, name: &str) -> bool {
+        (self.filter)(name)
+    }
+
+    /// Narrow this view further, events must match both filters
+    /// ## Parameters
+    /// * `filter` - The additional event name predicate
+    /// ## Returns
+    /// [`View`]
+    pub fn view<F: Fn(&str) -> bool + 'static>(self, filter: F) -> View<'a> {
+        let parent = self.filter;
+        View {
+            emitter: self.emitter,
+            filter: Box::new(move |name| parent(name) && filter(name)),
+        }
+    }
+
+    /// Get names of existing events visible through this view
+    /// ## Returns
+    /// [`Vec<String>`]
+    pub fn get_event_names(&self) -> Vec<String> {
+        self.emitter
+            .borrow()
+            .get_event_names()
+            .into_iter()
+            .filter(|x| self.contains(x))
+            .collect()
+    }
+
+    /// Add a new listener to a visible event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`Option<ListenerHandle>`] - `None` if the event is outside the view
+    /// ## Panics
+    /// If the emitter is borrowed, e.g. by one of its listeners
+    pub fn on(&self, name: &str, callback: ListenerCallback) -> Option<ListenerHandle> {
+        if !self.contains(name) {
+            return None;
+        }
+        Some(self.emitter.borrow_mut().on(name, callback))
+    }
+
+    /// Add a listener that will be called only once to a visible event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `callback` - The callback function
+    /// ## Returns
+    /// [`Option<ListenerHandle>`] - `None` if the event is outside the view
+    /// ## Panics
+    /// If the emitter is borrowed, e.g. by one of its listeners
+    pub fn once(&self, name: &str, callback: ListenerCallback) -> Option<ListenerHandle> {
+        if !self.contains(name) {
+            return None;
+        }
+        Some(self.emitter.borrow_mut().once(name, callback))
+    }
+
+    /// Emit a visible event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`EmitOutcome`] - [`DropReason::OutsideView`] if the event is outside the view
+    /// ## Panics
+    /// If the event doesn't exist, or if the emitter is borrowed, e.g. by one of its
+    /// listeners
+    pub fn emit<P: crate::payload::IntoPayload>(&self, name: &str, data: P) -> EmitOutcome {
+        if !self.contains(name) {
+            return EmitOutcome::DroppedByPolicy(DropReason::OutsideView);
+        }
+        self.emitter.borrow_mut().emit(name, data)
+    }
+
+    /// Remove all listeners of a visible event
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// ## Returns
+    /// [`bool`] - `true` if the event was removed, `false` if it wasn't or is outside the view
+    /// ## Panics
+    /// If the emitter is borrowed, e.g. by one of its listeners
+    pub fn remove_all_listeners(&self, name: &str) -> bool {
+        self.contains(name) && self.emitter.borrow_mut().remove_all_listeners(name)
+    }
+
+    /// Remove a single listener of a visible event
+    /// ## Parameters
+    /// * `handle` - The handle returned when the listener was registered
+    /// ## Returns
+    /// [`bool`] - `false` if the listener isn't registered or its event is outside the view
+    /// ## Panics
+    /// If the emitter is borrowed, e.g. by one of its listeners
+    pub fn off(&self, handle: ListenerHandle) -> bool {
+        let mut emitter = self.emitter.borrow_mut();
+        let visible = emitter
+            .listener_event(handle)
+            .is_some_and(|x| self.contains(x));
+        visible && emitter.off(handle)
+    }
+}
+
+impl<'a> crate::emitter::Emitter for View<'a> {
+    type Callback = ListenerCallback;
+
+    /// ## Panics
+    /// If the event is outside the view
+    fn on(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        View::on(self, name, callback).expect("Event is outside the view")
+    }
+
+    /// ## Panics
+    /// If the event is outside the view
+    fn once(&mut self, name: &str, callback: ListenerCallback) -> ListenerHandle {
+        View::once(self, name, callback).expect("Event is outside the view")
+    }
+
+    fn emit(&mut self, name: &str, data: String) -> EmitOutcome {
+        View::emit(self, name, data)
+    }
+
+    fn try_emit(
+        &mut self,
+        name: &str,
+        data: String,
+    ) -> Result<EmitOutcome, crate::outcome::EmitError> {
+        if self.contains(name) {
+            self.emitter.borrow().check_emit(name, &data)?;
+        }
+        Ok(View::emit(self, name, data))
+    }
+
+    fn remove_all_listeners(&mut self, name: &str) -> bool {
+        View::remove_all_listeners(self, name)
+    }
+
+    fn off(&mut self, handle: ListenerHandle) -> bool {
+        View::off(self, handle)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::listener::ListenerHandle;
+use crate::payload::IntoPayload;
+use crate::EventListener;
+use std::sync::mpsc::Sender;
+use std::sync::Weak;
+
+/// Non-owning reference to an emitter
+///
+/// Emissions are queued on the emitter like ingested messages and dispatched by
+/// [`EventListener::process_ingested`]. Holding a `WeakEmitter` doesn't keep the
+/// emitter alive, so listeners can keep one without creating a reference cycle.
+#[derive(Debug, Clone)]
+pub struct WeakEmitter {
+    inbox: Sender<(String, String)>,
+}
+
+impl WeakEmitter {
+    /// Queue an emission on the emitter
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `data` - The data to pass to the listeners
+    /// ## Returns
+    /// [`bool`] - `false` if the emitter was dropped
+    pub fn emit<P: IntoPayload>(&self, name: &str, data: P) -> bool {
+        self.inbox
+            .send((name.to_string(), data.into_payload()))
+            .is_ok()
+    }
+}
+
+impl EventListener {
+    /// Create a non-owning reference to this emitter
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// let mut emitter = EventListener::new();
+    /// let weak = emitter.downgrade();
+    /// emitter.on("ping", Box::new(move |_, data| {
+    ///  weak.emit("pong", data);
+    /// }));
+    /// emitter.on("pong", Box::new(|_, data| println!("pong {}", data)));
+    /// emitter.emit("ping", 1);
+    /// assert_eq!(emitter.process_ingested(), 1); // pong 1
+    ///
+    /// let weak = emitter.downgrade();
+    /// drop(emitter);
+    /// assert!(!weak.emit("ping", 2));
+    /// ```
+    /// ## Returns
+    /// [`WeakEmitter`]
+    pub fn downgrade(&self) -> WeakEmitter {
+        WeakEmitter {
+            inbox: self.ingest_sender.clone(),
+        }
+    }
+
+    /// Add a listener tied to the lifetime of an owner
+    ///
+    /// The callback receives the owner while it is alive. Once the last [`std::sync::Arc`]
+    /// of the owner is dropped, the next emission skips the listener and removes it.
+    /// ## Parameters
+    /// * `name` - The name of the event
+    /// * `owner` - The owner
+    /// * `callback` - The callback function
+    /// ## Example
+    /// ```
+    /// use rust_event_listener::EventListener;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// struct Label {
+    ///     text: Mutex<String>,
+    /// }
+    ///
+    /// let mut emitter = EventListener::new();
+    /// let label = Arc::new(Label { text: Mutex::new(String::new()) });
+    /// emitter.on_weak("status", Arc::downgrade(&label), |label, _, status| {
+    ///  *label.text.lock().unwrap() = status;
+    /// });
+    /// emitter.emit("status", "online");
+    /// assert_eq!(*label.text.lock().unwrap(), "online");
+    /// drop(label);
+    /// emitter.emit("status", "offline");
+    /// assert_eq!(emitter.listener_count("status"), 0);
+    /// ```
+    /// ## Returns
+    /// [`ListenerHandle`]
+    /// ## Panics
+    /// If the max listener count is reached
+    pub fn on_weak<T, F>(&mut self, name: &str, owner: Weak<T>, callback: F) -> ListenerHandle
+    where
+        T: 'static,
+        F: Fn(&T, String, String) + 'static,
+    {
+        self.on_while(name, move |name, data| match owner.upgrade() {
+            Some(owner) => {
+                callback(&owner, name, data);
+                true
+            }
+            None => false,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "async")]
+
+use rust_event_listener::EventListener;
+use std::time::Duration;
+
+#[test]
+fn filtered_listeners_are_not_acked() {
+    let mut emitter = EventListener::new();
+    let called = emitter.on("save", Box::new(|_, _| {}));
+    emitter
+        .listen("save")
+        .filter(|data| data == "other")
+        .call(Box::new(|_, _| panic!("filtered listener called")));
+    let report = emitter.emit_and_wait_acks("save", "doc".to_string(), Duration::from_secs(1));
+    assert_eq!(report.acked, vec![called]);
+    assert!(report.missing.is_empty());
+}
+
+#[test]
+fn aliases_and_patterns_are_acked() {
+    let mut emitter = EventListener::new();
+    emitter.alias("quit", "exit");
+    let exact = emitter.on("exit", Box::new(|_, _| {}));
+    let pattern = emitter.on_pattern("ex*", Box::new(|_, _| {}));
+    let report = emitter.emit_and_wait_acks("quit", "now".to_string(), Duration::from_secs(1));
+    assert!(report.is_complete());
+    assert_eq!(report.acked.len(), 2);
+    assert!(report.acked.contains(&exact));
+    assert!(report.acked.contains(&pattern));
+}
+
+#[test]
+fn failed_listeners_are_missing_and_error_listeners_are_not_acked() {
+    let mut emitter = EventListener::new();
+    emitter.on("listenerError", Box::new(|_, _| {}));
+    let failed = emitter.on("save", Box::new(|_, _| panic!("disk full")));
+    let called = emitter.on("save", Box::new(|_, _| {}));
+    let report = emitter.emit_and_wait_acks("save", "doc".to_string(), Duration::from_secs(1));
+    assert_eq!(report.acked, vec![called]);
+    assert_eq!(report.missing, vec![failed]);
+}
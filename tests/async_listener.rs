@@ -0,0 +1,56 @@
+#![cfg(feature = "async")]
+
+use rust_event_listener::middleware::MiddlewareResult;
+use rust_event_listener::outcome::{DropReason, EmitOutcome};
+use rust_event_listener::EventListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn counting_emitter(name: &str) -> (EventListener, Arc<AtomicUsize>) {
+    let started = Arc::new(AtomicUsize::new(0));
+    let mut emitter = EventListener::new();
+    let counter = started.clone();
+    emitter.on_async(
+        name,
+        Box::new(move |_, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async {})
+        }),
+    );
+    (emitter, started)
+}
+
+#[test]
+fn cancelled_emission_does_not_start_async_listeners() {
+    let (mut emitter, started) = counting_emitter("save");
+    emitter.use_middleware(Box::new(|_| MiddlewareResult::Cancel));
+    let outcome = emitter.emit_blocking("save", "1");
+    assert_eq!(outcome, EmitOutcome::DroppedByPolicy(DropReason::Cancelled));
+    assert_eq!(started.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn paused_emission_starts_async_listeners_on_resume_only() {
+    let (mut emitter, started) = counting_emitter("save");
+    emitter.pause();
+    drop(emitter.emit_async("save", "1"));
+    assert_eq!(started.load(Ordering::SeqCst), 0);
+    assert_eq!(emitter.paused_count(), 1);
+}
+
+#[test]
+fn buffered_emission_does_not_start_async_listeners() {
+    let (mut emitter, started) = counting_emitter("save");
+    emitter.buffer_until_listener("save", 4);
+    assert_eq!(emitter.emit_blocking("save", "1"), EmitOutcome::Buffered);
+    assert_eq!(started.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn emit_async_future_is_send() {
+    fn assert_send<T: Send>(_: &T) {}
+    let (mut emitter, started) = counting_emitter("save");
+    let future = emitter.emit_async("save", "1");
+    assert_send(&future);
+    assert_eq!(started.load(Ordering::SeqCst), 1);
+}
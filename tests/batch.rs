@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::uncaught::ListenerError;
+use rust_event_listener::EventListener;
+use std::time::Duration;
+
+#[test]
+fn panicking_batch_callback_is_reported_on_flush() {
+    let errors = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("listenerError", errors.payload());
+    let handle = emitter.on_batched(
+        "row",
+        10,
+        Duration::from_secs(1),
+        Box::new(|_| panic!("insert failed")),
+    );
+    emitter.emit("row", "a");
+    assert_eq!(emitter.flush_all_batches(), 1);
+    let errors = errors.calls.borrow();
+    assert_eq!(errors.len(), 1);
+    let error = ListenerError::from_json(&errors[0]).unwrap();
+    assert_eq!(error.event, "row");
+    assert_eq!(error.listener, handle);
+    assert_eq!(error.message, "insert failed");
+}
+
+#[test]
+fn panicking_batch_callback_is_reported_during_emission() {
+    let errors = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("listenerError", errors.payload());
+    let handle = emitter.on_batched(
+        "row",
+        1,
+        Duration::from_secs(1),
+        Box::new(|_| panic!("insert failed")),
+    );
+    emitter.emit("row", "a");
+    let errors = errors.calls.borrow();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        ListenerError::from_json(&errors[0]).unwrap().listener,
+        handle
+    );
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn emissions_bubble_up_nearest_parent_first() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_bubbling(true);
+    emitter.on("net", recorder.tag("net"));
+    emitter.on("net.socket.closed", recorder.tag("closed"));
+    emitter.on("net.socket", recorder.tag("socket"));
+    emitter.emit("net.socket.closed", 1);
+    assert_eq!(*recorder.calls.borrow(), vec!["closed", "socket", "net"]);
+}
+
+#[test]
+fn bubbling_is_disabled_by_default() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("net", recorder.tag("net"));
+    emitter.on("net.socket", recorder.tag("socket"));
+    emitter.emit("net.socket", 1);
+    assert_eq!(*recorder.calls.borrow(), vec!["socket"]);
+}
+
+#[test]
+fn stop_propagation_stops_bubbling() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_bubbling(true);
+    emitter.on("net", recorder.tag("net"));
+    emitter.on_controlled("net.socket", |_, _, control| control.stop_propagation());
+    emitter.emit("net.socket", 1);
+    assert!(recorder.calls.borrow().is_empty());
+}
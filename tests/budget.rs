@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::budget::BudgetPolicy;
+use rust_event_listener::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+fn slow_emitter() -> EventListener {
+    let mut emitter = EventListener::new();
+    emitter.set_emit_budget(Some(Duration::from_millis(1)), BudgetPolicy::Defer);
+    emitter.on(
+        "tick",
+        Box::new(|_, _| std::thread::sleep(Duration::from_millis(2))),
+    );
+    emitter
+}
+
+#[test]
+fn filtered_listener_is_not_deferred() {
+    let calls = Rc::new(RefCell::new(vec![]));
+    let mut emitter = slow_emitter();
+    let called = calls.clone();
+    emitter
+        .listen("tick")
+        .filter(|data| data == "yes")
+        .call(Box::new(move |_, data| called.borrow_mut().push(data)));
+    emitter.emit("tick", "no");
+    assert!(emitter.get_deferred().is_empty());
+    emitter.emit("tick", "yes");
+    assert_eq!(emitter.run_deferred(), 1);
+    assert_eq!(*calls.borrow(), vec!["yes"]);
+}
+
+#[test]
+fn paused_group_is_not_deferred() {
+    let calls = Rc::new(RefCell::new(0));
+    let mut emitter = slow_emitter();
+    let called = calls.clone();
+    emitter
+        .listen("tick")
+        .group("reports")
+        .call(Box::new(move |_, _| *called.borrow_mut() += 1));
+    emitter.pause_group("reports");
+    emitter.emit("tick", 1);
+    assert!(emitter.get_deferred().is_empty());
+    assert_eq!(emitter.run_deferred(), 0);
+    assert_eq!(*calls.borrow(), 0);
+}
@@ -0,0 +1,35 @@
+//! Fixtures shared by the integration tests
+
+#![allow(dead_code)]
+
+use rust_event_listener::listener::ListenerCallback;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Records calls of the listeners it creates
+#[derive(Default)]
+pub struct Recorder {
+    pub calls: Rc<RefCell<Vec<String>>>,
+}
+
+impl Recorder {
+    /// Listener recording `tag`
+    pub fn tag(&self, tag: &str) -> ListenerCallback {
+        let calls = self.calls.clone();
+        let tag = tag.to_string();
+        Box::new(move |_, _| calls.borrow_mut().push(tag.clone()))
+    }
+
+    /// Listener recording `tag:data`
+    pub fn tagged(&self, tag: &str) -> ListenerCallback {
+        let calls = self.calls.clone();
+        let tag = tag.to_string();
+        Box::new(move |_, data| calls.borrow_mut().push(format!("{}:{}", tag, data)))
+    }
+
+    /// Listener recording the payload
+    pub fn payload(&self) -> ListenerCallback {
+        let calls = self.calls.clone();
+        Box::new(move |_, data| calls.borrow_mut().push(data))
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+use std::time::Duration;
+
+#[test]
+fn debounced_listener_receives_last_payload_after_quiet_period() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_virtual_time(true);
+    emitter.on_debounced("input", Duration::from_millis(100), recorder.payload());
+    emitter.emit("input", "a");
+    emitter.advance(Duration::from_millis(60));
+    emitter.emit("input", "ab");
+    emitter.advance(Duration::from_millis(60));
+    assert!(recorder.calls.borrow().is_empty());
+    emitter.advance(Duration::from_millis(40));
+    assert_eq!(*recorder.calls.borrow(), vec!["ab"]);
+    assert_eq!(emitter.flush_debounced(), 0);
+}
+
+#[test]
+fn throttled_listener_drops_emissions_within_period() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_virtual_time(true);
+    emitter.on_throttled("tick", Duration::from_millis(100), recorder.payload());
+    for tick in 0..10 {
+        emitter.emit("tick", tick);
+        emitter.advance(Duration::from_millis(30));
+    }
+    assert_eq!(*recorder.calls.borrow(), vec!["0", "4", "8"]);
+}
+
+#[test]
+fn builder_throttle_matches_on_throttled() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_virtual_time(true);
+    emitter
+        .listen("tick")
+        .throttle(Duration::from_millis(100))
+        .call(recorder.payload());
+    for tick in 0..10 {
+        emitter.emit("tick", tick);
+        emitter.advance(Duration::from_millis(30));
+    }
+    assert_eq!(*recorder.calls.borrow(), vec!["0", "4", "8"]);
+}
+
+#[test]
+fn debounced_listener_panic_is_reported() {
+    let errors = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_virtual_time(true);
+    emitter.on("listenerError", errors.payload());
+    let handle = emitter.on_debounced(
+        "input",
+        Duration::from_millis(100),
+        Box::new(|_, _| panic!("search failed")),
+    );
+    emitter.emit("input", "a");
+    emitter.advance(Duration::from_millis(100));
+    let errors = errors.calls.borrow();
+    assert_eq!(errors.len(), 1);
+    let error = rust_event_listener::uncaught::ListenerError::from_json(&errors[0]).unwrap();
+    assert_eq!(error.listener, handle);
+    assert_eq!(error.message, "search failed");
+}
+
+#[test]
+fn builder_debounce_matches_on_debounced() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_virtual_time(true);
+    emitter
+        .listen("input")
+        .debounce(Duration::from_millis(100))
+        .filter(|data| !data.is_empty())
+        .call(recorder.payload());
+    emitter.emit("input", "a");
+    emitter.advance(Duration::from_millis(60));
+    emitter.emit("input", "ab");
+    emitter.emit("input", "");
+    emitter.advance(Duration::from_millis(60));
+    assert!(recorder.calls.borrow().is_empty());
+    emitter.advance(Duration::from_millis(40));
+    assert_eq!(*recorder.calls.borrow(), vec!["ab"]);
+}
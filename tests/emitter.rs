@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::emitter::{BoxEmitter, Emitter};
+use rust_event_listener::listener::ListenerCallback;
+use rust_event_listener::scope::Scope;
+use rust_event_listener::view::View;
+use rust_event_listener::EventListener;
+use std::cell::RefCell;
+
+/// Subscribe, emit twice with an unsubscription in between, through the trait only
+fn subscribe_then_unsubscribe<E: Emitter<Callback = ListenerCallback>>(
+    emitter: &mut E,
+    recorder: &Recorder,
+) {
+    let handle = emitter.on("tick", recorder.payload());
+    emitter.emit("tick", "1".to_string());
+    assert!(emitter.off(handle));
+    assert!(!emitter.off(handle));
+    emitter.on("tick", recorder.tag("other"));
+    emitter.emit("tick", "2".to_string());
+}
+
+#[test]
+fn off_unsubscribes_through_every_emitter() {
+    let recorder = Recorder::default();
+    subscribe_then_unsubscribe(&mut EventListener::new(), &recorder);
+    subscribe_then_unsubscribe(&mut BoxEmitter::new(EventListener::new()), &recorder);
+    let mut emitter = EventListener::new();
+    subscribe_then_unsubscribe(&mut emitter.namespace("app"), &recorder);
+    subscribe_then_unsubscribe(&mut emitter.tenant("acme"), &recorder);
+    let shared = RefCell::new(EventListener::new());
+    subscribe_then_unsubscribe(&mut Scope::new(&shared), &recorder);
+    subscribe_then_unsubscribe(&mut View::new(&shared, |_| true), &recorder);
+    assert_eq!(recorder.calls.borrow().len(), 12);
+    assert!(recorder
+        .calls
+        .borrow()
+        .chunks(2)
+        .all(|x| x == ["1", "other"]));
+}
+
+#[test]
+fn partitions_only_remove_their_own_listeners() {
+    let mut emitter = EventListener::new();
+    let outside = emitter.on("other.tick", Box::new(|_, _| {}));
+    let tenant = emitter.tenant("acme").on("tick", Box::new(|_, _| {}));
+    assert!(!Emitter::off(&mut emitter.namespace("app"), outside));
+    assert!(!Emitter::off(&mut emitter.tenant("globex"), tenant));
+    let shared = RefCell::new(emitter);
+    assert!(!View::new(&shared, |x| x.starts_with("app.")).off(outside));
+    assert!(shared.borrow_mut().off(outside));
+    assert!(shared.borrow_mut().tenant("acme").off(tenant));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::fallible::FailureStrategy;
+use rust_event_listener::uncaught::ListenerError;
+use rust_event_listener::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn stop_on_first_skips_remaining_listeners() {
+    let calls = Rc::new(RefCell::new(0));
+    let mut emitter = EventListener::new();
+    let failing = emitter.on_fallible("save", |_, _| Err("disk full"));
+    let counter = calls.clone();
+    emitter.on("save", Box::new(move |_, _| *counter.borrow_mut() += 1));
+    let failures = emitter
+        .emit_fallible::<_, &str>("save", 1, FailureStrategy::StopOnFirst)
+        .unwrap_err();
+    assert_eq!(failures, vec![(failing, "disk full")]);
+    assert_eq!(*calls.borrow(), 0);
+}
+
+#[test]
+fn plain_emit_reports_failures_as_listener_errors() {
+    let errors = Rc::new(RefCell::new(vec![]));
+    let mut emitter = EventListener::new();
+    let failing = emitter.on_fallible("save", |_, _| Err("disk full"));
+    let sink = errors.clone();
+    emitter.on(
+        "listenerError",
+        Box::new(move |_, data| {
+            sink.borrow_mut()
+                .push(ListenerError::from_json(&data).unwrap())
+        }),
+    );
+    emitter.emit("save", 1);
+    let errors = errors.borrow();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].listener, failing);
+    assert_eq!(errors[0].message, "\"disk full\"");
+}
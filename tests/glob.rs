@@ -0,0 +1,38 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn wildcards_are_literal_unless_registered_as_pattern() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("what?", recorder.tag("literal"));
+    emitter.on_pattern("user.*", recorder.tag("pattern"));
+    assert_eq!(emitter.listener_count("whatx"), 0);
+    emitter.emit("what?", "");
+    emitter.emit("user.login", "");
+    assert_eq!(*recorder.calls.borrow(), vec!["literal", "pattern"]);
+}
+
+#[test]
+fn once_pattern_is_called_by_the_first_match() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.once_pattern("job.*", recorder.payload());
+    emitter.emit("job.start", "a");
+    emitter.emit("job.end", "b");
+    assert_eq!(*recorder.calls.borrow(), vec!["a"]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn remove_listeners_matching_removes_async_listeners() {
+    let mut emitter = EventListener::new();
+    emitter.on("db.query", Box::new(|_, _| {}));
+    emitter.on_async("db.commit", Box::new(|_, _| Box::pin(async {})));
+    assert_eq!(emitter.remove_listeners_matching("db.*"), 2);
+    assert_eq!(emitter.listener_count("db.commit"), 0);
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn emissions_are_buffered_until_the_first_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.buffer_until_listener("ready", 10);
+    emitter.emit("ready", "early");
+    assert!(recorder.calls.borrow().is_empty());
+    emitter.on("ready", recorder.payload());
+    assert_eq!(*recorder.calls.borrow(), vec!["early"]);
+}
+
+#[test]
+fn pattern_listeners_receive_buffered_event_emissions() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.buffer_until_listener("job.done", 10);
+    emitter.on_pattern("job.*", recorder.payload());
+    emitter.emit("job.done", "now");
+    assert_eq!(*recorder.calls.borrow(), vec!["now"]);
+    assert!(emitter.stop_buffering("job.done").is_empty());
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::uncaught::ListenerError;
+use rust_event_listener::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn panicking_listener_does_not_stop_emit() {
+    let calls = Rc::new(RefCell::new(vec![]));
+    let errors = Rc::new(RefCell::new(vec![]));
+    let mut emitter = EventListener::new();
+    let recorded = errors.clone();
+    emitter.on(
+        "listenerError",
+        Box::new(move |_, data| recorded.borrow_mut().push(ListenerError::from_json(&data))),
+    );
+    emitter.on("test", Box::new(|_, _| panic!("boom")));
+    let called = calls.clone();
+    emitter.on(
+        "test",
+        Box::new(move |_, data| called.borrow_mut().push(data)),
+    );
+    emitter.emit("test", "1".to_string());
+    assert_eq!(*calls.borrow(), vec!["1"]);
+    let errors = errors.borrow();
+    assert_eq!(errors.len(), 1);
+    let error = errors[0].as_ref().unwrap();
+    assert_eq!(error.event, "test");
+    assert_eq!(error.message, "boom");
+}
+
+#[test]
+fn panicking_late_listener_is_reported() {
+    let errors = Rc::new(RefCell::new(0));
+    let mut emitter = EventListener::new();
+    let recorded = errors.clone();
+    emitter.on(
+        "listenerError",
+        Box::new(move |_, _| *recorded.borrow_mut() += 1),
+    );
+    emitter.buffer_until_listener("ready", 10);
+    emitter.emit("ready", "1".to_string());
+    emitter.emit("ready", "2".to_string());
+    emitter.on("ready", Box::new(|_, _| panic!("boom")));
+    assert_eq!(*errors.borrow(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Unhandled error: disk full")]
+fn error_without_listeners_panics() {
+    let mut emitter = EventListener::new();
+    emitter.emit("error", "disk full");
+}
+
+#[test]
+fn error_with_listeners_is_delivered() {
+    let mut emitter = EventListener::new();
+    emitter.on("error", Box::new(|_, _| {}));
+    assert!(emitter.emit("error", "disk full").is_delivered());
+}
+
+#[test]
+fn resumed_panic_still_consumes_once_listener() {
+    let calls = Rc::new(RefCell::new(0));
+    let mut emitter = EventListener::new();
+    let counted = calls.clone();
+    emitter.once("test", Box::new(move |_, _| *counted.borrow_mut() += 1));
+    emitter.on("test", Box::new(|_, _| panic!("boom")));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        emitter.emit("test", "1");
+    }));
+    assert!(result.is_err());
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        emitter.emit("test", "2");
+    }))
+    .unwrap_err();
+    assert_eq!(*calls.borrow(), 1);
+}
+
+#[test]
+fn resumed_panic_in_meta_listener_keeps_meta_events() {
+    let calls = Rc::new(RefCell::new(vec![]));
+    let mut emitter = EventListener::new();
+    let failing = Rc::new(RefCell::new(true));
+    let fail = failing.clone();
+    let called = calls.clone();
+    emitter.on(
+        "unhandledEvent",
+        Box::new(move |_, event| {
+            if fail.replace(false) {
+                panic!("boom");
+            }
+            called.borrow_mut().push(event);
+        }),
+    );
+    emitter.on("test", Box::new(|_, _| {}));
+    emitter.remove_all_listeners("test");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        emitter.emit("test", "1");
+    }));
+    assert!(result.is_err());
+    emitter.emit("test", "2");
+    assert_eq!(*calls.borrow(), vec!["test"]);
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::middleware::MiddlewareResult;
+use rust_event_listener::outcome::{DropReason, EmitOutcome};
+use rust_event_listener::EventListener;
+
+#[test]
+fn middlewares_rewrite_the_payload_in_registration_order() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.use_middleware(Box::new(|context| {
+        context.data.push('a');
+        MiddlewareResult::Continue
+    }));
+    emitter.use_middleware(Box::new(|context| {
+        context.data.push('b');
+        MiddlewareResult::Continue
+    }));
+    emitter.on("test", recorder.tagged("first"));
+    emitter.on("test", recorder.tagged("second"));
+    assert_eq!(emitter.emit("test", "x"), EmitOutcome::DeliveredTo(2));
+    assert_eq!(*recorder.calls.borrow(), vec!["first:xab", "second:xab"]);
+}
+
+#[test]
+fn cancelled_emission_skips_listeners_and_later_middlewares() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let calls = recorder.calls.clone();
+    emitter.use_middleware(Box::new(|context| {
+        if context.data == "secret" {
+            return MiddlewareResult::Cancel;
+        }
+        MiddlewareResult::Continue
+    }));
+    emitter.use_middleware(Box::new(move |context| {
+        if context.name() == "test" {
+            calls
+                .borrow_mut()
+                .push(format!("middleware:{}", context.data));
+        }
+        MiddlewareResult::Continue
+    }));
+    emitter.on("droppedEvent", recorder.tagged("dropped"));
+    emitter.on("test", recorder.tagged("on"));
+    assert_eq!(
+        emitter.emit("test", "secret"),
+        EmitOutcome::DroppedByPolicy(DropReason::Cancelled)
+    );
+    assert!(emitter.emit("test", "public").is_delivered());
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["dropped:test", "middleware:public", "on:public"]
+    );
+}
+
+#[test]
+fn context_listeners_see_the_metadata() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let calls = recorder.calls.clone();
+    emitter.use_middleware(Box::new(|context| {
+        context.set_meta("user", "ada");
+        context.set_meta("trace", "1");
+        MiddlewareResult::Continue
+    }));
+    emitter.use_middleware(Box::new(|context| {
+        context.set_meta("trace", "2");
+        MiddlewareResult::Continue
+    }));
+    emitter.on_context("save", move |context| {
+        calls.borrow_mut().push(format!(
+            "{}:{}:{:?}",
+            context.name(),
+            context.data,
+            context.metadata()
+        ));
+    });
+    emitter.emit("save", "draft");
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec![r#"save:draft:[("user", "ada"), ("trace", "2")]"#]
+    );
+}
+
+#[test]
+fn context_listeners_see_the_resolved_name() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let calls = recorder.calls.clone();
+    emitter.alias("store", "save");
+    emitter.use_middleware(Box::new(|context| {
+        let name = context.name().to_string();
+        context.set_meta("seen", &name);
+        MiddlewareResult::Continue
+    }));
+    emitter.on_context("save", move |context| {
+        calls
+            .borrow_mut()
+            .push(context.meta("seen").unwrap_or("none").to_string());
+    });
+    emitter.emit("store", "draft");
+    assert_eq!(*recorder.calls.borrow(), vec!["save"]);
+}
+
+#[test]
+fn context_listeners_work_without_middlewares() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let calls = recorder.calls.clone();
+    emitter.on_context("save", move |context| {
+        calls.borrow_mut().push(format!(
+            "{}:{}:{}",
+            context.name(),
+            context.data,
+            context.metadata().len()
+        ));
+    });
+    emitter.emit("save", "draft");
+    assert_eq!(*recorder.calls.borrow(), vec!["save:draft:0"]);
+}
+
+#[test]
+fn cleared_middlewares_stop_running() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.use_middleware(Box::new(|_| MiddlewareResult::Cancel));
+    emitter.use_middleware(Box::new(|_| MiddlewareResult::Continue));
+    emitter.on("test", recorder.payload());
+    assert!(emitter.emit("test", "1").is_dropped());
+    assert_eq!(emitter.clear_middlewares(), 2);
+    assert_eq!(emitter.clear_middlewares(), 0);
+    assert!(emitter.emit("test", "2").is_delivered());
+    assert_eq!(*recorder.calls.borrow(), vec!["2"]);
+}
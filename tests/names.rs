@@ -0,0 +1,38 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::name::EventName;
+use rust_event_listener::EventListener;
+
+#[test]
+fn on_stores_the_given_name() {
+    let mut emitter = EventListener::new();
+    let name = EventName::from("tick");
+    emitter.on(&name, Box::new(|_, _| {}));
+    assert!(name.ptr_eq(&emitter.get_event("tick").unwrap().name));
+    assert!(name.ptr_eq(&emitter.intern("tick")));
+}
+
+#[test]
+fn on_with_alias_name_registers_on_target() {
+    let mut emitter = EventListener::new();
+    emitter.alias("quit", "exit");
+    emitter.on(EventName::from("quit"), Box::new(|_, _| {}));
+    assert_eq!(
+        emitter
+            .get_event_names()
+            .iter()
+            .filter(|x| *x == "quit")
+            .count(),
+        0
+    );
+    assert_eq!(emitter.listener_count("exit"), 1);
+}
+
+#[test]
+fn emit_accepts_shared_names() {
+    let mut emitter = EventListener::new();
+    let tick = emitter.intern("tick");
+    emitter.on("tick", Box::new(|_, _| {}));
+    assert!(emitter.emit(&tick, 1).is_delivered());
+    assert!(emitter.emit(tick, 2).is_delivered());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::namespace::{NamespaceConfig, OverflowPolicy, RateLimit};
+use rust_event_listener::outcome::{DropReason, EmitOutcome};
+use rust_event_listener::EventListener;
+use std::time::Duration;
+
+fn limited(overflow: Option<OverflowPolicy>) -> NamespaceConfig {
+    NamespaceConfig {
+        max_listeners: None,
+        rate_limit: Some(RateLimit {
+            max_events: 1,
+            per: Duration::from_secs(60),
+        }),
+        overflow,
+    }
+}
+
+#[test]
+fn lossy_namespace_drops_overflowing_emissions() {
+    let mut emitter = EventListener::new();
+    emitter.configure_namespace("metrics", limited(None));
+    emitter.on("metrics.cpu", Box::new(|_, _| {}));
+    assert_eq!(emitter.emit("metrics.cpu", 1), EmitOutcome::DeliveredTo(1));
+    assert_eq!(
+        emitter.emit("metrics.cpu", 2),
+        EmitOutcome::DroppedByPolicy(DropReason::RateLimited)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Rate limit of namespace control exceeded")]
+fn strict_namespace_panics_on_overflow() {
+    let mut emitter = EventListener::new();
+    emitter.configure_namespace("control", limited(Some(OverflowPolicy::Strict)));
+    emitter.on("control.stop", Box::new(|_, _| {}));
+    emitter.emit("control.stop", 1);
+    emitter.emit("control.stop", 2);
+}
+
+#[test]
+fn nested_namespace_inherits_overflow_policy() {
+    let mut emitter = EventListener::new();
+    emitter.configure_namespace("control", limited(Some(OverflowPolicy::Strict)));
+    let mut control = emitter.namespace("control");
+    let mut motors = control.namespace("motors");
+    assert_eq!(motors.get_overflow_policy(), OverflowPolicy::Strict);
+    motors.set_overflow_policy(Some(OverflowPolicy::Lossy));
+    assert_eq!(motors.get_overflow_policy(), OverflowPolicy::Lossy);
+}
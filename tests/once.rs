@@ -0,0 +1,128 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn once_fires_a_single_time() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.once("test", recorder.tagged("once"));
+    emitter.emit("test", "1".to_string());
+    emitter.emit("test", "2".to_string());
+    assert_eq!(*recorder.calls.borrow(), vec!["once:1"]);
+    assert!(emitter.get_listeners("test").is_empty());
+}
+
+#[test]
+fn once_is_removed_during_the_same_emit() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.once("test", recorder.tagged("a"));
+    emitter.on("test", recorder.tagged("b"));
+    emitter.once("test", recorder.tagged("c"));
+    emitter.emit("test", "1".to_string());
+    assert_eq!(emitter.get_listeners("test").len(), 1);
+    emitter.emit("test", "2".to_string());
+    assert_eq!(*recorder.calls.borrow(), vec!["a:1", "b:1", "c:1", "b:2"]);
+}
+
+#[test]
+fn once_reports_unhandled_after_firing() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("unhandledEvent", recorder.tagged("unhandled"));
+    emitter.once("test", recorder.tagged("once"));
+    emitter.emit("test", "1".to_string());
+    emitter.emit("test", "2".to_string());
+    assert_eq!(*recorder.calls.borrow(), vec!["once:1", "unhandled:test"]);
+}
+
+#[test]
+fn once_can_be_removed_before_firing() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let handle = emitter.once("test", recorder.tagged("once"));
+    assert!(emitter.off(handle));
+    emitter.emit("test", "1".to_string());
+    assert!(recorder.calls.borrow().is_empty());
+}
+
+#[test]
+fn once_skipped_by_filter_stays_registered() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter
+        .listen("test")
+        .once()
+        .filter(|data| data == "match")
+        .call(recorder.tagged("once"));
+    emitter.emit("test", "other".to_string());
+    emitter.emit("test", "match".to_string());
+    emitter.emit("test", "match".to_string());
+    assert_eq!(*recorder.calls.borrow(), vec!["once:match"]);
+}
+
+#[test]
+fn once_receives_only_the_first_buffered_emission() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.buffer_until_listener("ready", 10);
+    emitter.emit("ready", "1".to_string());
+    emitter.emit("ready", "2".to_string());
+    emitter.once("ready", recorder.tagged("once"));
+    emitter.on("ready", recorder.tagged("on"));
+    assert_eq!(*recorder.calls.borrow(), vec!["once:1", "on:2"]);
+    assert_eq!(emitter.get_listeners("ready").len(), 1);
+}
+
+#[test]
+fn consumed_once_emits_remove_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("newListener", recorder.tagged("new"));
+    emitter.on("removeListener", recorder.tagged("removed"));
+    emitter.once("test", recorder.tagged("once"));
+    emitter.emit("test", "1".to_string());
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["new:removeListener", "new:test", "once:1", "removed:test"]
+    );
+}
+
+#[test]
+fn once_when_waits_for_matching_payload() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.once_when(
+        "reply",
+        |data| data.starts_with("id=2"),
+        recorder.tagged("once"),
+    );
+    emitter.on("reply", recorder.tagged("on"));
+    emitter.emit("reply", "id=1");
+    emitter.emit("reply", "id=2");
+    emitter.emit("reply", "id=2 again");
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["on:id=1", "once:id=2", "on:id=2", "on:id=2 again"]
+    );
+}
+
+#[test]
+fn on_times_is_removed_after_last_call() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("removeListener", recorder.tagged("removed"));
+    emitter.on_times("retry", 2, recorder.tagged("times"));
+    emitter.emit("retry", "1");
+    emitter.emit("retry", "2");
+    emitter.emit("retry", "3");
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["times:1", "times:2", "removed:retry"]
+    );
+    assert_eq!(emitter.listener_count("retry"), 0);
+}
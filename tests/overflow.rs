@@ -0,0 +1,120 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::overflow::{MaxListenersExceeded, OverflowPolicy};
+use rust_event_listener::EventListener;
+
+#[test]
+fn panic_is_the_default_policy() {
+    let emitter = EventListener::new();
+    assert_eq!(emitter.get_overflow_policy(), OverflowPolicy::Panic);
+}
+
+#[test]
+#[should_panic(expected = "Max listeners reached")]
+fn panic_policy_panics_on_overflow() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_max_listeners(1);
+    emitter.on("test", recorder.tag("a"));
+    emitter.on("test", recorder.tag("b"));
+}
+
+#[test]
+fn error_policy_refuses_the_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_max_listeners(2);
+    emitter.set_overflow_policy(OverflowPolicy::Error);
+    assert!(emitter.try_on("test", recorder.tag("a")).is_ok());
+    assert!(emitter.try_once("test", recorder.tag("b")).is_ok());
+    assert_eq!(
+        emitter.try_on("test", recorder.tag("c")),
+        Err(MaxListenersExceeded {
+            event: "test".to_string(),
+            max: 2,
+        })
+    );
+    assert_eq!(
+        emitter.try_once("test", recorder.tag("d")).unwrap_err().max,
+        2
+    );
+    emitter.emit("test", "1");
+    assert_eq!(*recorder.calls.borrow(), vec!["a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "Max listeners reached: test already has 1 listeners")]
+fn error_policy_makes_on_panic_with_the_error() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_max_listeners(1);
+    emitter.set_overflow_policy(OverflowPolicy::Error);
+    emitter.on("test", recorder.tag("a"));
+    emitter.on("test", recorder.tag("b"));
+}
+
+#[test]
+fn error_policy_frees_room_after_removal() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_max_listeners(1);
+    emitter.set_overflow_policy(OverflowPolicy::Error);
+    let handle = emitter.try_on("test", recorder.tag("a")).unwrap();
+    assert!(emitter.try_on("test", recorder.tag("b")).is_err());
+    assert!(emitter.off(handle));
+    assert!(emitter.try_on("test", recorder.tag("c")).is_ok());
+    emitter.emit("test", "1");
+    assert_eq!(*recorder.calls.borrow(), vec!["c"]);
+}
+
+#[test]
+fn warn_and_ignore_policies_add_the_listener() {
+    for policy in [OverflowPolicy::Warn, OverflowPolicy::Ignore] {
+        let recorder = Recorder::default();
+        let mut emitter = EventListener::new();
+        emitter.set_max_listeners(1);
+        emitter.set_overflow_policy(policy);
+        emitter.on("test", recorder.tag("a"));
+        assert!(emitter.try_on("test", recorder.tag("b")).is_ok());
+        emitter.once("test", recorder.tag("c"));
+        emitter.emit("test", "1");
+        assert_eq!(*recorder.calls.borrow(), vec!["a", "b", "c"]);
+    }
+}
+
+#[test]
+fn per_event_max_overrides_the_default() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_max_listeners(1);
+    emitter.set_max_listeners_for("wide", 3);
+    emitter.set_overflow_policy(OverflowPolicy::Error);
+    for tag in ["a", "b", "c"] {
+        assert!(emitter.try_on("wide", recorder.tag(tag)).is_ok());
+    }
+    assert_eq!(
+        emitter.try_on("wide", recorder.tag("d")).unwrap_err().max,
+        3
+    );
+    assert!(emitter.try_on("narrow", recorder.tag("e")).is_ok());
+    assert_eq!(
+        emitter.try_on("narrow", recorder.tag("f")).unwrap_err().max,
+        1
+    );
+}
+
+#[test]
+fn zero_max_means_unlimited() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_max_listeners(0);
+    emitter.set_overflow_policy(OverflowPolicy::Error);
+    for _ in 0..100 {
+        assert!(emitter.try_on("test", recorder.tag("x")).is_ok());
+    }
+    emitter.emit("test", "1");
+    assert_eq!(recorder.calls.borrow().len(), 100);
+}
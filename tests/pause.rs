@@ -0,0 +1,129 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::outcome::{DropReason, EmitOutcome};
+use rust_event_listener::pause::PauseOverflow;
+use rust_event_listener::EventListener;
+
+#[test]
+fn paused_emissions_flush_in_order_on_resume() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("a", recorder.tagged("a"));
+    emitter.on("b", recorder.tagged("b"));
+    emitter.pause();
+    assert!(emitter.is_paused());
+    assert_eq!(emitter.emit("a", "1"), EmitOutcome::Buffered);
+    assert_eq!(emitter.emit("b", "2"), EmitOutcome::Buffered);
+    assert_eq!(emitter.emit("a", "3"), EmitOutcome::Buffered);
+    assert!(recorder.calls.borrow().is_empty());
+    assert_eq!(emitter.paused_count(), 3);
+    assert_eq!(emitter.resume(), 3);
+    assert!(!emitter.is_paused());
+    assert_eq!(emitter.paused_count(), 0);
+    assert_eq!(*recorder.calls.borrow(), vec!["a:1", "b:2", "a:3"]);
+}
+
+#[test]
+fn listeners_added_while_paused_receive_the_flush() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.pause();
+    emitter.emit("config.loaded", "defaults");
+    emitter.on("config.loaded", recorder.tagged("config"));
+    assert!(recorder.calls.borrow().is_empty());
+    assert_eq!(emitter.resume(), 1);
+    assert_eq!(*recorder.calls.borrow(), vec!["config:defaults"]);
+}
+
+#[test]
+fn emissions_after_resume_are_delivered_immediately() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.payload());
+    emitter.pause();
+    emitter.emit("test", "1");
+    emitter.resume();
+    assert_eq!(emitter.emit("test", "2"), EmitOutcome::DeliveredTo(1));
+    assert_eq!(emitter.resume(), 0);
+    assert_eq!(*recorder.calls.borrow(), vec!["1", "2"]);
+}
+
+#[test]
+fn meta_events_are_emitted_while_paused() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("newListener", recorder.tagged("new"));
+    emitter.pause();
+    emitter.on("test", recorder.tagged("on"));
+    assert_eq!(*recorder.calls.borrow(), vec!["new:test"]);
+}
+
+#[test]
+fn full_buffer_drops_the_newest_emission_by_default() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("droppedEvent", recorder.tagged("dropped"));
+    emitter.on("test", recorder.tagged("on"));
+    emitter.set_pause_buffer(2, PauseOverflow::default());
+    emitter.pause();
+    emitter.emit("test", "1");
+    emitter.emit("test", "2");
+    assert_eq!(
+        emitter.emit("test", "3"),
+        EmitOutcome::DroppedByPolicy(DropReason::PauseBufferFull)
+    );
+    assert_eq!(emitter.paused_count(), 2);
+    assert_eq!(emitter.resume(), 2);
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["dropped:test", "on:1", "on:2"]
+    );
+}
+
+#[test]
+fn full_buffer_can_drop_the_oldest_emission() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("droppedEvent", recorder.tagged("dropped"));
+    emitter.on("a", recorder.tagged("a"));
+    emitter.on("b", recorder.tagged("b"));
+    emitter.set_pause_buffer(2, PauseOverflow::DropOldest);
+    emitter.pause();
+    emitter.emit("a", "1");
+    emitter.emit("b", "2");
+    assert_eq!(emitter.emit("b", "3"), EmitOutcome::Buffered);
+    assert_eq!(emitter.resume(), 2);
+    assert_eq!(*recorder.calls.borrow(), vec!["dropped:a", "b:2", "b:3"]);
+}
+
+#[test]
+fn shrinking_the_buffer_keeps_the_newest_emissions() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.payload());
+    emitter.pause();
+    for index in 1..=5 {
+        emitter.emit("test", index);
+    }
+    emitter.set_pause_buffer(2, PauseOverflow::DropNewest);
+    assert_eq!(emitter.paused_count(), 2);
+    assert_eq!(emitter.resume(), 2);
+    assert_eq!(*recorder.calls.borrow(), vec!["4", "5"]);
+}
+
+#[test]
+fn zero_capacity_buffers_without_limit() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.payload());
+    emitter.set_pause_buffer(0, PauseOverflow::DropNewest);
+    emitter.pause();
+    for index in 0..1000 {
+        emitter.emit("test", index);
+    }
+    assert_eq!(emitter.resume(), 1000);
+    assert_eq!(recorder.calls.borrow().len(), 1000);
+}
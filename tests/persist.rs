@@ -0,0 +1,76 @@
+#![cfg(feature = "persistence")]
+
+use rust_event_listener::journal::Journal;
+use rust_event_listener::persist::{Durability, JournalWriter};
+use std::io::Write;
+use std::time::Duration;
+
+#[test]
+fn open_continues_after_the_last_entry() {
+    let path = std::env::temp_dir().join("rust_event_listener_test_persist_tail.jsonl");
+    let _ = std::fs::remove_file(&path);
+    let mut writer = JournalWriter::open(&path, Durability::OnCommit).unwrap();
+    let payload = "x".repeat(1000);
+    for _ in 0..20 {
+        writer.append("blob", &payload).unwrap();
+    }
+    writer.commit().unwrap();
+    drop(writer);
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    file.write_all(b"{\"seq\":20,\"na").unwrap();
+    drop(file);
+    let writer = JournalWriter::open(&path, Durability::OnCommit).unwrap();
+    assert_eq!(writer.durable_seq(), Some(19));
+    drop(writer);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn interval_entries_are_committed_when_due() {
+    let path = std::env::temp_dir().join("rust_event_listener_test_persist_due.jsonl");
+    let _ = std::fs::remove_file(&path);
+    let mut writer =
+        JournalWriter::open(&path, Durability::Interval(Duration::from_millis(5))).unwrap();
+    writer.commit().unwrap();
+    writer.append("test", "1").unwrap();
+    assert!(!writer.commit_due().unwrap());
+    assert_eq!(writer.pending(), 1);
+    std::thread::sleep(Duration::from_millis(10));
+    assert!(writer.commit_due().unwrap());
+    assert_eq!(writer.durable_seq(), Some(0));
+    drop(writer);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn torn_tail_is_truncated_and_offsets_continue() {
+    let path = std::env::temp_dir().join("rust_event_listener_test_persist_torn.jsonl");
+    let _ = std::fs::remove_file(&path);
+    let mut writer = JournalWriter::open(&path, Durability::Always).unwrap();
+    writer.append("test", "1").unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    writer.append("test", "2").unwrap();
+    drop(writer);
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    file.write_all(b"{\"seq\":2,\"na").unwrap();
+    drop(file);
+    let mut writer = JournalWriter::open(&path, Durability::Always).unwrap();
+    writer.append("test", "3").unwrap();
+    drop(writer);
+    let journal = Journal::load(&path).unwrap();
+    let entries = journal.entries();
+    assert_eq!(
+        entries.iter().map(|x| x.seq).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(entries[2].payload, "3");
+    assert!(entries.windows(2).all(|x| x[0].offset <= x[1].offset));
+    assert!(entries[2].offset >= Duration::from_millis(20));
+    std::fs::remove_file(&path).unwrap();
+}
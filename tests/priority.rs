@@ -0,0 +1,46 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn higher_priorities_run_first() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.tag("default"));
+    emitter.on_with_priority("test", -5, recorder.tag("low"));
+    emitter.on_with_priority("test", 5, recorder.tag("high"));
+    emitter.emit("test", 1);
+    assert_eq!(*recorder.calls.borrow(), vec!["high", "default", "low"]);
+}
+
+#[test]
+fn equal_priorities_keep_registration_order() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    for tag in ["a", "b", "c"] {
+        emitter.on_with_priority("test", 1, recorder.tag(tag));
+    }
+    emitter.on_with_priority("test", 2, recorder.tag("first"));
+    emitter.on_with_priority("test", 1, recorder.tag("d"));
+    emitter.emit("test", 1);
+    assert_eq!(*recorder.calls.borrow(), vec!["first", "a", "b", "c", "d"]);
+}
+
+#[test]
+fn prepended_listeners_run_before_their_priority() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on_with_priority("test", 1, recorder.tag("high"));
+    emitter.on("test", recorder.tag("a"));
+    emitter.prepend_listener("test", recorder.tag("b"));
+    emitter.prepend_once_listener("test", recorder.tag("c"));
+    emitter.emit("test", 1);
+    emitter.emit("test", 2);
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["high", "c", "b", "a", "high", "b", "a"]
+    );
+}
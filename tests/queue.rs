@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::queue::FairnessPolicy;
+use rust_event_listener::EventListener;
+
+#[test]
+fn strict_policy_dispatches_by_priority_then_arrival() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_fairness_policy(FairnessPolicy::Strict);
+    emitter.on("job", recorder.payload());
+    emitter.emit_queued_with_priority("job", "low1", 0);
+    emitter.emit_queued_with_priority("job", "high1", 5);
+    emitter.emit_queued_with_priority("job", "low2", 0);
+    emitter.emit_queued_with_priority("job", "high2", 5);
+    let pending = emitter
+        .get_pending()
+        .into_iter()
+        .map(|x| x.data.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(pending, vec!["low1", "high1", "low2", "high2"]);
+    assert_eq!(emitter.process_pending(0), 4);
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["high1", "high2", "low1", "low2"]
+    );
+}
+
+#[test]
+fn aging_lets_waiting_emissions_overtake_higher_priorities() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.set_fairness_policy(FairnessPolicy::Aging { step: 1 });
+    emitter.on("job", recorder.payload());
+    emitter.emit_queued_with_priority("job", "bulk", 0);
+    for index in 0..4 {
+        emitter.emit_queued_with_priority("job", format!("urgent{}", index), 2);
+        emitter.process_pending(1);
+    }
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["urgent0", "urgent1", "bulk", "urgent2"]
+    );
+    assert_eq!(emitter.pending_count(), 1);
+}
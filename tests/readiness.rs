@@ -0,0 +1,25 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::sync::SyncEventListener;
+use std::time::{Duration, Instant};
+
+#[test]
+fn wait_for_subscribers_wakes_on_registration() {
+    let emitter = SyncEventListener::new();
+    let consumer = emitter.clone();
+    let worker = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        consumer.on("job", Box::new(|_, _| {}));
+    });
+    let started = Instant::now();
+    assert!(emitter.wait_for_subscribers("job", 1, Duration::from_secs(10)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+    worker.join().unwrap();
+}
+
+#[test]
+fn wait_for_subscribers_times_out() {
+    let emitter = SyncEventListener::new();
+    emitter.on("job", Box::new(|_, _| {}));
+    assert!(!emitter.wait_for_subscribers("job", 2, Duration::from_millis(10)));
+}
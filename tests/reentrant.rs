@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::sync::SyncEventListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn listener_added_during_emit_receives_next_emission() {
+    let emitter = SyncEventListener::new();
+    let added = Arc::new(AtomicUsize::new(0));
+    let inner = emitter.clone();
+    let counter = added.clone();
+    emitter.once(
+        "tick",
+        Box::new(move |_, _| {
+            let counter = counter.clone();
+            inner.on(
+                "tick",
+                Box::new(move |_, _| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+            );
+        }),
+    );
+    assert_eq!(emitter.emit("tick", 1), 1);
+    assert_eq!(added.load(Ordering::SeqCst), 0);
+    assert_eq!(emitter.emit("tick", 2), 1);
+    assert_eq!(added.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn listener_removed_during_emit_is_skipped() {
+    let emitter = SyncEventListener::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handle = Arc::new(std::sync::Mutex::new(None));
+    let inner = emitter.clone();
+    let target = handle.clone();
+    emitter.on(
+        "tick",
+        Box::new(move |_, _| {
+            if let Some(handle) = target.lock().unwrap().take() {
+                inner.off(handle);
+            }
+        }),
+    );
+    let counter = calls.clone();
+    *handle.lock().unwrap() = Some(emitter.on(
+        "tick",
+        Box::new(move |_, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }),
+    ));
+    assert_eq!(emitter.emit("tick", 1), 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(emitter.listener_count("tick"), 1);
+}
+
+#[test]
+fn nested_emit_from_listener() {
+    let emitter = SyncEventListener::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let inner = emitter.clone();
+    emitter.on(
+        "outer",
+        Box::new(move |_, _| {
+            inner.emit("inner", 1);
+        }),
+    );
+    let counter = calls.clone();
+    emitter.on(
+        "inner",
+        Box::new(move |_, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }),
+    );
+    emitter.emit("outer", 1);
+    emitter.emit("outer", 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::journal::{Journal, ReplayError, ReplaySpeed};
+use rust_event_listener::replay::ReplayQuery;
+use rust_event_listener::EventListener;
+use std::time::Duration;
+
+#[test]
+fn invalid_speed_factors_are_rejected_before_replaying() {
+    let recorder = Recorder::default();
+    let mut journal = Journal::new();
+    std::thread::sleep(Duration::from_millis(1));
+    journal.record("test", "1");
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.payload());
+    for factor in [0.0, -2.0, f64::NAN, f64::INFINITY, 1e-300] {
+        let speed = ReplaySpeed::Accelerated(factor);
+        match journal.replay_into(&mut emitter, speed) {
+            Err(ReplayError::InvalidSpeed(_)) => {}
+            other => panic!("factor {} gave {:?}", factor, other),
+        }
+    }
+    assert!(recorder.calls.borrow().is_empty());
+    let report = journal
+        .replay_into(&mut emitter, ReplaySpeed::Accelerated(1000.0))
+        .unwrap();
+    assert_eq!(report.replayed, 1);
+}
+
+#[test]
+fn unknown_events_are_skipped() {
+    let recorder = Recorder::default();
+    let mut journal = Journal::new();
+    journal.record("cart.add", "apple");
+    journal.record("cart.remove", "apple");
+    journal.record("cart.add", "pear");
+    let mut emitter = EventListener::new();
+    emitter.on("cart.add", recorder.payload());
+    let query = ReplayQuery::new().name("cart.*");
+    let report = journal
+        .replay_query_into(&mut emitter, ReplaySpeed::AsFastAsPossible, &query)
+        .unwrap();
+    assert_eq!(report.replayed, 2);
+    assert_eq!(report.skipped, vec![1]);
+    assert_eq!(*recorder.calls.borrow(), vec!["apple", "pear"]);
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn query_replay_accepts_out_of_order_offsets() {
+    let recorder = Recorder::default();
+    let mut journal = Journal::new();
+    for payload in ["late", "early", "middle"] {
+        journal.record("test", payload);
+    }
+    let mut trace = journal.to_trace();
+    for (payload, offset) in [("late", 3000), ("early", 1000), ("middle", 2000)] {
+        let start = trace.find(&format!("\"payload\":\"{}\"", payload)).unwrap();
+        let field = trace[..start].rfind("\"offset_us\":").unwrap() + "\"offset_us\":".len();
+        let end = field + trace[field..].find(',').unwrap();
+        trace.replace_range(field..end, &offset.to_string());
+    }
+    let journal = Journal::from_trace(&trace).unwrap();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.payload());
+    let report = journal
+        .replay_query_into(&mut emitter, ReplaySpeed::RealTime, &ReplayQuery::new())
+        .unwrap();
+    assert_eq!(report.replayed, 3);
+    assert_eq!(*recorder.calls.borrow(), vec!["late", "early", "middle"]);
+}
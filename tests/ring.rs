@@ -0,0 +1,78 @@
+#![cfg(feature = "persistence")]
+
+use rust_event_listener::ring::RingJournal;
+use rust_event_listener::EventListener;
+use std::io::ErrorKind;
+
+#[test]
+fn unflushed_sequence_is_recovered_on_open() {
+    let path = std::env::temp_dir().join("rust_event_listener_test_ring_recover.bin");
+    let _ = std::fs::remove_file(&path);
+    let mut ring = RingJournal::open(&path, 4, 256).unwrap();
+    for tick in 0..6 {
+        ring.append("tick", &tick.to_string()).unwrap();
+    }
+    // Simulate a crash, the header is never flushed
+    std::mem::forget(ring);
+    let mut ring = RingJournal::open(&path, 4, 256).unwrap();
+    assert_eq!(ring.total_appended(), 6);
+    ring.append("tick", "6").unwrap();
+    let payloads = ring
+        .snapshot()
+        .unwrap()
+        .entries()
+        .iter()
+        .map(|x| x.payload.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(payloads, vec!["3", "4", "5", "6"]);
+    drop(ring);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn record_errors_are_kept() {
+    let path = std::env::temp_dir().join("rust_event_listener_test_ring_error.bin");
+    let _ = std::fs::remove_file(&path);
+    let mut emitter = EventListener::new();
+    emitter.on("blob", Box::new(|_, _| {}));
+    emitter.set_ring_journal(Some(RingJournal::open(&path, 2, 64).unwrap()));
+    emitter.emit("blob", "x".repeat(100));
+    emitter.emit("blob", "small");
+    let ring = emitter.ring_journal_mut().unwrap();
+    assert_eq!(ring.take_error().unwrap().kind(), ErrorKind::InvalidInput);
+    assert!(ring.take_error().is_none());
+    assert_eq!(ring.total_appended(), 1);
+    drop(emitter);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn corrupted_slots_are_skipped() {
+    use std::io::{Seek, SeekFrom, Write};
+    let path = std::env::temp_dir().join("rust_event_listener_test_ring_corrupt.bin");
+    let _ = std::fs::remove_file(&path);
+    let mut ring = RingJournal::open(&path, 3, 128).unwrap();
+    for tick in 0..3 {
+        ring.append("tick", &tick.to_string()).unwrap();
+    }
+    drop(ring);
+    // Tear the second slot, right after the 32 byte header and the first slot
+    let mut file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+    file.seek(SeekFrom::Start(32 + 128 + 10)).unwrap();
+    file.write_all(b"\xff\xff garbage").unwrap();
+    drop(file);
+    let mut ring = RingJournal::open(&path, 3, 128).unwrap();
+    assert_eq!(ring.total_appended(), 3);
+    let snapshot = ring.snapshot().unwrap();
+    let payloads = snapshot
+        .entries()
+        .iter()
+        .map(|x| x.payload.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(payloads, vec!["0", "2"]);
+    assert!(snapshot
+        .metadata()
+        .contains(&("corrupted_slots".to_string(), "1".to_string())));
+    drop(ring);
+    std::fs::remove_file(&path).unwrap();
+}
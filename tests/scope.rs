@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::scope::Scope;
+use rust_event_listener::EventListener;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[test]
+fn scopes_can_be_open_at_once() {
+    let emitter = RefCell::new(EventListener::new());
+    let mut first = Scope::new(&emitter);
+    let mut second = first.scope();
+    first.on("message", Box::new(|_, _| {}));
+    second.on("message", Box::new(|_, _| {}));
+    second.once("close", Box::new(|_, _| {}));
+    assert_eq!(emitter.borrow().listener_count("message"), 2);
+    drop(first);
+    assert_eq!(emitter.borrow().listener_count("message"), 1);
+    assert_eq!(second.remove_all_listeners("close"), 1);
+    drop(second);
+    assert_eq!(emitter.borrow().listener_count("message"), 0);
+}
+
+#[test]
+fn scope_dropped_by_a_listener_is_removed_before_the_next_emission() {
+    let emitter: &'static RefCell<EventListener> =
+        Box::leak(Box::new(RefCell::new(EventListener::new())));
+    let calls = Rc::new(Cell::new(0));
+    let mut scope = Scope::new(emitter);
+    let counted = calls.clone();
+    scope.on("tick", Box::new(move |_, _| counted.set(counted.get() + 1)));
+    let slot = Rc::new(RefCell::new(Some(scope)));
+    let owner = slot.clone();
+    emitter.borrow_mut().on(
+        "close",
+        Box::new(move |_, _| drop(owner.borrow_mut().take())),
+    );
+    emitter.borrow_mut().emit("close", "");
+    assert!(slot.borrow().is_none());
+    emitter.borrow_mut().emit("tick", 1);
+    assert_eq!(calls.get(), 0);
+    assert_eq!(emitter.borrow().listener_count("tick"), 0);
+}
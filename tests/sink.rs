@@ -0,0 +1,34 @@
+#![cfg(feature = "futures")]
+
+mod common;
+
+use common::Recorder;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use rust_event_listener::emitter::Emitter;
+use rust_event_listener::outcome::EmitError;
+use rust_event_listener::EventListener;
+
+#[test]
+fn stream_forwards_into_the_emitter() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("line", recorder.payload());
+    let lines = stream::iter(1..=3).map(|x| Ok(("line".to_string(), x)));
+    block_on(lines.forward(emitter.sink())).unwrap();
+    assert_eq!(*recorder.calls.borrow(), vec!["1", "2", "3"]);
+}
+
+#[test]
+fn emit_errors_end_the_pipeline() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("line", recorder.payload());
+    let lines = stream::iter(vec!["line", "missing", "line"])
+        .map(|name| Ok((name.to_string(), name.to_string())));
+    let mut sink = emitter.sink();
+    let error = block_on(lines.forward(&mut sink)).unwrap_err();
+    assert_eq!(error, EmitError::UnknownEvent("missing".to_string()));
+    assert_eq!(sink.sent(), 1);
+    assert_eq!(*recorder.calls.borrow(), vec!["line"]);
+}
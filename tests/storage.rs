@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn emit_reaches_only_the_named_event_among_many() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    for index in 0..1000 {
+        emitter.on(format!("event.{}", index), recorder.tag(&index.to_string()));
+    }
+    emitter.emit("event.0", "x");
+    emitter.emit("event.999", "x");
+    emitter.emit("event.500", "x");
+    assert_eq!(*recorder.calls.borrow(), vec!["0", "999", "500"]);
+    assert_eq!(emitter.listener_count("event.500"), 1);
+}
+
+#[test]
+fn event_names_keep_creation_order() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let names = ["zeta", "alpha", "mid", "beta"];
+    for name in names {
+        emitter.on(name, recorder.tag(name));
+    }
+    emitter.on("alpha", recorder.tag("alpha again"));
+    let event_names = emitter.get_event_names();
+    assert!(event_names.ends_with(&names.map(String::from)));
+}
+
+#[test]
+fn listeners_keep_insertion_order_within_an_event() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.tagged("a"));
+    emitter.on("other", recorder.tagged("other"));
+    let handle = emitter.on("test", recorder.tagged("b"));
+    emitter.on("test", recorder.tagged("c"));
+    emitter.emit("test", "1");
+    assert!(emitter.off(handle));
+    emitter.on("test", recorder.tagged("d"));
+    emitter.emit("test", "2");
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["a:1", "b:1", "c:1", "a:2", "c:2", "d:2"]
+    );
+}
+
+#[test]
+fn emptied_events_can_be_listened_again() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("first", recorder.tagged("first"));
+    emitter.on("second", recorder.tagged("second"));
+    assert!(emitter.remove_all_listeners("first"));
+    emitter.emit("second", "1");
+    emitter.on("first", recorder.tagged("again"));
+    emitter.emit("first", "2");
+    emitter.emit("second", "3");
+    assert_eq!(
+        *recorder.calls.borrow(),
+        vec!["second:1", "again:2", "second:3"]
+    );
+    let event_names = emitter.get_event_names();
+    assert!(event_names.ends_with(&["first".to_string(), "second".to_string()]));
+}
+
+#[test]
+#[should_panic(expected = "Event doesn't exist")]
+fn emitting_an_unknown_event_among_many_panics() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    for index in 0..100 {
+        emitter.on(format!("event.{}", index), recorder.tag("x"));
+    }
+    emitter.emit("event.100", "x");
+}
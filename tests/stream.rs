@@ -0,0 +1,61 @@
+#![cfg(feature = "async")]
+
+use rust_event_listener::executor::{Executor, ThreadExecutor};
+use rust_event_listener::EventListener;
+use std::time::{Duration, Instant};
+
+#[test]
+fn stream_is_consumed_on_another_thread() {
+    let mut emitter = EventListener::new();
+    let mut ticks = emitter.event_stream("tick", 16);
+    let consumer = std::thread::spawn(move || {
+        let received = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let collected = received.clone();
+        ThreadExecutor.block_on(Box::pin(async move {
+            while let Some(tick) = ticks.next().await {
+                collected.borrow_mut().push(tick);
+            }
+        }));
+        received.take()
+    });
+    for tick in 0..3 {
+        std::thread::sleep(Duration::from_millis(5));
+        emitter.emit("tick", tick);
+    }
+    emitter.remove_all_listeners("tick");
+    assert_eq!(consumer.join().unwrap(), vec!["0", "1", "2"]);
+}
+
+#[test]
+fn wait_for_timeout_resolves_with_the_payload_or_none() {
+    let mut emitter = EventListener::new();
+    let ready = emitter.wait_for_timeout("ready", Duration::from_secs(60));
+    emitter.emit("ready", "v1");
+    let started = Instant::now();
+    ThreadExecutor.block_on(Box::pin(async move {
+        assert_eq!(ready.await.as_deref(), Some("v1"));
+    }));
+    let missing = emitter.wait_for_timeout("missing", Duration::from_millis(20));
+    ThreadExecutor.block_on(Box::pin(async move {
+        assert_eq!(missing.await, None);
+    }));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn stream_works_with_futures_combinators() {
+    use futures::StreamExt;
+    let mut emitter = EventListener::new();
+    let ticks = emitter.event_stream("tick", 0);
+    for tick in 0..4 {
+        emitter.emit("tick", tick);
+    }
+    emitter.remove_all_listeners("tick");
+    let even = futures::executor::block_on(
+        ticks
+            .filter(|x| std::future::ready(x.parse::<u32>().unwrap() % 2 == 0))
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(even, vec!["0", "2"]);
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::EventListener;
+
+#[test]
+fn dropped_subscription_removes_its_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.tagged("on"));
+    let subscription = emitter.subscribe("test", recorder.tagged("sub"));
+    emitter.emit("test", "1");
+    drop(subscription);
+    emitter.emit("test", "2");
+    assert_eq!(*recorder.calls.borrow(), vec!["on:1", "sub:1", "on:2"]);
+    assert_eq!(emitter.get_listeners("test").len(), 1);
+}
+
+#[test]
+fn dropped_subscription_emits_remove_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("removeListener", recorder.tagged("removed"));
+    let subscription = emitter.subscribe("test", recorder.tagged("sub"));
+    drop(subscription);
+    emitter.emit("test", "1");
+    assert_eq!(*recorder.calls.borrow(), vec!["removed:test"]);
+}
+
+#[test]
+fn subscribe_once_fires_a_single_time() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let _subscription = emitter.subscribe_once("test", recorder.tagged("once"));
+    emitter.emit("test", "1");
+    emitter.emit("test", "2");
+    assert_eq!(*recorder.calls.borrow(), vec!["once:1"]);
+}
+
+#[test]
+fn subscribe_once_dropped_before_firing_is_never_called() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("test", recorder.tagged("on"));
+    drop(emitter.subscribe_once("test", recorder.tagged("once")));
+    emitter.emit("test", "1");
+    assert_eq!(*recorder.calls.borrow(), vec!["on:1"]);
+}
+
+#[test]
+fn guard_ties_an_existing_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let handle = emitter.on("test", recorder.tagged("on"));
+    let subscription = emitter.guard(handle);
+    assert_eq!(subscription.handle(), handle);
+    emitter.emit("test", "1");
+    drop(subscription);
+    emitter.emit("test", "2");
+    assert_eq!(*recorder.calls.borrow(), vec!["on:1"]);
+}
+
+#[test]
+fn forget_keeps_the_listener() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let subscription = emitter.subscribe("test", recorder.tagged("sub"));
+    let handle = subscription.forget();
+    emitter.emit("test", "1");
+    assert!(emitter.off(handle));
+    emitter.emit("test", "2");
+    assert_eq!(*recorder.calls.borrow(), vec!["sub:1"]);
+}
+
+#[test]
+fn subscription_outliving_its_listener_is_harmless() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    let subscription = emitter.subscribe("test", recorder.tagged("sub"));
+    assert!(emitter.off(subscription.handle()));
+    emitter.on("test", recorder.tagged("on"));
+    drop(subscription);
+    emitter.emit("test", "1");
+    assert_eq!(*recorder.calls.borrow(), vec!["on:1"]);
+}
+
+#[test]
+fn broadcast_receives_payloads_in_order() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.on("price", recorder.payload());
+    let prices = emitter.subscribe_broadcast("price");
+    emitter.emit("price", 10);
+    emitter.emit("price", 11);
+    assert_eq!(prices.try_iter().collect::<Vec<_>>(), vec!["10", "11"]);
+    assert_eq!(*recorder.calls.borrow(), vec!["10", "11"]);
+}
+
+#[test]
+fn dropped_broadcast_receiver_removes_its_listener() {
+    let mut emitter = EventListener::new();
+    let prices = emitter.subscribe_broadcast("price");
+    emitter.emit("price", 10);
+    drop(prices);
+    emitter.emit("price", 11);
+    emitter.emit("price", 12);
+    assert!(emitter.get_listeners("price").is_empty());
+}
+
+#[test]
+fn broadcast_receiver_can_move_to_another_thread() {
+    let mut emitter = EventListener::new();
+    let prices = emitter.subscribe_broadcast("price");
+    let consumer = std::thread::spawn(move || prices.iter().take(3).collect::<Vec<_>>());
+    for price in 1..=3 {
+        emitter.emit("price", price);
+    }
+    assert_eq!(consumer.join().unwrap(), vec!["1", "2", "3"]);
+}
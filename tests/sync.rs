@@ -0,0 +1,118 @@
+#![cfg(feature = "std")]
+
+use rust_event_listener::sync::SyncEventListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn listener_limit_on_one_thread_leaves_the_emitter_usable() {
+    let emitter = SyncEventListener::new();
+    emitter.set_max_listeners(1);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    emitter.on(
+        "job",
+        Box::new(move |_, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }),
+    );
+    let subscriber = emitter.clone();
+    let refused = std::thread::spawn(move || subscriber.on("job", Box::new(|_, _| {})));
+    assert!(refused.join().is_err());
+    let error = emitter.try_once("job", Box::new(|_, _| {})).unwrap_err();
+    assert_eq!(error.event, "job");
+    let workers = (0..4)
+        .map(|id| {
+            let emitter = emitter.clone();
+            std::thread::spawn(move || emitter.emit("job", id))
+        })
+        .collect::<Vec<_>>();
+    for worker in workers {
+        assert_eq!(worker.join().unwrap(), 1);
+    }
+    assert_eq!(calls.load(Ordering::Relaxed), 4);
+    let handle = emitter.try_on("other", Box::new(|_, _| {})).unwrap();
+    assert!(emitter.off(handle));
+}
+
+#[test]
+fn emit_parallel_calls_every_listener_once() {
+    let emitter = SyncEventListener::new();
+    emitter.set_max_listeners(64);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let payloads = Arc::new(Mutex::new(vec![]));
+    for _ in 0..64 {
+        let counter = calls.clone();
+        let payloads = payloads.clone();
+        emitter.on(
+            "frame",
+            Box::new(move |name, data| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                payloads.lock().unwrap().push(format!("{}:{}", name, data));
+            }),
+        );
+    }
+    assert_eq!(emitter.emit_parallel("frame", 1), 64);
+    assert_eq!(calls.load(Ordering::Relaxed), 64);
+    assert!(payloads.lock().unwrap().iter().all(|x| x == "frame:1"));
+    assert_eq!(emitter.emit_parallel("frame", 2), 64);
+    assert_eq!(calls.load(Ordering::Relaxed), 128);
+}
+
+#[test]
+fn emit_parallel_runs_listeners_off_the_calling_thread() {
+    let emitter = SyncEventListener::new();
+    let threads = Arc::new(Mutex::new(vec![]));
+    for _ in 0..4 {
+        let threads = threads.clone();
+        emitter.on(
+            "frame",
+            Box::new(move |_, _| threads.lock().unwrap().push(std::thread::current().id())),
+        );
+    }
+    emitter.emit_parallel("frame", 1);
+    let threads = threads.lock().unwrap();
+    assert_eq!(threads.len(), 4);
+    assert!(!threads.contains(&std::thread::current().id()));
+}
+
+#[test]
+fn emit_parallel_removes_fired_once_listeners() {
+    let emitter = SyncEventListener::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    for _ in 0..3 {
+        let counter = calls.clone();
+        emitter.once(
+            "frame",
+            Box::new(move |_, _| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }),
+        );
+    }
+    let counter = calls.clone();
+    emitter.on(
+        "frame",
+        Box::new(move |_, _| {
+            counter.fetch_add(10, Ordering::Relaxed);
+        }),
+    );
+    assert_eq!(emitter.emit_parallel("frame", 1), 4);
+    assert_eq!(emitter.listener_count("frame"), 1);
+    assert_eq!(emitter.emit_parallel("frame", 2), 1);
+    assert_eq!(calls.load(Ordering::Relaxed), 23);
+}
+
+#[test]
+fn emit_parallel_without_listeners_calls_nothing() {
+    let emitter = SyncEventListener::new();
+    let handle = emitter.on("frame", Box::new(|_, _| {}));
+    assert!(emitter.off(handle));
+    assert_eq!(emitter.emit_parallel("frame", 1), 0);
+}
+
+#[test]
+#[should_panic(expected = "Event doesn't exist")]
+fn emit_parallel_panics_on_unknown_event() {
+    let emitter = SyncEventListener::new();
+    emitter.emit_parallel("frame", 1);
+}
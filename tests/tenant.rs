@@ -0,0 +1,38 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::Recorder;
+use rust_event_listener::tenant::is_valid_tenant_id;
+use rust_event_listener::EventListener;
+
+#[test]
+fn tenants_with_overlapping_names_stay_isolated() {
+    let recorder = Recorder::default();
+    let mut emitter = EventListener::new();
+    emitter.tenant("a").on("b/c", recorder.tag("a"));
+    emitter.tenant("a.b").on("c", recorder.tag("a.b"));
+    emitter.tenant("a").emit("b/c", "");
+    assert_eq!(*recorder.calls.borrow(), vec!["a"]);
+    assert!(!is_valid_tenant_id("a/b"));
+    assert!(!is_valid_tenant_id("@a"));
+    assert!(!is_valid_tenant_id(""));
+}
+
+#[test]
+#[should_panic(expected = "Invalid tenant id")]
+fn tenant_ids_with_separators_are_rejected() {
+    let mut emitter = EventListener::new();
+    emitter.tenant("a").on("b/c", Box::new(|_, _| {}));
+    emitter.tenant("a/b").on("c", Box::new(|_, _| {}));
+}
+
+#[test]
+#[should_panic(expected = "Invalid tenant id")]
+fn cross_tenant_ids_are_checked() {
+    let mut emitter = EventListener::new();
+    let audit = emitter.cross_tenant();
+    emitter
+        .tenant("ops")
+        .on_tenant(&audit, "a/b", "c", Box::new(|_, _| {}));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "persistence")]
+
+use rust_event_listener::journal::Journal;
+
+fn trace_with_payload(payload: &str) -> String {
+    let mut journal = Journal::new();
+    journal.record("test", "placeholder");
+    journal.to_trace().replace("\"placeholder\"", payload)
+}
+
+#[test]
+fn deeply_nested_trace_is_rejected() {
+    let nested = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+    let error = Journal::from_trace(&nested).unwrap_err();
+    assert!(error.starts_with("Nesting deeper than"), "{}", error);
+}
+
+#[test]
+fn unpaired_surrogates_are_rejected() {
+    assert!(Journal::from_trace(&trace_with_payload("\"\\ud83d\\ude00\"")).is_ok());
+    assert!(Journal::from_trace(&trace_with_payload("\"\\ud83d\\u0041\"")).is_err());
+    assert!(Journal::from_trace(&trace_with_payload("\"\\ude00\"")).is_err());
+}
+
+#[test]
+fn wrong_typed_version_is_reported() {
+    let trace = Journal::new().to_trace();
+    let version = trace.find("\"version\":").unwrap() + "\"version\":".len();
+    let end = version + trace[version..].find([',', '}']).unwrap();
+    let trace = format!("{}\"1.0\"{}", &trace[..version], &trace[end..]);
+    assert_eq!(
+        Journal::from_trace(&trace).unwrap_err(),
+        "Trace version must be an unsigned integer"
+    );
+}